@@ -5,6 +5,10 @@ use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use rayon::iter::ParallelBridge;
 use std::hint::black_box;
+use std::sync::Arc;
+use utils::{cheap_source, lines, parsed_source};
+
+mod utils;
 
 const TEST_LARGE_OUTPUT: bool = false;
 
@@ -13,6 +17,7 @@ const LARGE_OUTPUT_LEN: usize = match TEST_LARGE_OUTPUT {
     false => 0,
 };
 const SEED: u64 = 5426;
+const BUFFER_LEN: usize = 1024;
 const FIB_UPPER_BOUND: u32 = 201;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -93,6 +98,22 @@ fn orx_into_split_vec(inputs: &[usize]) -> SplitVec<Output> {
         .collect()
 }
 
+fn orx_parsed_into_vec(lines: Arc<[String]>) -> Vec<Output> {
+    parsed_source(lines)
+        .iter_into_par()
+        .map(|x| map(&x))
+        .filter(filter)
+        .collect()
+}
+
+fn orx_buffered_into_vec(source: impl Iterator<Item = usize> + Send + 'static) -> Vec<Output> {
+    source
+        .iter_into_par_buffered(BUFFER_LEN)
+        .map(|x| map(&x))
+        .filter(filter)
+        .collect()
+}
+
 fn run(c: &mut Criterion) {
     let treatments = [65_536 * 2];
 
@@ -101,6 +122,7 @@ fn run(c: &mut Criterion) {
     for n in &treatments {
         let input = inputs(*n);
         let expected = seq(&input);
+        let shared: Arc<[usize]> = input.as_slice().into();
 
         group.bench_with_input(BenchmarkId::new("seq-into-vec", n), n, |b, _| {
             assert_eq!(&expected, &seq(&input));
@@ -122,6 +144,33 @@ fn run(c: &mut Criterion) {
             assert_eq!(&expected, &orx_into_split_vec(&input));
             b.iter(|| orx_into_split_vec(black_box(&input)))
         });
+
+        group.bench_with_input(BenchmarkId::new("orx-buffered-into-vec", n), n, |b, _| {
+            assert_eq!(
+                &expected,
+                &orx_buffered_into_vec(cheap_source(shared.clone()))
+            );
+            b.iter(|| orx_buffered_into_vec(cheap_source(black_box(shared.clone()))))
+        });
+
+        let lines = lines(&input);
+
+        group.bench_with_input(BenchmarkId::new("orx-parsed-into-vec", n), n, |b, _| {
+            assert_eq!(&expected, &orx_parsed_into_vec(lines.clone()));
+            b.iter(|| orx_parsed_into_vec(black_box(lines.clone())))
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("orx-buffered-parsed-into-vec", n),
+            n,
+            |b, _| {
+                assert_eq!(
+                    &expected,
+                    &orx_buffered_into_vec(parsed_source(lines.clone()))
+                );
+                b.iter(|| orx_buffered_into_vec(parsed_source(black_box(lines.clone()))))
+            },
+        );
     }
 
     group.finish();
@@ -4,6 +4,10 @@ use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use rayon::iter::ParallelBridge;
 use std::hint::black_box;
+use std::sync::Arc;
+use utils::{cheap_source, lines, parsed_source};
+
+mod utils;
 
 const TEST_LARGE_OUTPUT: bool = false;
 
@@ -18,6 +22,7 @@ const LARGE_OUTPUT_LEN: usize = match TEST_LARGE_OUTPUT {
     false => 0,
 };
 const SEED: u64 = 9562;
+const BUFFER_LEN: usize = 1024;
 const FIB_UPPER_BOUND: u32 = 201;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -99,6 +104,28 @@ fn orx(inputs: &[usize], find: impl Fn(&Output) -> bool + Send + Sync) -> Option
         .find(&find)
 }
 
+fn orx_parsed(
+    lines: Arc<[String]>,
+    find: impl Fn(&Output) -> bool + Send + Sync,
+) -> Option<Output> {
+    parsed_source(lines)
+        .iter_into_par()
+        .map(|x| map(&x))
+        .filter(filter)
+        .find(&find)
+}
+
+fn orx_buffered(
+    source: impl Iterator<Item = usize> + Send + 'static,
+    find: impl Fn(&Output) -> bool + Send + Sync,
+) -> Option<Output> {
+    source
+        .iter_into_par_buffered(BUFFER_LEN)
+        .map(|x| map(&x))
+        .filter(filter)
+        .find(&find)
+}
+
 fn run(c: &mut Criterion) {
     let treatments = [N_EARLY, N_MIDDLE, N_LATE, N_NEVER];
 
@@ -108,6 +135,7 @@ fn run(c: &mut Criterion) {
         let find = get_find(*n_when);
         let input = inputs(N);
         let expected = seq(&input, &find);
+        let shared: Arc<[usize]> = input.as_slice().into();
 
         let n_when = match *n_when {
             N_EARLY => "find-early",
@@ -131,6 +159,33 @@ fn run(c: &mut Criterion) {
             assert_eq!(&expected, &orx(&input, &find));
             b.iter(|| orx(black_box(&input), &find))
         });
+
+        group.bench_with_input(BenchmarkId::new("orx-buffered", n_when), n_when, |b, _| {
+            assert_eq!(
+                &expected,
+                &orx_buffered(cheap_source(shared.clone()), &find)
+            );
+            b.iter(|| orx_buffered(cheap_source(black_box(shared.clone())), &find))
+        });
+
+        let lines = lines(&input);
+
+        group.bench_with_input(BenchmarkId::new("orx-parsed", n_when), n_when, |b, _| {
+            assert_eq!(&expected, &orx_parsed(lines.clone(), &find));
+            b.iter(|| orx_parsed(black_box(lines.clone()), &find))
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("orx-buffered-parsed", n_when),
+            n_when,
+            |b, _| {
+                assert_eq!(
+                    &expected,
+                    &orx_buffered(parsed_source(lines.clone()), &find)
+                );
+                b.iter(|| orx_buffered(parsed_source(black_box(lines.clone())), &find))
+            },
+        );
     }
 
     group.finish();
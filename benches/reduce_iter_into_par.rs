@@ -4,6 +4,10 @@ use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use rayon::iter::ParallelBridge;
 use std::hint::black_box;
+use std::sync::Arc;
+use utils::{cheap_source, lines, parsed_source};
+
+mod utils;
 
 const TEST_LARGE_OUTPUT: bool = false;
 
@@ -12,6 +16,7 @@ const LARGE_OUTPUT_LEN: usize = match TEST_LARGE_OUTPUT {
     false => 0,
 };
 const SEED: u64 = 9562;
+const BUFFER_LEN: usize = 1024;
 const FIB_UPPER_BOUND: u32 = 201;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -93,6 +98,22 @@ fn orx(inputs: &[usize]) -> Option<Output> {
         .reduce(reduce)
 }
 
+fn orx_parsed(lines: Arc<[String]>) -> Option<Output> {
+    parsed_source(lines)
+        .iter_into_par()
+        .map(|x| map(&x))
+        .filter(filter)
+        .reduce(reduce)
+}
+
+fn orx_buffered(source: impl Iterator<Item = usize> + Send + 'static) -> Option<Output> {
+    source
+        .iter_into_par_buffered(BUFFER_LEN)
+        .map(|x| map(&x))
+        .filter(filter)
+        .reduce(reduce)
+}
+
 #[allow(dead_code)]
 fn orx_with<P: ParThreadPool>(inputs: &[usize], pool: P) -> Option<Output> {
     inputs
@@ -112,6 +133,7 @@ fn run(c: &mut Criterion) {
     for n in &treatments {
         let input = inputs(*n);
         let expected = seq(&input);
+        let shared: Arc<[usize]> = input.as_slice().into();
 
         group.bench_with_input(BenchmarkId::new("seq", n), n, |b, _| {
             assert_eq!(&expected, &seq(&input));
@@ -128,6 +150,23 @@ fn run(c: &mut Criterion) {
             b.iter(|| orx(black_box(&input)))
         });
 
+        group.bench_with_input(BenchmarkId::new("orx-buffered", n), n, |b, _| {
+            assert_eq!(&expected, &orx_buffered(cheap_source(shared.clone())));
+            b.iter(|| orx_buffered(cheap_source(black_box(shared.clone()))))
+        });
+
+        let lines = lines(&input);
+
+        group.bench_with_input(BenchmarkId::new("orx-parsed", n), n, |b, _| {
+            assert_eq!(&expected, &orx_parsed(lines.clone()));
+            b.iter(|| orx_parsed(black_box(lines.clone())))
+        });
+
+        group.bench_with_input(BenchmarkId::new("orx-buffered-parsed", n), n, |b, _| {
+            assert_eq!(&expected, &orx_buffered(parsed_source(lines.clone())));
+            b.iter(|| orx_buffered(parsed_source(black_box(lines.clone()))))
+        });
+
         #[cfg(feature = "rayon-core")]
         group.bench_with_input(
             BenchmarkId::new("orx (rayon-core::ThreadPool)", n),
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+/// Number of rounds of the checksum, which makes parsing a line expensive.
+const CHECKSUM_ROUNDS: u64 = 1024;
+
+fn checksum(value: usize) -> u64 {
+    (0..CHECKSUM_ROUNDS).fold(value as u64, |h, i| {
+        (h ^ i).wrapping_mul(0x100_0000_01b3).rotate_left(7)
+    })
+}
+
+fn parse(line: &str) -> usize {
+    let (value, sum) = line.split_once(':').unwrap();
+    let value = value.parse().unwrap();
+    assert_eq!(sum.parse::<u64>().unwrap(), checksum(value));
+    value
+}
+
+/// Creates the lines of the `inputs` to be parsed by the [`parsed_source`], each containing a value and its checksum.
+pub fn lines(inputs: &[usize]) -> Arc<[String]> {
+    inputs
+        .iter()
+        .map(|x| format!("{x}:{}", checksum(*x)))
+        .collect()
+}
+
+/// Source iterator yielding the `inputs`, from which pulling an element is cheap.
+///
+/// The inputs are shared, so that creating the source does not copy the inputs.
+pub fn cheap_source(inputs: Arc<[usize]>) -> impl Iterator<Item = usize> + Send + 'static {
+    (0..inputs.len()).map(move |i| inputs[i])
+}
+
+/// Source iterator parsing and validating the `lines`, from which pulling an element is expensive.
+///
+/// The lines are shared, so that creating the source does not copy the lines.
+pub fn parsed_source(lines: Arc<[String]>) -> impl Iterator<Item = usize> + Send + 'static {
+    (0..lines.len()).map(move |i| parse(&lines[i]))
+}
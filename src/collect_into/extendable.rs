@@ -0,0 +1,170 @@
+use super::par_collect_into::ParCollectIntoCore;
+use crate::collect_into::utils::push_with_idx;
+use crate::generic_values::runner_results::{Fallibility, Infallible};
+use crate::generic_values::{TransformableValues, Values};
+use crate::runner::ParallelRunner;
+use crate::{IterationOrder, Params};
+use alloc::collections::{BinaryHeap, LinkedList, VecDeque};
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+use orx_split_vec::SplitVec;
+
+/// A sequential collection, such as a deque or a heap, which is extended with the values collected
+/// into a vector by the parallel computation.
+pub trait ExtendableCollection<O>: Extend<O> {
+    fn with_capacity(capacity: usize) -> Self;
+
+    fn reserve(&mut self, additional: usize);
+
+    fn len(&self) -> usize;
+}
+
+impl<T> ExtendableCollection<T> for VecDeque<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        VecDeque::with_capacity(capacity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        VecDeque::reserve(self, additional);
+    }
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+}
+
+impl<T> ExtendableCollection<T> for LinkedList<T> {
+    fn with_capacity(_: usize) -> Self {
+        LinkedList::new()
+    }
+
+    fn reserve(&mut self, _: usize) {}
+
+    fn len(&self) -> usize {
+        LinkedList::len(self)
+    }
+}
+
+impl<T: Ord> ExtendableCollection<T> for BinaryHeap<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        BinaryHeap::with_capacity(capacity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        BinaryHeap::reserve(self, additional);
+    }
+
+    fn len(&self) -> usize {
+        BinaryHeap::len(self)
+    }
+}
+
+macro_rules! impl_par_collect_into_core {
+    ($collection:ty) => {
+        impl<O> ParCollectIntoCore<O> for $collection
+        where
+            $collection: ExtendableCollection<O>,
+            O: Send + Sync,
+        {
+            type BridgePinnedVec = SplitVec<O>;
+
+            fn empty(iter_len: Option<usize>) -> Self {
+                ExtendableCollection::with_capacity(iter_len.unwrap_or(0))
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                ExtendableCollection::reserve(self, additional);
+            }
+
+            fn m_collect_into<R, I, M1>(
+                mut self,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                map1: M1,
+            ) -> Self
+            where
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                M1: Fn(I::Item) -> O + Sync,
+            {
+                let iter_len = iter.try_get_len();
+                if let Some(len) = iter_len {
+                    ExtendableCollection::reserve(&mut self, len);
+                }
+                let vec = Vec::empty(iter_len);
+                self.extend(vec.m_collect_into(orchestrator, params, iter, map1));
+                self
+            }
+
+            fn x_collect_into<R, I, Vo, X1>(
+                mut self,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                xap1: X1,
+            ) -> Self
+            where
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                Vo: TransformableValues<Item = O, Fallibility = Infallible>,
+                X1: Fn(I::Item) -> Vo + Sync,
+            {
+                let split_vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+                self.extend(split_vec.x_collect_into(orchestrator, params, iter, xap1));
+                self
+            }
+
+            fn x_try_collect_into<R, I, Vo, X1>(
+                mut self,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                xap1: X1,
+            ) -> Result<Self, <Vo::Fallibility as Fallibility>::Error>
+            where
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                X1: Fn(I::Item) -> Vo + Sync,
+                Vo: Values<Item = O>,
+                Self: Sized,
+            {
+                let split_vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+                let result = split_vec.x_try_collect_into(orchestrator, params, iter, xap1);
+                result.map(|split_vec| {
+                    self.extend(split_vec);
+                    self
+                })
+            }
+
+            fn extend_with_idx(
+                mut self,
+                vectors: Vec<Vec<(usize, O)>>,
+                max_idx_inc: Option<usize>,
+                order: IterationOrder,
+            ) -> Self {
+                let mut split_vec = SplitVec::new();
+                push_with_idx(&mut split_vec, vectors, max_idx_inc, order);
+                self.extend(split_vec);
+                self
+            }
+
+            fn extend_from_vecs(mut self, vectors: Vec<Vec<O>>) -> Self {
+                ExtendableCollection::reserve(&mut self, vectors.iter().map(|x| x.len()).sum());
+                self.extend(vectors.into_iter().flatten());
+                self
+            }
+
+            // test
+
+            #[cfg(test)]
+            fn length(&self) -> usize {
+                ExtendableCollection::len(self)
+            }
+        }
+    };
+}
+
+impl_par_collect_into_core!(VecDeque<O>);
+impl_par_collect_into_core!(LinkedList<O>);
+impl_par_collect_into_core!(BinaryHeap<O>);
@@ -1,8 +1,8 @@
 use super::par_collect_into::ParCollectIntoCore;
-use crate::Params;
 use crate::generic_values::runner_results::{Fallibility, Infallible};
 use crate::generic_values::{TransformableValues, Values};
 use crate::runner::ParallelRunner;
+use crate::{IterationOrder, Params};
 use alloc::vec::Vec;
 use orx_concurrent_iter::ConcurrentIter;
 use orx_fixed_vec::FixedVec;
@@ -20,6 +20,12 @@ where
         vec.into()
     }
 
+    fn reserve(&mut self, additional: usize) {
+        let mut vec = Vec::from(core::mem::replace(self, Vec::new().into()));
+        vec.reserve(additional);
+        *self = vec.into();
+    }
+
     fn m_collect_into<R, I, M1>(self, orchestrator: R, params: Params, iter: I, map1: M1) -> Self
     where
         R: ParallelRunner,
@@ -66,6 +72,21 @@ where
             .map(FixedVec::from)
     }
 
+    fn extend_with_idx(
+        self,
+        vectors: Vec<Vec<(usize, O)>>,
+        max_idx_inc: Option<usize>,
+        order: IterationOrder,
+    ) -> Self {
+        let vec = Vec::from(self);
+        FixedVec::from(vec.extend_with_idx(vectors, max_idx_inc, order))
+    }
+
+    fn extend_from_vecs(self, vectors: Vec<Vec<O>>) -> Self {
+        let vec = Vec::from(self);
+        FixedVec::from(vec.extend_from_vecs(vectors))
+    }
+
     // test
 
     #[cfg(test)]
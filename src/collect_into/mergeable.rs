@@ -0,0 +1,248 @@
+use super::par_collect_into::ParCollectIntoCore;
+use crate::collect_into::utils::push_with_idx;
+use crate::executor::parallel_compute as prc;
+use crate::generic_values::runner_results::{Fallibility, Infallible};
+use crate::generic_values::{TransformableValues, Values};
+use crate::runner::ParallelRunner;
+use crate::{IterationOrder, Params};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+use orx_split_vec::SplitVec;
+#[cfg(feature = "std")]
+use {
+    core::hash::{BuildHasher, Hash},
+    std::collections::{HashMap, HashSet},
+};
+
+/// A collection, such as a map or a set, which is built up by extending it with items.
+///
+/// In arbitrary order, each thread collects into its own partial collection and the
+/// partials are merged at the end.
+pub trait MergeableCollection<O>: Default + Extend<O> + IntoIterator<Item = O> + Send {
+    fn reserve(&mut self, additional: usize);
+
+    fn len(&self) -> usize;
+}
+
+impl<K: Ord + Send, V: Send> MergeableCollection<(K, V)> for BTreeMap<K, V> {
+    fn reserve(&mut self, _: usize) {}
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+}
+
+impl<T: Ord + Send> MergeableCollection<T> for BTreeSet<T> {
+    fn reserve(&mut self, _: usize) {}
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> MergeableCollection<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash + Send,
+    V: Send,
+    S: BuildHasher + Default + Send,
+{
+    fn reserve(&mut self, additional: usize) {
+        HashMap::reserve(self, additional);
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> MergeableCollection<T> for HashSet<T, S>
+where
+    T: Eq + Hash + Send,
+    S: BuildHasher + Default + Send,
+{
+    fn reserve(&mut self, additional: usize) {
+        HashSet::reserve(self, additional);
+    }
+
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+}
+
+/// Merges the partial collections by extending the larger ones with the smaller ones.
+pub(crate) fn merge_partials<O, C>(partials: Vec<C>) -> C
+where
+    C: MergeableCollection<O>,
+{
+    let merged = partials
+        .into_iter()
+        .reduce(|mut a, mut b| match a.len() >= b.len() {
+            true => {
+                a.extend(b);
+                a
+            }
+            false => {
+                b.extend(a);
+                b
+            }
+        });
+    merged.unwrap_or_default()
+}
+
+/// Extends the `collection` with the `items` that are collected by the computation,
+/// so that the collected items are inserted after the existing ones.
+pub(crate) fn extend_collection<O, C>(mut collection: C, items: impl IntoIterator<Item = O>) -> C
+where
+    C: MergeableCollection<O>,
+{
+    collection.extend(items);
+    collection
+}
+
+/// Collects into the `collection` either through partial collections of threads when
+/// the order is arbitrary or sequential, or through an ordered intermediate vector otherwise.
+pub(crate) fn x_try_collect_into<C, R, I, Vo, X1>(
+    collection: C,
+    orchestrator: R,
+    params: Params,
+    iter: I,
+    xap1: X1,
+) -> Result<C, <Vo::Fallibility as Fallibility>::Error>
+where
+    C: MergeableCollection<Vo::Item>,
+    R: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values,
+    Vo::Item: Send + Sync,
+    X1: Fn(I::Item) -> Vo + Sync,
+{
+    match (params.is_sequential(), params.iteration_order) {
+        (false, IterationOrder::Ordered) => {
+            let split_vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+            let result = split_vec.x_try_collect_into(orchestrator, params, iter, xap1);
+            result.map(|split_vec| extend_collection(collection, split_vec))
+        }
+        _ => {
+            let (_, result) = prc::collect_partial::x(orchestrator, params, iter, xap1, C::default);
+            result.map(|partials| match collection.len() {
+                0 => merge_partials(partials),
+                _ => extend_collection(collection, merge_partials(partials)),
+            })
+        }
+    }
+}
+
+pub(crate) fn extend_with_idx<O, C>(
+    collection: C,
+    vectors: Vec<Vec<(usize, O)>>,
+    max_idx_inc: Option<usize>,
+    order: IterationOrder,
+) -> C
+where
+    C: MergeableCollection<O>,
+{
+    let mut split_vec = SplitVec::new();
+    push_with_idx(&mut split_vec, vectors, max_idx_inc, order);
+    extend_collection(collection, split_vec)
+}
+
+macro_rules! impl_par_collect_into_core {
+    ([$($g:ident),*] $collection:ty, $item:ty) => {
+        impl<$($g),*> ParCollectIntoCore<$item> for $collection
+        where
+            $collection: MergeableCollection<$item>,
+            $item: Send + Sync,
+        {
+            type BridgePinnedVec = SplitVec<$item>;
+
+            fn empty(_: Option<usize>) -> Self {
+                Self::default()
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                MergeableCollection::reserve(self, additional);
+            }
+
+            fn m_collect_into<R, I, M1>(
+                self,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                map1: M1,
+            ) -> Self
+            where
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                M1: Fn(I::Item) -> $item + Sync,
+            {
+                let xap1 = |x| Some(map1(x));
+                let Ok(collection) = x_try_collect_into(self, orchestrator, params, iter, xap1);
+                collection
+            }
+
+            fn x_collect_into<R, I, Vo, X1>(
+                self,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                xap1: X1,
+            ) -> Self
+            where
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                Vo: TransformableValues<Item = $item, Fallibility = Infallible>,
+                X1: Fn(I::Item) -> Vo + Sync,
+            {
+                let Ok(collection) = x_try_collect_into(self, orchestrator, params, iter, xap1);
+                collection
+            }
+
+            fn x_try_collect_into<R, I, Vo, X1>(
+                self,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                xap1: X1,
+            ) -> Result<Self, <Vo::Fallibility as Fallibility>::Error>
+            where
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                X1: Fn(I::Item) -> Vo + Sync,
+                Vo: Values<Item = $item>,
+                Self: Sized,
+            {
+                x_try_collect_into(self, orchestrator, params, iter, xap1)
+            }
+
+            fn extend_with_idx(
+                self,
+                vectors: Vec<Vec<(usize, $item)>>,
+                max_idx_inc: Option<usize>,
+                order: IterationOrder,
+            ) -> Self {
+                extend_with_idx(self, vectors, max_idx_inc, order)
+            }
+
+            fn extend_from_vecs(self, vectors: Vec<Vec<$item>>) -> Self {
+                extend_collection(self, vectors.into_iter().flatten())
+            }
+
+            // test
+
+            #[cfg(test)]
+            fn length(&self) -> usize {
+                MergeableCollection::len(self)
+            }
+        }
+    };
+}
+
+impl_par_collect_into_core!([K, V] BTreeMap<K, V>, (K, V));
+impl_par_collect_into_core!([T] BTreeSet<T>, T);
+#[cfg(feature = "std")]
+impl_par_collect_into_core!([K, V, S] HashMap<K, V, S>, (K, V));
+#[cfg(feature = "std")]
+impl_par_collect_into_core!([T, S] HashSet<T, S>, T);
@@ -1,9 +1,12 @@
 pub(crate) mod collect;
+pub(crate) mod extendable;
 mod fixed_vec;
+pub(crate) mod mergeable;
 mod par_collect_into;
 mod split_vec;
+pub(crate) mod string;
 pub(crate) mod utils;
 mod vec;
 
-pub use par_collect_into::ParCollectInto;
 pub(crate) use par_collect_into::ParCollectIntoCore;
+pub use par_collect_into::{ParCollectInto, ParCollectTarget};
@@ -1,17 +1,21 @@
-use crate::Params;
 use crate::generic_values::runner_results::{Fallibility, Infallible};
 use crate::generic_values::{TransformableValues, Values};
 use crate::runner::ParallelRunner;
 use crate::using::UParCollectIntoCore;
+use crate::{IterationOrder, Params};
+use alloc::vec::Vec;
 use orx_concurrent_iter::ConcurrentIter;
 use orx_iterable::Collection;
 use orx_pinned_vec::IntoConcurrentPinnedVec;
 
-pub trait ParCollectIntoCore<O>: Collection<Item = O> {
+pub trait ParCollectIntoCore<O> {
     type BridgePinnedVec: IntoConcurrentPinnedVec<O>;
 
     fn empty(iter_len: Option<usize>) -> Self;
 
+    /// Reserves capacity for at least `additional` more elements to be collected into the collection.
+    fn reserve(&mut self, additional: usize);
+
     fn m_collect_into<R, I, M1>(self, orchestrator: R, params: Params, iter: I, map1: M1) -> Self
     where
         R: ParallelRunner,
@@ -45,6 +49,20 @@ pub trait ParCollectIntoCore<O>: Collection<Item = O> {
         Vo: Values<Item = O>,
         Self: Sized;
 
+    /// Pushes the values collected by threads together with their indices to the end of the collection,
+    /// skipping the values with indices greater than `max_idx_inc`.
+    ///
+    /// Values are pushed in the order of their indices when `order` is `IterationOrder::Ordered`.
+    fn extend_with_idx(
+        self,
+        vectors: Vec<Vec<(usize, O)>>,
+        max_idx_inc: Option<usize>,
+        order: IterationOrder,
+    ) -> Self;
+
+    /// Pushes all values of the `vectors` to the end of the collection, in the given order.
+    fn extend_from_vecs(self, vectors: Vec<Vec<O>>) -> Self;
+
     // test
 
     #[cfg(test)]
@@ -59,6 +77,7 @@ pub trait ParCollectIntoCore<O>: Collection<Item = O> {
     fn is_equal_to<'a>(&self, b: impl orx_iterable::Iterable<Item = &'a O>) -> bool
     where
         O: PartialEq + 'a,
+        Self: Collection<Item = O>,
     {
         let mut b = b.iter();
         for x in self.iter() {
@@ -76,6 +95,7 @@ pub trait ParCollectIntoCore<O>: Collection<Item = O> {
     fn is_equal_to_ref(&self, b: impl orx_iterable::Iterable<Item = O>) -> bool
     where
         O: PartialEq,
+        Self: Collection<Item = O>,
     {
         let mut b = b.iter();
         for x in self.iter() {
@@ -91,6 +111,17 @@ pub trait ParCollectIntoCore<O>: Collection<Item = O> {
 }
 
 /// Collection types into which outputs of a parallel computations can be collected into.
-pub trait ParCollectInto<O>: ParCollectIntoCore<O> + UParCollectIntoCore<O> {}
+pub trait ParCollectInto<O>: ParCollectTarget<O> + Collection<Item = O> {}
+
+impl<O, C> ParCollectInto<O> for C where C: ParCollectTarget<O> + Collection<Item = O> {}
+
+/// Types into which outputs of a parallel computation can be collected by [`collect`] and [`collect_into`].
+///
+/// These are all collections implementing [`ParCollectInto`] together with the maps, such as `HashMap`
+/// and `BTreeMap`, which are not a [`Collection`] of their items.
+///
+/// [`collect`]: crate::ParIter::collect
+/// [`collect_into`]: crate::ParIter::collect_into
+pub trait ParCollectTarget<O>: ParCollectIntoCore<O> + UParCollectIntoCore<O> {}
 
-impl<O, C> ParCollectInto<O> for C where C: ParCollectIntoCore<O> + UParCollectIntoCore<O> {}
+impl<O, C> ParCollectTarget<O> for C where C: ParCollectIntoCore<O> + UParCollectIntoCore<O> {}
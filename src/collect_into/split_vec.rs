@@ -1,12 +1,12 @@
 use super::collect::{map_collect_into, xap_collect_into, xap_try_collect_into};
 use super::par_collect_into::ParCollectIntoCore;
-use crate::Params;
-use crate::collect_into::utils::split_vec_reserve;
+use crate::collect_into::utils::{push_with_idx, split_vec_reserve};
 use crate::generic_values::runner_results::{Fallibility, Infallible};
 use crate::generic_values::{TransformableValues, Values};
 use crate::runner::ParallelRunner;
+use crate::{IterationOrder, Params};
+use alloc::vec::Vec;
 use orx_concurrent_iter::ConcurrentIter;
-#[cfg(test)]
 use orx_pinned_vec::PinnedVec;
 use orx_split_vec::{GrowthWithConstantTimeAccess, PseudoDefault, SplitVec};
 
@@ -24,6 +24,10 @@ where
         vec
     }
 
+    fn reserve(&mut self, additional: usize) {
+        split_vec_reserve(self, false, Some(additional));
+    }
+
     fn m_collect_into<R, I, M1>(
         mut self,
         orchestrator: R,
@@ -79,6 +83,23 @@ where
         result
     }
 
+    fn extend_with_idx(
+        mut self,
+        vectors: Vec<Vec<(usize, O)>>,
+        max_idx_inc: Option<usize>,
+        order: IterationOrder,
+    ) -> Self {
+        push_with_idx(&mut self, vectors, max_idx_inc, order);
+        self
+    }
+
+    fn extend_from_vecs(mut self, vectors: Vec<Vec<O>>) -> Self {
+        for x in vectors.into_iter().flatten() {
+            self.push(x);
+        }
+        self
+    }
+
     // test
 
     #[cfg(test)]
@@ -0,0 +1,120 @@
+use super::par_collect_into::ParCollectIntoCore;
+use crate::collect_into::utils::push_with_idx;
+use crate::executor::parallel_compute as prc;
+use crate::generic_values::runner_results::{Fallibility, Infallible};
+use crate::generic_values::{TransformableValues, Values};
+use crate::runner::ParallelRunner;
+use crate::{IterationOrder, Params};
+use alloc::string::String;
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+use orx_split_vec::SplitVec;
+
+/// Appends the string `buffers` to the end of the `string` in the given order.
+pub(crate) fn concat_buffers(mut string: String, buffers: Vec<String>) -> String {
+    let mut buffers = buffers.into_iter();
+    if string.is_empty()
+        && let Some(first) = buffers.next()
+    {
+        string = first;
+    }
+
+    string.reserve(buffers.as_slice().iter().map(String::len).sum());
+    for buffer in buffers {
+        string.push_str(&buffer);
+    }
+    string
+}
+
+impl<O> ParCollectIntoCore<O> for String
+where
+    String: Extend<O>,
+    O: Send + Sync,
+{
+    type BridgePinnedVec = SplitVec<O>;
+
+    fn empty(_: Option<usize>) -> Self {
+        String::new()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        String::reserve(self, additional);
+    }
+
+    fn m_collect_into<R, I, M1>(self, orchestrator: R, params: Params, iter: I, map1: M1) -> Self
+    where
+        R: ParallelRunner,
+        I: ConcurrentIter,
+        M1: Fn(I::Item) -> O + Sync,
+    {
+        let xap1 = |x| Some(map1(x));
+        let Ok(string) = self.x_try_collect_into(orchestrator, params, iter, xap1);
+        string
+    }
+
+    fn x_collect_into<R, I, Vo, X1>(
+        self,
+        orchestrator: R,
+        params: Params,
+        iter: I,
+        xap1: X1,
+    ) -> Self
+    where
+        R: ParallelRunner,
+        I: ConcurrentIter,
+        Vo: TransformableValues<Item = O, Fallibility = Infallible>,
+        X1: Fn(I::Item) -> Vo + Sync,
+    {
+        let Ok(string) = self.x_try_collect_into(orchestrator, params, iter, xap1);
+        string
+    }
+
+    fn x_try_collect_into<R, I, Vo, X1>(
+        self,
+        orchestrator: R,
+        params: Params,
+        iter: I,
+        xap1: X1,
+    ) -> Result<Self, <Vo::Fallibility as Fallibility>::Error>
+    where
+        R: ParallelRunner,
+        I: ConcurrentIter,
+        X1: Fn(I::Item) -> Vo + Sync,
+        Vo: Values<Item = O>,
+        Self: Sized,
+    {
+        // each thread appends to its own buffers which are concatenated at the end;
+        // in order to respect the order, a thread starts a new buffer whenever it jumps to a later position
+        let (_, result) = match (params.is_sequential(), params.iteration_order) {
+            (false, IterationOrder::Ordered) => {
+                prc::collect_runs::x(orchestrator, params, iter, xap1, String::new)
+            }
+            _ => prc::collect_partial::x(orchestrator, params, iter, xap1, String::new),
+        };
+        result.map(|buffers| concat_buffers(self, buffers))
+    }
+
+    fn extend_with_idx(
+        mut self,
+        vectors: Vec<Vec<(usize, O)>>,
+        max_idx_inc: Option<usize>,
+        order: IterationOrder,
+    ) -> Self {
+        let mut split_vec = SplitVec::new();
+        push_with_idx(&mut split_vec, vectors, max_idx_inc, order);
+        self.extend(split_vec);
+        self
+    }
+
+    fn extend_from_vecs(mut self, vectors: Vec<Vec<O>>) -> Self {
+        self.extend(vectors.into_iter().flatten());
+        self
+    }
+
+    // test
+
+    #[cfg(test)]
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
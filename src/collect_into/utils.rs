@@ -1,3 +1,5 @@
+use crate::IterationOrder;
+use crate::heap_sort::heap_sort_into;
 use alloc::vec::Vec;
 use orx_pinned_vec::PinnedVec;
 use orx_split_vec::{GrowthWithConstantTimeAccess, SplitVec};
@@ -37,3 +39,26 @@ pub fn split_vec_reserve<T, G: GrowthWithConstantTimeAccess>(
         Some(len) => split_vec.reserve_maximum_concurrent_capacity(split_vec.len() + len),
     };
 }
+
+pub fn push_with_idx<T, P>(
+    output: &mut P,
+    vectors: Vec<Vec<(usize, T)>>,
+    max_idx_inc: Option<usize>,
+    order: IterationOrder,
+) where
+    P: PinnedVec<T>,
+{
+    match order {
+        IterationOrder::Ordered => heap_sort_into(vectors, max_idx_inc, output),
+        IterationOrder::Arbitrary => {
+            let max_idx_or_inf = max_idx_inc.unwrap_or(usize::MAX);
+            for vec in vectors {
+                for (idx, value) in vec {
+                    if idx <= max_idx_or_inf {
+                        output.push(value);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use super::par_collect_into::ParCollectIntoCore;
-use crate::Params;
 use crate::collect_into::collect::map_collect_into;
-use crate::collect_into::utils::extend_vec_from_split;
+use crate::collect_into::utils::{extend_vec_from_split, push_with_idx};
 use crate::generic_values::runner_results::{Fallibility, Infallible};
 use crate::generic_values::{TransformableValues, Values};
 use crate::runner::ParallelRunner;
+use crate::{IterationOrder, Params};
 use alloc::vec::Vec;
 use orx_concurrent_iter::ConcurrentIter;
 use orx_fixed_vec::FixedVec;
@@ -23,6 +23,10 @@ where
         }
     }
 
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+
     fn m_collect_into<R, I, M1>(
         mut self,
         orchestrator: R,
@@ -88,6 +92,26 @@ where
         result.map(|split_vec| extend_vec_from_split(self, split_vec))
     }
 
+    fn extend_with_idx(
+        mut self,
+        vectors: Vec<Vec<(usize, O)>>,
+        max_idx_inc: Option<usize>,
+        order: IterationOrder,
+    ) -> Self {
+        self.reserve(vectors.iter().map(|x| x.len()).sum());
+        let mut fixed_vec = FixedVec::from(self);
+        push_with_idx(&mut fixed_vec, vectors, max_idx_inc, order);
+        Vec::from(fixed_vec)
+    }
+
+    fn extend_from_vecs(mut self, vectors: Vec<Vec<O>>) -> Self {
+        self.reserve(vectors.iter().map(|x| x.len()).sum());
+        for vec in vectors {
+            self.extend(vec);
+        }
+        self
+    }
+
     // test
 
     #[cfg(test)]
@@ -1,5 +1,5 @@
 use crate::{
-    ChunkSize, IterationOrder, NumThreads, ParCollectInto, ParIterResult,
+    ChunkSize, IterationOrder, NumThreads, ParCollectTarget, ParIterResult,
     par_iter_option::{ParIterOption, ResultIntoOption},
     runner::{DefaultRunner, ParallelRunner},
 };
@@ -104,12 +104,26 @@ where
         ParOption::new(self.par.inspect(operation))
     }
 
+    fn chain<Q, C>(self, other: C) -> impl ParIterOption<R, Item = Self::Item>
+    where
+        Self: Sized,
+        Q: ParallelRunner,
+        C: ParIterOption<Q, Item = Self::Item>,
+        Self::Item: Send,
+    {
+        ParOption::new(self.par.chain(other.into_fallible_unit_result()))
+    }
+
+    fn into_fallible_unit_result(self) -> impl ParIterResult<R, Item = Self::Item, Err = ()> {
+        self.par
+    }
+
     // collect
 
     fn collect_into<C>(self, output: C) -> Option<C>
     where
         Self::Item: Send,
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         self.par.collect_into(output).into_option()
     }
@@ -117,7 +131,7 @@ where
     fn collect<C>(self) -> Option<C>
     where
         Self::Item: Send,
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         self.par.collect().into_option()
     }
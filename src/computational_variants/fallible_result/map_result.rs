@@ -2,7 +2,7 @@ use crate::computational_variants::ParMap;
 use crate::executor::parallel_compute as prc;
 use crate::par_iter_result::{IntoResult, ParIterResult};
 use crate::runner::{DefaultRunner, ParallelRunner};
-use crate::{IterationOrder, ParCollectInto, ParIter};
+use crate::{IterationOrder, ParCollectTarget, ParIter};
 use core::marker::PhantomData;
 use orx_concurrent_iter::ConcurrentIter;
 
@@ -81,7 +81,7 @@ where
 
     fn collect_into<C>(self, output: C) -> Result<C, Self::Err>
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
         Self::Item: Send,
         Self::Err: Send,
     {
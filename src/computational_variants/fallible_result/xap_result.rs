@@ -4,7 +4,7 @@ use crate::generic_values::TransformableValues;
 use crate::generic_values::runner_results::Infallible;
 use crate::par_iter_result::{IntoResult, ParIterResult};
 use crate::runner::{DefaultRunner, ParallelRunner};
-use crate::{IterationOrder, ParCollectInto, Params};
+use crate::{IterationOrder, ParCollectTarget, Params};
 use core::marker::PhantomData;
 use orx_concurrent_iter::ConcurrentIter;
 
@@ -92,7 +92,7 @@ where
 
     fn collect_into<C>(self, output: C) -> Result<C, Self::Err>
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
         Self::Item: Send,
         Self::Err: Send,
     {
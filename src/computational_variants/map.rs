@@ -1,12 +1,17 @@
-use super::xap::ParXap;
+use super::{xap::ParXap, xap_parts::XapParts};
 use crate::computational_variants::fallible_result::ParMapResult;
 use crate::executor::parallel_compute as prc;
+use crate::executor::parallel_compute::collect_slice::{InitSlice, UninitSlice, assume_init};
 use crate::generic_values::{Vector, WhilstAtom};
+use crate::iter::{ConIterSkip, ConIterStepBy, ConIterTake};
 use crate::par_iter_result::IntoResult;
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::using::{UParMap, UsingClone, UsingFun};
-use crate::{ChunkSize, IterationOrder, NumThreads, ParCollectInto, ParEnumerate, ParIter, Params};
+use crate::{
+    ChunkSize, IterationOrder, NumThreads, ParCollectTarget, ParEnumerate, ParIter, Params,
+};
 use crate::{ParIterResult, ParIterUsing};
+use core::mem::MaybeUninit;
 use orx_concurrent_iter::ConcurrentIter;
 
 /// A parallel iterator that maps inputs.
@@ -70,6 +75,11 @@ where
         &self.iter
     }
 
+    fn into_xap(self) -> impl XapParts<R, Item = Self::Item> {
+        let (orchestrator, params, iter, m1) = self.destruct();
+        ParXap::new(orchestrator, params, iter, move |x| Some(m1(x)))
+    }
+
     fn params(&self) -> Params {
         self.params
     }
@@ -188,12 +198,30 @@ where
 
     fn collect_into<C>(self, output: C) -> C
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let (orchestrator, params, iter, m1) = self.destruct();
         output.m_collect_into(orchestrator, params, iter, m1)
     }
 
+    fn collect_into_slice(self, slice: &mut [Self::Item])
+    where
+        Self::Item: Send,
+    {
+        let (orchestrator, params, iter, m1) = self.destruct();
+        prc::collect_slice::m(orchestrator, params, iter, m1, InitSlice::new(slice));
+    }
+
+    fn collect_into_uninit_slice(self, slice: &mut [MaybeUninit<Self::Item>]) -> &mut [Self::Item]
+    where
+        Self::Item: Send,
+    {
+        let (orchestrator, params, iter, m1) = self.destruct();
+        prc::collect_slice::m(orchestrator, params, iter, m1, UninitSlice::new(slice));
+        // SAFETY: the computation panics unless each position of the slice is written exactly once
+        unsafe { assume_init(slice) }
+    }
+
     // reduce
 
     fn reduce<Reduce>(self, reduce: Reduce) -> Option<Self::Item>
@@ -217,6 +245,49 @@ where
             IterationOrder::Arbitrary => prc::next_any::m(orchestrator, params, iter, m1).1,
         }
     }
+
+    fn last(self) -> Option<Self::Item>
+    where
+        Self::Item: Send,
+    {
+        self.find_last(|_| true)
+    }
+
+    fn find_last<Predicate>(self, predicate: Predicate) -> Option<Self::Item>
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let (orchestrator, params, iter, m1) = self.destruct();
+        match params.iteration_order {
+            IterationOrder::Ordered => {
+                let (_, last) = prc::last::m(orchestrator, params, iter, m1, predicate);
+                last.map(|x| x.1)
+            }
+            IterationOrder::Arbitrary => {
+                let par = ParMap::new(orchestrator, params, iter, m1);
+                par.find(predicate)
+            }
+        }
+    }
+
+    fn position<Predicate>(self, predicate: Predicate) -> Option<usize>
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let predicate = |(_, x): &(usize, Self::Item)| predicate(x);
+        self.enumerate().find(predicate).map(|(idx, _)| idx)
+    }
+
+    fn rposition<Predicate>(self, predicate: Predicate) -> Option<usize>
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let predicate = |(_, x): &(usize, Self::Item)| predicate(x);
+        self.enumerate().find_last(predicate).map(|(idx, _)| idx)
+    }
 }
 
 impl<I, O, M1, R> ParEnumerate<R> for ParMap<I, O, M1, R>
@@ -230,4 +301,19 @@ where
         let x1 = move |(x, i): (usize, I::Item)| (x, m1(i));
         ParMap::new(orchestrator, params, iter.enumerate(), x1)
     }
+
+    fn skip(self, n: usize) -> impl ParEnumerate<R, Item = Self::Item> {
+        let (orchestrator, params, iter, m1) = self.destruct();
+        ParMap::new(orchestrator, params, ConIterSkip::new(iter, n), m1)
+    }
+
+    fn take(self, n: usize) -> impl ParEnumerate<R, Item = Self::Item> {
+        let (orchestrator, params, iter, m1) = self.destruct();
+        ParMap::new(orchestrator, params, ConIterTake::new(iter, n), m1)
+    }
+
+    fn step_by(self, step: usize) -> impl ParEnumerate<R, Item = Self::Item> {
+        let (orchestrator, params, iter, m1) = self.destruct();
+        ParMap::new(orchestrator, params, ConIterStepBy::new(iter, step), m1)
+    }
 }
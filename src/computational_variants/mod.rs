@@ -11,7 +11,9 @@ pub mod fallible_result;
 mod map;
 mod par;
 mod xap;
+mod xap_parts;
 
 pub use map::ParMap;
 pub use par::Par;
 pub use xap::ParXap;
+pub use xap_parts::XapParts;
@@ -1,14 +1,17 @@
-use super::{map::ParMap, xap::ParXap};
+use super::{map::ParMap, xap::ParXap, xap_parts::XapParts};
 use crate::computational_variants::fallible_result::ParResult;
 use crate::executor::parallel_compute as prc;
+use crate::executor::parallel_compute::collect_slice::{InitSlice, UninitSlice, assume_init};
 use crate::generic_values::{Vector, WhilstAtom};
+use crate::iter::{ConIterSkip, ConIterStepBy, ConIterTake, ConIterZip};
 use crate::par_iter_result::IntoResult;
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::using::{UPar, UsingClone, UsingFun};
 use crate::{
-    ChunkSize, IterationOrder, NumThreads, ParCollectInto, ParIter, Params, default_fns::map_self,
+    ChunkSize, IterationOrder, NumThreads, ParCollectTarget, ParIter, Params, default_fns::map_self,
 };
 use crate::{IntoParIter, ParEnumerate, ParIterResult, ParIterUsing};
+use core::mem::MaybeUninit;
 use orx_concurrent_iter::chain::ChainKnownLenI;
 use orx_concurrent_iter::{ConcurrentIter, ExactSizeConcurrentIter};
 
@@ -70,6 +73,11 @@ where
         &self.iter
     }
 
+    fn into_xap(self) -> impl XapParts<R, Item = Self::Item> {
+        let (orchestrator, params, iter) = self.destruct();
+        ParXap::new(orchestrator, params, iter, Some)
+    }
+
     fn params(&self) -> Params {
         self.params
     }
@@ -179,12 +187,36 @@ where
 
     fn collect_into<C>(self, output: C) -> C
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let (orchestrator, params, iter) = self.destruct();
         output.m_collect_into(orchestrator, params, iter, map_self)
     }
 
+    fn collect_into_slice(self, slice: &mut [Self::Item])
+    where
+        Self::Item: Send,
+    {
+        let (orchestrator, params, iter) = self.destruct();
+        prc::collect_slice::m(orchestrator, params, iter, map_self, InitSlice::new(slice));
+    }
+
+    fn collect_into_uninit_slice(self, slice: &mut [MaybeUninit<Self::Item>]) -> &mut [Self::Item]
+    where
+        Self::Item: Send,
+    {
+        let (orchestrator, params, iter) = self.destruct();
+        prc::collect_slice::m(
+            orchestrator,
+            params,
+            iter,
+            map_self,
+            UninitSlice::new(slice),
+        );
+        // SAFETY: the computation panics unless each position of the slice is written exactly once
+        unsafe { assume_init(slice) }
+    }
+
     // reduce
 
     fn reduce<Reduce>(self, reduce: Reduce) -> Option<Self::Item>
@@ -205,6 +237,45 @@ where
             IterationOrder::Arbitrary => prc::next_any::m(orchestrator, params, iter, map_self).1,
         }
     }
+
+    fn last(self) -> Option<Self::Item> {
+        self.find_last(|_| true)
+    }
+
+    fn find_last<Predicate>(self, predicate: Predicate) -> Option<Self::Item>
+    where
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let (orchestrator, params, iter) = self.destruct();
+        match params.iteration_order {
+            IterationOrder::Ordered => {
+                let (_, last) = prc::last::m(orchestrator, params, iter, map_self, predicate);
+                last.map(|x| x.1)
+            }
+            IterationOrder::Arbitrary => {
+                let par = Par::new(orchestrator, params, iter);
+                par.find(predicate)
+            }
+        }
+    }
+
+    fn position<Predicate>(self, predicate: Predicate) -> Option<usize>
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let predicate = |(_, x): &(usize, Self::Item)| predicate(x);
+        self.enumerate().find(predicate).map(|(idx, _)| idx)
+    }
+
+    fn rposition<Predicate>(self, predicate: Predicate) -> Option<usize>
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let predicate = |(_, x): &(usize, Self::Item)| predicate(x);
+        self.enumerate().find_last(predicate).map(|(idx, _)| idx)
+    }
 }
 
 impl<I, R> Par<I, R>
@@ -216,6 +287,9 @@ where
     ///
     /// The first iterator is required to have a known length for chaining.
     ///
+    /// In order to chain parallel iterators with different transformations, or with a first iterator
+    /// of unknown length, see [`ParIter::chain`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -239,6 +313,37 @@ where
         let iter = iter.chain(other.into_con_iter());
         Par::new(orchestrator, params, iter)
     }
+
+    /// Zips up this and `other` parallel iterators into a single parallel iterator of pairs.
+    ///
+    /// The `i`-th element of the zipped iterator is the pair of `i`-th elements of both
+    /// iterators. Both iterators are required to have a known length for zipping.
+    /// If the lengths differ, the zipped iterator stops as soon as the shorter one is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![1, 2, 3, 4];
+    /// let b = vec![5, 6, 7, 8];
+    ///
+    /// let dot_product: i32 = a.par().zip(&b).map(|(x, y)| x * y).sum();
+    /// assert_eq!(dot_product, 70);
+    ///
+    /// let zipped: Vec<_> = (0..3).into_par().zip(&a).collect();
+    /// assert_eq!(zipped, vec![(0, &1), (1, &2), (2, &3)]);
+    /// ```
+    pub fn zip<C>(self, other: C) -> Par<ConIterZip<I, C::IntoIter>, R>
+    where
+        I: ExactSizeConcurrentIter,
+        C: IntoParIter,
+        C::IntoIter: ExactSizeConcurrentIter,
+    {
+        let (orchestrator, params, iter) = self.destruct();
+        let iter = ConIterZip::new(iter, other.into_con_iter());
+        Par::new(orchestrator, params, iter)
+    }
 }
 
 impl<I, R> ParEnumerate<R> for Par<I, R>
@@ -250,4 +355,19 @@ where
         let (orchestrator, params, iter) = self.destruct();
         Par::new(orchestrator, params, iter.enumerate())
     }
+
+    fn skip(self, n: usize) -> impl ParEnumerate<R, Item = Self::Item> {
+        let (orchestrator, params, iter) = self.destruct();
+        Par::new(orchestrator, params, ConIterSkip::new(iter, n))
+    }
+
+    fn take(self, n: usize) -> impl ParEnumerate<R, Item = Self::Item> {
+        let (orchestrator, params, iter) = self.destruct();
+        Par::new(orchestrator, params, ConIterTake::new(iter, n))
+    }
+
+    fn step_by(self, step: usize) -> impl ParEnumerate<R, Item = Self::Item> {
+        let (orchestrator, params, iter) = self.destruct();
+        Par::new(orchestrator, params, ConIterStepBy::new(iter, step))
+    }
 }
@@ -0,0 +1,152 @@
+use crate::{test_utils::*, *};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+fn key(x: &usize) -> usize {
+    x % 17
+}
+
+fn argmin<T>(
+    iter: impl Iterator<Item = (usize, T)>,
+    key: impl Fn(&T) -> usize,
+) -> Option<(usize, T)> {
+    iter.min_by(|a, b| key(&a.1).cmp(&key(&b.1)).then(a.0.cmp(&b.0)))
+}
+
+fn argmax<T>(
+    iter: impl Iterator<Item = (usize, T)>,
+    key: impl Fn(&T) -> usize,
+) -> Option<(usize, T)> {
+    iter.max_by(|a, b| key(&a.1).cmp(&key(&b.1)).then(b.0.cmp(&a.0)))
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn argmin_argmax_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let seq = || input.iter().copied().map(value).enumerate();
+        let par = || {
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .map(|i| value(*i))
+        };
+
+        assert_eq!(par().argmin_by_key(key), argmin(seq(), key));
+        assert_eq!(par().argmax_by_key(key), argmax(seq(), key));
+        assert_eq!(par().argmin_by_key(|x| *x), argmin(seq(), |x| *x));
+        assert_eq!(par().argmax_by_key(|x| *x), argmax(seq(), |x| *x));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn argmin_argmax_filter(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(value).collect();
+        let filter = |x: &usize| x % 3 == 1;
+        let seq = || input.iter().copied().enumerate().filter(|x| filter(&x.1));
+        let par = || {
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .copied()
+                .filter(filter)
+        };
+
+        assert_eq!(par().argmin_by_key(key), argmin(seq(), key));
+        assert_eq!(par().argmax_by_key(key), argmax(seq(), key));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn argmin_argmax_flat_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let flat_map = |i: usize| [value(i), value(i + n), value(i + 2 * n)];
+        let seq = || {
+            input
+                .iter()
+                .copied()
+                .enumerate()
+                .flat_map(|(idx, i)| flat_map(i).map(|x| (idx, x)))
+        };
+        let par = || {
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .flat_map(|i| flat_map(*i))
+        };
+
+        assert_eq!(par().argmin_by_key(key), argmin(seq(), key));
+        assert_eq!(par().argmax_by_key(key), argmax(seq(), key));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn argmin_argmax_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 3;
+        let seq = || {
+            input
+                .iter()
+                .copied()
+                .take_while(|i| *i < stop)
+                .map(value)
+                .enumerate()
+        };
+        let par = || {
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .take_while(|i| **i < stop)
+                .map(|i| value(*i))
+        };
+
+        assert_eq!(par().argmin_by_key(|x| *x), argmin(seq(), |x| *x));
+        assert_eq!(par().argmax_by_key(|x| *x), argmax(seq(), |x| *x));
+        assert_eq!(par().argmin_by_key(key), argmin(seq(), key));
+        assert_eq!(par().argmax_by_key(key), argmax(seq(), key));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn argmin_argmax_monotone(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let decreasing = |i: &&usize| n - **i;
+        let stop = n / 2;
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+        assert_eq!(
+            par().argmax_by_key(|x| **x),
+            n.checked_sub(1).map(|i| (i, &input[i]))
+        );
+        assert_eq!(
+            par().argmin_by_key(decreasing),
+            n.checked_sub(1).map(|i| (i, &input[i]))
+        );
+
+        let expected = stop.checked_sub(1).map(|i| (i, &input[i]));
+        let output = par().take_while(|i| **i < stop).argmax_by_key(|x| **x);
+        assert_eq!(output, expected);
+        let output = par().take_while(|i| **i < stop).argmin_by_key(decreasing);
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn argmin_argmax_empty() {
+    let input: Vec<usize> = Vec::new();
+    assert_eq!(input.par().num_threads(4).argmin_by_key(|x| **x), None);
+    assert_eq!(input.par().num_threads(4).argmax_by_key(|x| **x), None);
+}
@@ -0,0 +1,202 @@
+use crate::{test_utils::*, *};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+fn group_seq<T: Ord, K: Ord>(
+    iter: impl Iterator<Item = T>,
+    key: impl Fn(&T) -> K,
+) -> BTreeMap<K, Vec<T>> {
+    let mut groups: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for x in iter {
+        groups.entry(key(&x)).or_default().push(x);
+    }
+    groups.values_mut().for_each(|x| x.sort());
+    groups
+}
+
+fn sorted_groups<K: Ord, T: Ord>(
+    groups: impl IntoIterator<Item = (K, Vec<T>)>,
+) -> BTreeMap<K, Vec<T>> {
+    let mut groups: BTreeMap<_, _> = groups.into_iter().collect();
+    groups.values_mut().for_each(|x| x.sort());
+    groups
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn group_by_key_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| value(i).to_string();
+        let key = |x: &String| x.chars().last();
+        let expected = group_seq(input.iter().map(|i| map(*i)), key);
+
+        let output = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| map(*i))
+            .group_by_key(key);
+        assert_eq!(sorted_groups(output), expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn group_by_key_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = 2 * n / 3;
+        let flat_map = |i: usize| [value(i), value(i + n)];
+        let filter = |x: &usize| !x.is_multiple_of(5);
+        // many distinct keys to spread over the shards
+        let key = |x: &usize| *x;
+        let expected = group_seq(
+            input
+                .iter()
+                .copied()
+                .take_while(|i| *i < stop)
+                .flat_map(flat_map)
+                .filter(filter),
+            key,
+        );
+
+        let output = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .take_while(|i| *i < stop)
+            .flat_map(flat_map)
+            .filter(filter)
+            .group_by_key(key);
+        assert_eq!(sorted_groups(output), expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn reduce_by_key_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|i| (value(i) % 37, i)).collect();
+        let mut expected = BTreeMap::new();
+        for (k, v) in &input {
+            *expected.entry(*k).or_insert(0) += v;
+        }
+
+        let output: BTreeMap<_, _> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .reduce_by_key(|x| x.0, |x| x.1, |a, b| a + b)
+            .into_iter()
+            .collect();
+        assert_eq!(output, expected);
+
+        let mut expected = BTreeMap::new();
+        for (k, v) in &input {
+            let max = expected.entry(v % 1000).or_insert(*k);
+            *max = (*max).max(*k);
+        }
+        let output: BTreeMap<_, _> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .reduce_by_key(|x| x.1 % 1000, |x| x.0, |a, b| a.max(b))
+            .into_iter()
+            .collect();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn reduce_by_key_filter_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|i| value(i).to_string()).collect();
+        let filter_map = |x: &String| x.starts_with('1').then(|| x.clone());
+        let mut expected: BTreeMap<usize, String> = BTreeMap::new();
+        for x in input.iter().filter_map(filter_map) {
+            let longest = expected.entry(x.len()).or_default();
+            if x > *longest {
+                *longest = x;
+            }
+        }
+
+        let output: BTreeMap<_, _> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter_map(filter_map)
+            .reduce_by_key(|x| x.len(), |x| x, |a, b| a.max(b))
+            .into_iter()
+            .collect();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[cfg(feature = "std")]
+#[test_matrix(N, NT, CHUNK)]
+fn group_by_key_sharded(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| value(i).to_string();
+        let key = |x: &String| x.len() * 10 + x.chars().last().map(|c| c as usize).unwrap_or(0);
+        let expected = group_seq(input.iter().map(|i| map(*i)), key);
+
+        let shards = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| map(*i))
+            .group_by_key_sharded(key);
+        let num_keys: usize = shards.iter().map(|x| x.len()).sum();
+        assert_eq!(num_keys, expected.len());
+        assert_eq!(sorted_groups(shards.into_iter().flatten()), expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[cfg(feature = "std")]
+#[test_matrix(N, NT, CHUNK)]
+fn reduce_by_key_sharded(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|i| (value(i) % 37, i)).collect();
+        let filter = |x: &&(usize, usize)| x.1 % 3 != 1;
+        let mut expected = BTreeMap::new();
+        for (k, v) in input.iter().filter(filter) {
+            *expected.entry(*k).or_insert(0) += v;
+        }
+
+        let shards = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(filter)
+            .reduce_by_key_sharded(|x| x.0, |x| x.1, |a, b| a + b);
+        let num_keys: usize = shards.iter().map(|x| x.len()).sum();
+        assert_eq!(num_keys, expected.len());
+        let output: BTreeMap<_, _> = shards.into_iter().flatten().collect();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[cfg(feature = "std")]
+#[test_matrix([1, 4])]
+fn reduce_by_key_panic_in_reduce(nt: usize) {
+    let reduce = |a: String, b: String| {
+        assert_ne!(b, "500");
+        a + &b
+    };
+    let result = std::panic::catch_unwind(|| {
+        (0..1000)
+            .par()
+            .num_threads(nt)
+            .reduce_by_key(|x| x % 7, |x| x.to_string(), reduce)
+    });
+    assert!(result.is_err());
+}
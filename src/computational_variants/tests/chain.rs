@@ -0,0 +1,188 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn chain_map_filter_map_collect(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+        let left = a.par().num_threads(nt).chunk_size(chunk).map(|x| x * 3);
+        let right = b
+            .par()
+            .filter_map(|x| x.parse::<usize>().ok().filter(|x| x % 2 == 0));
+        let vec: Vec<_> = left.chain(right).collect();
+
+        let left = a.iter().map(|x| x * 3);
+        let right = b
+            .iter()
+            .filter_map(|x| x.parse::<usize>().ok().filter(|x| x % 2 == 0));
+        let expected: Vec<_> = left.chain(right).collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn chain_par_with_transformed(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..n).collect();
+
+        let left = a.par().num_threads(nt).chunk_size(chunk);
+        let vec: Vec<_> = ParIter::chain(left, b.par().filter(|x| *x % 3 == 0)).collect();
+        let expected: Vec<_> = a.iter().chain(b.iter().filter(|x| *x % 3 == 0)).collect();
+        assert_eq!(vec, expected);
+
+        let left = a.par().num_threads(nt).chunk_size(chunk);
+        let vec: Vec<_> = left.chain(&b).collect();
+        let expected: Vec<_> = a.iter().chain(&b).collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn chain_unknown_len_left(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+        let b: Vec<_> = (0..n).map(|x| (n + x).to_string()).collect();
+
+        let left = a.iter().filter(|x| x.len() < 3).cloned().iter_into_par();
+        let left = left.num_threads(nt).chunk_size(chunk).map(|x| x + "!");
+        let right = b.par().flat_map(|x| [x.clone(), x.clone()]);
+        let vec: Vec<String> = left.chain(right).collect();
+
+        let left = a.iter().filter(|x| x.len() < 3).map(|x| x.clone() + "!");
+        let right = b.iter().flat_map(|x| [x.clone(), x.clone()]);
+        let expected: Vec<_> = left.chain(right).collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn chain_arbitrary(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..n).map(|x| x as u64).collect();
+
+        let left = a.par().num_threads(nt).chunk_size(chunk).map(|x| *x as u64);
+        let left = left.iteration_order(IterationOrder::Arbitrary);
+        let right = b.into_par().filter(|x| x % 3 == 0);
+        let mut vec: Vec<_> = left.chain(right).collect();
+        vec.sort();
+
+        let mut expected: Vec<_> = (0..n as u64)
+            .chain((0..n as u64).filter(|x| x % 3 == 0))
+            .collect();
+        expected.sort();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn chain_reduce(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+
+        let left = a.par().num_threads(nt).chunk_size(chunk).copied();
+        let right = (0..n).into_par().map(|x| x * 2);
+        let sum = ParIter::chain(left, right).sum();
+        assert_eq!(sum, 3 * (0..n).sum::<usize>());
+
+        let left = a
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(|x| *x % 2 == 1);
+        let count = left.chain(a.par().skip(n / 2)).count();
+        assert_eq!(count, n / 2 + (n - n / 2));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn chain_first_find(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..n).map(|x| n + x).collect();
+
+        let left = || a.par().num_threads(nt).chunk_size(chunk).copied();
+        let right = || b.iter().filter(|x| *x % 7 == 0).copied().iter_into_par();
+
+        let first = left().chain(right()).first();
+        assert_eq!(
+            first,
+            a.first()
+                .copied()
+                .or(b.iter().find(|x| *x % 7 == 0).copied())
+        );
+
+        let find = left().chain(right()).find(|x| *x >= n);
+        assert_eq!(find, b.iter().find(|x| *x % 7 == 0).copied());
+
+        let left = left().filter(|x| *x > n);
+        let first = left.chain(right()).first();
+        assert_eq!(first, b.iter().find(|x| *x % 7 == 0).copied());
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn chain_fallible_result(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+        let b: Vec<_> = (0..n).collect();
+
+        let left = || {
+            let par = a.par().num_threads(nt).chunk_size(chunk);
+            par.map(|x| x.parse::<usize>().map_err(|_| x.clone()))
+                .into_fallible_result()
+        };
+        let right = b.par().map(|x| Ok(*x)).into_fallible_result();
+        let vec: Result<Vec<_>, _> = left().chain(right).collect();
+        let expected: Vec<_> = (0..n).chain(0..n).collect();
+        assert_eq!(vec, Ok(expected));
+
+        let right = b.par().map(|x| match *x == n / 2 {
+            true => Err("right".to_string()),
+            false => Ok(*x),
+        });
+        let sum = left().chain(right.into_fallible_result()).sum();
+        match n {
+            0 => assert_eq!(sum, Ok(0)),
+            _ => assert_eq!(sum, Err("right".to_string())),
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn chain_fallible_option(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+
+        let left = || {
+            let par = a.par().num_threads(nt).chunk_size(chunk);
+            par.map(|x| Some(*x)).into_fallible_option()
+        };
+        let right = (0..n)
+            .into_par()
+            .map(|x| Some(x + n))
+            .into_fallible_option();
+        let vec: Option<Vec<_>> = left().chain(right).collect();
+        assert_eq!(vec, Some((0..2 * n).collect()));
+
+        let right = (0..n).into_par().map(|x| (x != n / 3).then_some(x));
+        let count = left().chain(right.into_fallible_option()).count();
+        match n {
+            0 => assert_eq!(count, Some(0)),
+            _ => assert_eq!(count, None),
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
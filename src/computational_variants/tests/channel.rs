@@ -0,0 +1,123 @@
+use crate::{test_utils::*, *};
+use alloc::vec::Vec;
+use std::sync::mpsc::{Receiver, channel, sync_channel};
+use std::thread;
+use test_case::test_matrix;
+
+fn spawn_producer(n: usize) -> (Receiver<usize>, thread::JoinHandle<()>) {
+    let (tx, rx) = sync_channel(64);
+    let producer = thread::spawn(move || {
+        for i in 0..n {
+            tx.send(i).expect("receiver is alive");
+        }
+    });
+    (rx, producer)
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn channel_collect(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let expected: Vec<_> = (0..n).filter(|x| x % 3 != 1).map(|x| x * 2).collect();
+
+        let (rx, producer) = spawn_producer(n);
+        let output: Vec<_> = rx
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(|x| x % 3 != 1)
+            .map(|x| x * 2)
+            .collect();
+        producer.join().expect("producer does not panic");
+        assert_eq!(output, expected);
+
+        let (rx, producer) = spawn_producer(n);
+        let sum: usize = rx.into_par().num_threads(nt).chunk_size(chunk).sum();
+        producer.join().expect("producer does not panic");
+        assert_eq!(sum, (0..n).sum::<usize>());
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn channel_multiple_producers(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let (tx, rx) = channel();
+        let producers: Vec<_> = (0..4)
+            .map(|p| {
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    for i in (p..n).step_by(4) {
+                        tx.send(i).expect("receiver is alive");
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+
+        let mut output: Vec<_> = rx.into_par().num_threads(nt).chunk_size(chunk).collect();
+        for producer in producers {
+            producer.join().expect("producer does not panic");
+        }
+        output.sort();
+        assert_eq!(output, (0..n).collect::<Vec<_>>());
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn channel_early_exit(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        // infinite producer which stops only once the receiver is dropped
+        let (tx, rx) = sync_channel(64);
+        let producer = thread::spawn(move || {
+            let mut i = 0;
+            while tx.send(i).is_ok() {
+                i += 1;
+            }
+        });
+
+        let target = n / 2;
+        let found = rx
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .find(|x| *x == target);
+        assert_eq!(found, Some(target));
+        producer.join().expect("producer does not panic");
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn channel_early_exit_while_idle(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        // producer which keeps the channel connected but empty after sending its elements,
+        // so that the threads are blocked on the channel when the iterator is skipped to its end
+        let (tx, rx) = channel();
+        let (done_tx, done_rx) = channel::<()>();
+        let producer = thread::spawn(move || {
+            for i in 0..n {
+                tx.send(i).expect("receiver is alive");
+            }
+            let _ = done_rx.recv();
+        });
+
+        let target = n - 1;
+        let found = rx
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .find(|x| *x == target);
+        assert_eq!(found, Some(target));
+        done_tx.send(()).expect("producer is alive");
+        producer.join().expect("producer does not panic");
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn channel_empty() {
+    let (tx, rx) = channel::<usize>();
+    drop(tx);
+    assert_eq!(rx.into_par().count(), 0);
+}
@@ -0,0 +1,189 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+use test_case::test_matrix;
+
+const ORDER: [IterationOrder; 2] = [IterationOrder::Ordered, IterationOrder::Arbitrary];
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_into_slice_par(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(value).collect();
+        let expected: Vec<_> = input.iter().collect();
+
+        for order in ORDER {
+            let mut buffer = vec![&0; n];
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .collect_into_slice(&mut buffer);
+            assert_eq!(buffer, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_into_slice_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let map = |i: usize| value(i).to_string();
+        let expected: Vec<_> = (0..n).map(map).collect();
+
+        for order in ORDER {
+            let mut buffer = vec![String::from("initial"); n];
+            (0..n)
+                .into_par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .map(map)
+                .collect_into_slice(&mut buffer);
+            assert_eq!(buffer, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_into_slice_unknown_len(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let map = |i: usize| value(i).to_string();
+        let expected: Vec<_> = (0..n).map(map).collect();
+
+        let mut buffer = vec![String::new(); n];
+        (0..n)
+            .filter(|_| true)
+            .iter_into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(map)
+            .collect_into_slice(&mut buffer);
+        assert_eq!(buffer, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_into_slice_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let flat_map = |i: usize| [value(i), value(i + n)];
+        let filter = |x: &usize| !x.is_multiple_of(5);
+        let expected: Vec<_> = (0..n).flat_map(flat_map).filter(filter).collect();
+
+        for order in ORDER {
+            let mut buffer = vec![0; expected.len()];
+            (0..n)
+                .into_par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .flat_map(flat_map)
+                .filter(filter)
+                .collect_into_slice(&mut buffer);
+            assert_eq!(buffer, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_into_uninit_slice(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let map = |i: usize| value(i).to_string();
+        let expected: Vec<_> = (0..n).map(map).collect();
+
+        let mut buffer: Vec<_> = (0..n).map(|_| MaybeUninit::uninit()).collect();
+        let output = (0..n)
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(map)
+            .collect_into_uninit_slice(&mut buffer);
+        assert_eq!(output, expected.as_slice());
+        output.iter_mut().for_each(|x| x.push('!'));
+        // SAFETY: all elements are initialized and dropped only once
+        output
+            .iter_mut()
+            .for_each(|x| unsafe { core::ptr::drop_in_place(x) });
+
+        let mut buffer: Vec<_> = (0..expected.len()).map(|_| MaybeUninit::uninit()).collect();
+        let output = (0..n)
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter_map(|i| Some(map(i)))
+            .collect_into_uninit_slice(&mut buffer);
+        assert_eq!(output, expected.as_slice());
+        output
+            .iter_mut()
+            .for_each(|x| unsafe { core::ptr::drop_in_place(x) });
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+#[should_panic(expected = "length of the slice must be equal")]
+fn collect_into_slice_len_mismatch_map() {
+    let mut buffer = vec![0; 9];
+    (0..10)
+        .into_par()
+        .num_threads(2)
+        .map(|x| x + 1)
+        .collect_into_slice(&mut buffer);
+}
+
+#[test]
+#[should_panic(expected = "length of the slice must be equal")]
+fn collect_into_slice_len_mismatch_xap() {
+    let mut buffer = vec![0; 10];
+    (0..10)
+        .into_par()
+        .num_threads(2)
+        .filter(|x| x % 3 != 0)
+        .collect_into_slice(&mut buffer);
+}
+
+/// Iterator which reports an incorrect size hint of `(4, Some(4))` while yielding `len` elements.
+struct MisreportedLen {
+    next: usize,
+    len: usize,
+}
+
+impl Iterator for MisreportedLen {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.next < self.len).then(|| {
+            self.next += 1;
+            self.next
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (4, Some(4))
+    }
+}
+
+#[test_matrix([2, 100], [1, 2])]
+#[should_panic]
+fn collect_into_slice_misreported_len(len: usize, nt: usize) {
+    let mut buffer = vec![100; 4];
+    MisreportedLen { next: 0, len }
+        .iter_into_par()
+        .num_threads(nt)
+        .collect_into_slice(&mut buffer);
+}
+
+#[test_matrix([2, 100], [1, 2])]
+#[should_panic]
+fn collect_into_uninit_slice_misreported_len(len: usize, nt: usize) {
+    let mut buffer: Vec<MaybeUninit<usize>> = (0..4).map(|_| MaybeUninit::uninit()).collect();
+    MisreportedLen { next: 0, len }
+        .iter_into_par()
+        .num_threads(nt)
+        .collect_into_uninit_slice(&mut buffer);
+}
@@ -0,0 +1,243 @@
+use crate::{test_utils::*, *};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+use test_case::test_matrix;
+
+const ORDER: [IterationOrder; 2] = [IterationOrder::Ordered, IterationOrder::Arbitrary];
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_btree_map_unique_keys(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| (i, value(i).to_string());
+        let expected: BTreeMap<_, _> = input.iter().map(|i| map(*i)).collect();
+
+        for order in ORDER {
+            let output: BTreeMap<_, _> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .map(|i| map(*i))
+                .collect();
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_btree_map_ordered_last_wins(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| (value(i) % 97, i);
+        let expected: BTreeMap<_, _> = input.iter().map(|i| map(*i)).collect();
+
+        let output: BTreeMap<_, _> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| map(*i))
+            .collect();
+        assert_eq!(output, expected);
+
+        // in arbitrary order, any of the values of a key might win
+        let output: BTreeMap<_, _> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .map(|i| map(*i))
+            .collect();
+        assert_eq!(
+            output.keys().collect::<Vec<_>>(),
+            expected.keys().collect::<Vec<_>>()
+        );
+        assert!(output.iter().all(|(k, i)| map(*i).0 == *k));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_btree_set_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let flat_map = |i: usize| [value(i), value(i + n)];
+        let filter = |x: &usize| !x.is_multiple_of(5);
+        let expected: BTreeSet<_> = input
+            .iter()
+            .copied()
+            .flat_map(flat_map)
+            .filter(filter)
+            .collect();
+
+        for order in ORDER {
+            let output: BTreeSet<_> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .copied()
+                .flat_map(flat_map)
+                .filter(filter)
+                .collect();
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_btree_set_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = 2 * n / 3;
+        let expected: BTreeSet<_> = input
+            .iter()
+            .copied()
+            .take_while(|i| *i < stop)
+            .map(value)
+            .collect();
+
+        let output: BTreeSet<_> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .take_while(|i| *i < stop)
+            .map(value)
+            .collect();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_into_non_empty_btree_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let initial = || -> BTreeMap<_, _> {
+            [
+                (0, "initial".to_string()),
+                (usize::MAX, "initial".to_string()),
+            ]
+            .into_iter()
+            .collect()
+        };
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| (i, value(i).to_string());
+        let mut expected = initial();
+        expected.extend(input.iter().map(|i| map(*i)));
+
+        for order in ORDER {
+            let output = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .map(|i| map(*i))
+                .collect_into(initial());
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_btree_map_fallible(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| (i, value(i));
+        let expected: BTreeMap<_, _> = input.iter().map(|i| map(*i)).collect();
+
+        for order in ORDER {
+            let output: Result<BTreeMap<_, _>, String> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .map(|i| Ok(map(*i)))
+                .into_fallible_result()
+                .collect();
+            assert_eq!(output, Ok(expected.clone()));
+
+            let output: Result<BTreeMap<_, _>, String> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .map(|i| match *i == n / 2 {
+                    true => Err(i.to_string()),
+                    false => Ok(map(*i)),
+                })
+                .into_fallible_result()
+                .collect();
+            assert_eq!(output, Err((n / 2).to_string()));
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[cfg(feature = "std")]
+#[test_matrix(N, NT, CHUNK)]
+fn collect_hash_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let flat_map = |i: usize| [(2 * i, value(i)), (2 * i + 1, value(i + n))];
+        let expected: HashMap<_, _> = input.iter().copied().flat_map(flat_map).collect();
+
+        for order in ORDER {
+            let output: HashMap<_, _> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .copied()
+                .flat_map(flat_map)
+                .collect();
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[cfg(feature = "std")]
+#[test_matrix(N, NT, CHUNK)]
+fn collect_hash_set(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let filter_map = |i: &usize| (!i.is_multiple_of(3)).then(|| value(*i).to_string());
+        let expected: HashSet<_> = input.iter().filter_map(filter_map).collect();
+
+        for order in ORDER {
+            let output: HashSet<_> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .filter_map(filter_map)
+                .collect();
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn collect_sets_into_par_collect_into() {
+    // sets are collections of their items, and hence, can be used wherever ParCollectInto is required
+    fn collect_and_count<C: ParCollectInto<usize>>(output: C) -> usize {
+        (0..100)
+            .par()
+            .map(|x| x % 10)
+            .collect_into(output)
+            .iter()
+            .count()
+    }
+    assert_eq!(collect_and_count(BTreeSet::new()), 10);
+    #[cfg(feature = "std")]
+    assert_eq!(collect_and_count(HashSet::<usize>::new()), 10);
+    assert_eq!(collect_and_count(Vec::new()), 100);
+}
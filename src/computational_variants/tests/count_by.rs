@@ -0,0 +1,144 @@
+use crate::{test_utils::*, *};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+fn count_seq<T, K: Ord>(
+    iter: impl Iterator<Item = T>,
+    key: impl Fn(&T) -> K,
+) -> BTreeMap<K, usize> {
+    let mut counts = BTreeMap::new();
+    for x in iter {
+        *counts.entry(key(&x)).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn histogram_seq<T>(
+    iter: impl Iterator<Item = T>,
+    num_bins: usize,
+    bin: impl Fn(&T) -> usize,
+) -> Vec<usize> {
+    let mut histogram = vec![0; num_bins];
+    for x in iter {
+        if let Some(count) = histogram.get_mut(bin(&x)) {
+            *count += 1;
+        }
+    }
+    histogram
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn count_by_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| value(i).to_string();
+        let key = |x: &String| x.len();
+        let expected = count_seq(input.iter().map(|i| map(*i)), key);
+
+        let output: BTreeMap<_, _> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| map(*i))
+            .count_by(key)
+            .into_iter()
+            .collect();
+        assert_eq!(output, expected);
+
+        let key = |x: &String| x.chars().last();
+        let expected = count_seq(input.iter().map(|i| map(*i)), key);
+        let output: BTreeMap<_, _> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| map(*i))
+            .count_by(key)
+            .into_iter()
+            .collect();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn count_by_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 2;
+        let flat_map = |i: usize| [value(i), value(i + n)];
+        let filter = |x: &usize| !x.is_multiple_of(3);
+        let key = |x: &usize| x % 10;
+        let expected = count_seq(
+            input
+                .iter()
+                .copied()
+                .take_while(|i| *i < stop)
+                .flat_map(flat_map)
+                .filter(filter),
+            key,
+        );
+
+        let output: BTreeMap<_, _> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .take_while(|i| *i < stop)
+            .flat_map(flat_map)
+            .filter(filter)
+            .count_by(key)
+            .into_iter()
+            .collect();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn histogram_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(value).collect();
+        let bin = |x: &usize| x / 100;
+
+        for num_bins in [0, 1, 7, 11, 20] {
+            let expected = histogram_seq(input.iter().copied(), num_bins, bin);
+            let output = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .copied()
+                .histogram(num_bins, bin);
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn histogram_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let flat_map = |i: usize| [value(i), value(i + n), value(i + 2 * n)];
+        let filter = |x: &usize| x.is_multiple_of(2);
+        let bin = |x: &usize| x % 13;
+        let expected = histogram_seq(
+            input.iter().copied().flat_map(flat_map).filter(filter),
+            13,
+            bin,
+        );
+
+        let output = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .flat_map(flat_map)
+            .filter(filter)
+            .histogram(13, bin);
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
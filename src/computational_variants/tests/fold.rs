@@ -0,0 +1,109 @@
+use crate::{test_utils::*, *};
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+fn add_histograms(mut a: Vec<usize>, b: Vec<usize>) -> Vec<usize> {
+    a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+    a
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn fold_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+        let expected = input.iter().fold(vec![0; 10], |mut h, x| {
+            h[x.len()] += 1;
+            h
+        });
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let histogram = par().fold(
+            || vec![0; 10],
+            |mut h, x| {
+                h[x.len()] += 1;
+                h
+            },
+            add_histograms,
+        );
+        assert_eq!(histogram, expected);
+
+        let histogram = par().map(|x| x.len()).fold(
+            || vec![0; 10],
+            |mut h, x| {
+                h[x] += 1;
+                h
+            },
+            add_histograms,
+        );
+        assert_eq!(histogram, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn fold_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let expected: u64 = input
+            .iter()
+            .filter(|x| *x % 3 == 1)
+            .flat_map(|x| [*x as u64, 1])
+            .sum();
+
+        let sum = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(|x| *x % 3 == 1)
+            .flat_map(|x| [*x as u64, 1])
+            .fold(|| 0u64, |a, x| a + x, |a, b| a + b);
+        assert_eq!(sum, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn fold_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 2;
+
+        let (min, max) = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .take_while(|x| **x < stop)
+            .fold(
+                || (usize::MAX, 0),
+                |(a, b), x| (a.min(*x), b.max(*x)),
+                |(a, b), (c, d)| (a.min(c), b.max(d)),
+            );
+
+        let expected = match stop {
+            0 => (usize::MAX, 0),
+            _ => (0, stop - 1),
+        };
+        assert_eq!((min, max), expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn fold_empty() {
+    let input: Vec<usize> = vec![];
+    let acc = input.par().fold(
+        || vec![42],
+        |mut a, x| {
+            a.push(*x);
+            a
+        },
+        |mut a, b| {
+            a.extend(b);
+            a
+        },
+    );
+    assert!(acc.iter().all(|x| *x == 42));
+}
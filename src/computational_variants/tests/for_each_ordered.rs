@@ -0,0 +1,195 @@
+use crate::{test_utils::*, *};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use std::io::{self, Write};
+use test_case::test_matrix;
+
+/// A writer that fails once more than `capacity` bytes are written.
+struct BoundedWriter {
+    bytes: Vec<u8>,
+    capacity: usize,
+}
+
+impl Write for BoundedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.bytes.len() + buf.len() > self.capacity {
+            true => Err(io::Error::other("capacity exceeded")),
+            false => {
+                self.bytes.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn for_each_ordered_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let expected: Vec<_> = input.iter().map(|x| x * 3).collect();
+
+        let mut output = vec![];
+        input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|x| x * 3)
+            .for_each_ordered(|x| output.push(x));
+        assert_eq!(output, expected);
+
+        let mut output = vec![];
+        input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .map(|x| x * 3)
+            .for_each_ordered(|x| output.push(x));
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK, [1, 7, 100])]
+fn for_each_ordered_xap_with_window(n: &[usize], nt: &[usize], chunk: &[usize], window: usize) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let filter = |x: &usize| x % 5 != 2;
+        let flat_map = |x: usize| vec![x; x % 3];
+        let expected: Vec<_> = input
+            .iter()
+            .copied()
+            .filter(filter)
+            .flat_map(flat_map)
+            .collect();
+
+        let mut output = vec![];
+        input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .filter(filter)
+            .flat_map(flat_map)
+            .for_each_ordered_with_window(window, |x| output.push(x));
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn for_each_ordered_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 2;
+        let expected: Vec<_> = input.iter().copied().take_while(|x| *x != stop).collect();
+
+        let mut output = vec![];
+        input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .take_while(|x| *x != stop)
+            .for_each_ordered_with_window(16, |x| output.push(x));
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK, [1, 7, 100])]
+fn for_each_ordered_flat_map_while(n: &[usize], nt: &[usize], chunk: &[usize], window: usize) {
+    let test = |n: usize, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 3;
+        let flat_map = |x: usize| [x, x + n, x + 2 * n];
+        let expected: Vec<_> = input
+            .iter()
+            .copied()
+            .flat_map(flat_map)
+            .take_while(|x| *x != stop + n)
+            .collect();
+
+        let mut output = vec![];
+        input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .flat_map(flat_map)
+            .take_while(|x| *x != stop + n)
+            .for_each_ordered_with_window(window, |x| output.push(x));
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn write_ordered(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let format = |x: usize| format!("{x}\n");
+        let expected: String = input.iter().map(|x| format(*x)).collect();
+
+        let mut output: Vec<u8> = vec![];
+        let result = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .write_ordered(&mut output, format);
+        assert!(result.is_ok());
+        assert_eq!(String::from_utf8(output).expect("is utf8"), expected);
+
+        let capacity = expected.len() / 3;
+        let mut writer = BoundedWriter {
+            bytes: vec![],
+            capacity,
+        };
+        let result = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .write_ordered(&mut writer, format);
+        assert!(result.is_err());
+        let written = String::from_utf8(writer.bytes).expect("is utf8");
+        assert!(expected.starts_with(&written));
+        assert!(written.len() <= capacity);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+#[should_panic]
+fn for_each_ordered_zero_window() {
+    (0..10).par().for_each_ordered_with_window(0, |_| {});
+}
+
+#[test_matrix([1, 4], [1, 4])]
+#[should_panic]
+fn for_each_ordered_panic_in_map(nt: usize, window: usize) {
+    (0..1000)
+        .par()
+        .num_threads(nt)
+        .map(|x| {
+            assert_ne!(x, 500);
+            x
+        })
+        .for_each_ordered_with_window(window, |_| {});
+}
+
+#[test_matrix([1, 4], [1, 4])]
+#[should_panic]
+fn for_each_ordered_panic_in_sink(nt: usize, window: usize) {
+    (0..1000)
+        .par()
+        .num_threads(nt)
+        .for_each_ordered_with_window(window, |x| assert_ne!(x, 500));
+}
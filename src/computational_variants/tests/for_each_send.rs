@@ -0,0 +1,176 @@
+use crate::{test_utils::*, *};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, sync_channel};
+use test_case::test_matrix;
+
+/// Sink collecting the batches, which disconnects after receiving `capacity` elements.
+struct Batches {
+    batches: Mutex<Vec<Vec<usize>>>,
+    capacity: usize,
+}
+
+impl Sink<usize> for Batches {
+    fn send_batch(&self, batch: &mut Vec<usize>) -> bool {
+        let mut batches = self.batches.lock().expect("is not poisoned");
+        let len: usize = batches.iter().map(|x| x.len()).sum();
+        match len + batch.len() > self.capacity {
+            true => false,
+            false => {
+                batches.push(core::mem::take(batch));
+                true
+            }
+        }
+    }
+}
+
+fn sorted(mut x: Vec<usize>) -> Vec<usize> {
+    x.sort();
+    x
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn for_each_send_channel(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let flat_map = |x: &usize| vec![*x; x % 3];
+        let expected: Vec<_> = input.iter().flat_map(flat_map).collect();
+
+        let (tx, rx) = channel();
+        let all_sent = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .flat_map(flat_map)
+            .for_each_send(tx);
+        assert!(all_sent);
+        assert_eq!(sorted(rx.iter().collect()), sorted(expected.clone()));
+
+        let (tx, rx) = sync_channel(16);
+        let output = std::thread::scope(|s| {
+            let consumer = s.spawn(move || rx.iter().collect::<Vec<_>>());
+            let all_sent = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .flat_map(flat_map)
+                .for_each_send(tx);
+            assert!(all_sent);
+            consumer.join().expect("consumer does not panic")
+        });
+        assert_eq!(sorted(output), sorted(expected));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn for_each_send_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let stop = n / 3;
+
+        let (tx, rx) = channel();
+        let all_sent = (0..n)
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .take_while(|x| *x != stop)
+            .for_each_send(tx);
+        assert!(all_sent);
+        let output: Vec<_> = rx.iter().collect();
+        assert!(output.iter().all(|x| *x != stop));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn for_each_send_disconnected(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let num_computed = AtomicUsize::new(0);
+        let (tx, rx) = channel();
+        drop(rx);
+        let all_sent = (0..n)
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .inspect(|_| _ = num_computed.fetch_add(1, Ordering::Relaxed))
+            .for_each_send(tx);
+        assert!(!all_sent);
+        // each thread computes at most one chunk before observing the disconnected sink
+        if nt > 0 && chunk > 0 && nt * chunk < n {
+            assert!(num_computed.load(Ordering::Relaxed) <= nt * chunk);
+        }
+
+        let capacity = n / 2;
+        let batches = Batches {
+            batches: Mutex::new(vec![]),
+            capacity,
+        };
+        let all_sent = (0..n)
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .for_each_send(&batches);
+        assert!(!all_sent);
+        let batches = batches.batches.into_inner().expect("is not poisoned");
+        let output: Vec<_> = batches.into_iter().flatten().collect();
+        assert!(output.len() <= capacity);
+        let output = sorted(output);
+        output.windows(2).for_each(|w| assert!(w[0] < w[1]));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn for_each_send_batch_channel(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        // one message per pulled chunk when the chunk size is fixed
+        let num_chunks = match chunk {
+            0 => None,
+            c => Some(n.div_ceil(c)),
+        };
+        let expected: Vec<_> = (0..n).collect();
+
+        let (tx, rx) = channel();
+        let all_sent = (0..n)
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .for_each_send(BatchSender::new(tx));
+        assert!(all_sent);
+        let batches: Vec<_> = rx.iter().collect();
+        assert!(batches.iter().all(|x| !x.is_empty()));
+        if let Some(num_chunks) = num_chunks {
+            assert_eq!(batches.len(), num_chunks);
+        }
+        assert_eq!(sorted(batches.into_iter().flatten().collect()), expected);
+
+        let (tx, rx) = sync_channel(4);
+        let batches = std::thread::scope(|s| {
+            let consumer = s.spawn(move || rx.iter().collect::<Vec<_>>());
+            let all_sent = (0..n)
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .for_each_send(SyncBatchSender::new(tx));
+            assert!(all_sent);
+            consumer.join().expect("consumer does not panic")
+        });
+        if let Some(num_chunks) = num_chunks {
+            assert_eq!(batches.len(), num_chunks);
+        }
+        assert_eq!(sorted(batches.into_iter().flatten().collect()), expected);
+
+        let (tx, rx) = channel::<Vec<usize>>();
+        drop(rx);
+        let all_sent = (0..n)
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .for_each_send(BatchSender::new(tx));
+        assert!(!all_sent);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
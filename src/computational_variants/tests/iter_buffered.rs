@@ -0,0 +1,83 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_concurrent_iter::{ConcurrentIter, IntoConcurrentIter};
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK, [1, 7, 1024])]
+fn iter_into_par_buffered_collect(n: &[usize], nt: &[usize], chunk: &[usize], buffer_len: usize) {
+    let test = |n, nt, chunk| {
+        let source = move || (0..n).filter(|x| x % 5 != 3).map(|x| x.to_string());
+        let map = |x: String| x.len() + x.parse::<usize>().expect("is a number");
+        let expected: Vec<_> = source().map(map).collect();
+
+        let output: Vec<_> = source()
+            .iter_into_par_buffered(buffer_len)
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(map)
+            .collect();
+        assert_eq!(output, expected);
+
+        let sum: usize = source()
+            .iter_into_par_buffered(buffer_len)
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .map(map)
+            .sum();
+        assert_eq!(sum, expected.iter().sum::<usize>());
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn iter_into_par_buffered_early_exit(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        // infinite source, which is stopped once the computation completes
+        let target = n / 2;
+        let found = (0..)
+            .iter_into_par_buffered(64)
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|x: usize| x * 2)
+            .find(|x| *x == target * 2);
+        assert_eq!(found, Some(target * 2));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn iter_into_par_buffered_dropped() {
+    let par = (0..)
+        .map(|x: usize| x.to_string())
+        .iter_into_par_buffered(8);
+    drop(par);
+}
+
+#[test]
+fn iter_into_par_buffered_into_seq_iter() {
+    let par = (0..100)
+        .map(|x: usize| x.to_string())
+        .iter_into_par_buffered(8);
+    let iter = par.into_con_iter();
+    assert_eq!(iter.next(), Some(0.to_string()));
+    let remaining: Vec<_> = iter.into_seq_iter().collect();
+    assert_eq!(
+        remaining,
+        (1..100).map(|x| x.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[should_panic]
+fn iter_into_par_buffered_source_panics() {
+    let source = (0..1000usize).inspect(|x| assert_ne!(*x, 500, "failed at 500"));
+    let _ = source.iter_into_par_buffered(16).num_threads(2).count();
+}
+
+#[test]
+#[should_panic]
+fn iter_into_par_buffered_zero_buffer() {
+    let _ = (0..10).iter_into_par_buffered(0);
+}
@@ -243,3 +243,135 @@ fn min_max_xap_filter_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
     };
     test_n_nt_chunk(n, nt, chunk, test);
 }
+
+#[test_matrix(N, NT, CHUNK)]
+fn min_max_single_pass(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input = || input::<Vec<_>>(n);
+        let map = |x: String| x.parse::<usize>().expect("is a number");
+        let seq = || input().into_iter().map(&map);
+
+        let par = || {
+            input()
+                .into_par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .map(&map)
+        };
+
+        assert_eq!(par().min_max(), seq().min().zip(seq().max()));
+        assert_eq!(
+            par().min_max_by(cmp),
+            seq().min_by(cmp).zip(seq().max_by(cmp))
+        );
+        assert_eq!(
+            par().min_max_by_key(key),
+            seq().min_by_key(key).zip(seq().max_by_key(key))
+        );
+
+        let strings = || {
+            input()
+                .into_par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .filter(|x| x.starts_with('3'))
+        };
+        let seq_strings = || input().into_iter().filter(|x| x.starts_with('3'));
+        assert_eq!(
+            strings().min_max(),
+            seq_strings().min().zip(seq_strings().max())
+        );
+        assert_eq!(
+            strings()
+                .min_max_by_key(|x| x.len())
+                .map(|(a, b)| (a.len(), b.len())),
+            seq_strings()
+                .map(|x| x.len())
+                .min()
+                .zip(seq_strings().map(|x| x.len()).max())
+        );
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn min_max_single_element(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input = || input::<Vec<_>>(n);
+        let last = (n + 9).to_string();
+
+        let output = input()
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(|x| *x == last)
+            .min_max();
+        assert_eq!(output, Some((last.clone(), last)));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn min_max_fallible(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|i| (i * 7919 + 13) % 1013).collect();
+        let expected = input.iter().min().copied().zip(input.iter().max().copied());
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let output = par().map(|x| Ok::<_, String>(*x)).into_fallible_result();
+        assert_eq!(output.min_max(), Ok(expected));
+
+        let output = par()
+            .map(|x| match *x == 1000 {
+                true => Err("error".to_string()),
+                false => Ok(*x),
+            })
+            .into_fallible_result();
+        assert_eq!(output.min_max_by(cmp), Err("error".to_string()));
+
+        let output = par().map(|x| Some(*x)).into_fallible_option();
+        assert_eq!(output.min_max_by_key(key), Some(expected));
+
+        let output = par()
+            .map(|x| (*x != 1000).then_some(*x))
+            .into_fallible_option();
+        assert_eq!(output.min_max(), None);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn min_max_duplicate_keys(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|i| (value(i), i)).collect();
+        let key = |x: &(usize, usize)| x.0 % 10;
+        let expected = input
+            .iter()
+            .copied()
+            .min_by_key(key)
+            .zip(input.iter().copied().max_by_key(key));
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk).copied();
+        assert_eq!(par().min_max_by_key(key), expected);
+        assert_eq!(par().min_max_by(|a, b| key(a).cmp(&key(b))), expected);
+
+        let output = par().map(Ok::<_, String>).into_fallible_result();
+        assert_eq!(output.min_max_by_key(key), Ok(expected));
+
+        let output = par().map(Some).into_fallible_option();
+        assert_eq!(output.min_max_by_key(key), Some(expected));
+
+        let flat_map = |x: &(usize, usize)| [*x, (x.0 + 1, x.1)];
+        let seq = || input.iter().flat_map(flat_map);
+        let expected = seq().min_by_key(key).zip(seq().max_by_key(key));
+        let output = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .flat_map(flat_map)
+            .min_max_by_key(key);
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
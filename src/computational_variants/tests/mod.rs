@@ -1,17 +1,45 @@
+mod argmin_argmax;
+mod by_key;
+mod chain;
+#[cfg(feature = "std")]
+mod channel;
+mod collect_into_slice;
+mod collect_map_set;
 mod copied;
 mod count;
+mod count_by;
 mod enumerate;
 mod fallible_option;
 mod fallible_result;
 mod flatten;
+mod fold;
 mod for_each;
+#[cfg(feature = "std")]
+mod for_each_ordered;
+#[cfg(feature = "std")]
+mod for_each_send;
 mod inspect;
+#[cfg(feature = "std")]
+mod iter_buffered;
 mod iter_consuming;
 mod iter_ref;
 mod map;
 mod min_max;
+mod par_extend_concurrent;
+mod parallel_extend;
+mod partition_unzip;
+mod position_last;
 mod range;
+mod scan;
+#[cfg(feature = "std")]
+mod seq_iter;
+mod skip_take_step_by;
 mod slice;
+mod sorted;
+mod stats;
+mod string;
 mod sum;
+mod top_k;
 mod vectors;
 mod xap;
+mod zip;
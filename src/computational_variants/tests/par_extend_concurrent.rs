@@ -0,0 +1,75 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+#[cfg(feature = "orx-concurrent-vec")]
+use orx_concurrent_vec::ConcurrentVec;
+use test_case::test_matrix;
+
+fn sorted<T: Ord>(mut values: Vec<T>) -> Vec<T> {
+    values.sort();
+    values
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_extend_bag_successively(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| value(i).to_string();
+        let filter = |x: &String| !x.starts_with('3');
+        let mut expected: Vec<_> = input.iter().map(|i| map(*i)).collect();
+        expected.extend(input.iter().map(|i| map(*i)).filter(filter));
+        expected.extend(input.iter().flat_map(|i| [map(*i), map(i + n)]));
+
+        let bag = ConcurrentBag::new();
+        bag.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .map(|i| map(*i)),
+        );
+        bag.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .map(|i| map(*i))
+                .filter(filter),
+        );
+        bag.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .flat_map(|i| [map(*i), map(i + n)]),
+        );
+
+        assert_eq!(sorted(bag.into_inner().to_vec()), sorted(expected));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[cfg(feature = "orx-concurrent-vec")]
+#[test_matrix(N, NT, CHUNK)]
+fn par_extend_vec_at_the_same_time(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let expected: Vec<_> = (0..2 * n).map(value).collect();
+
+        let vec = ConcurrentVec::new();
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let par = (0..n).par().num_threads(nt).chunk_size(chunk);
+                vec.par_extend(par.map(value));
+            });
+            s.spawn(|| {
+                let input: Vec<_> = (n..2 * n).collect();
+                let par = input.into_par().num_threads(nt).chunk_size(chunk);
+                vec.par_extend(par.map(value));
+            });
+        });
+
+        assert_eq!(sorted(vec.to_vec()), sorted(expected));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
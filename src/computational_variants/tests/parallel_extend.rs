@@ -0,0 +1,171 @@
+use crate::{test_utils::*, *};
+use alloc::collections::{BTreeMap, BinaryHeap, LinkedList, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+use orx_split_vec::SplitVec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_extend_vec(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let mut expected = vec![42, 7];
+        expected.extend(input.iter().map(|x| x * 2));
+        expected.extend(input.iter().filter(|x| *x % 3 == 0).map(|x| x + 1));
+
+        let mut vec = vec![42, 7];
+        vec.par_extend(input.par().num_threads(nt).chunk_size(chunk).map(|x| x * 2));
+        vec.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .filter(|x| *x % 3 == 0)
+                .map(|x| x + 1),
+        );
+        assert_eq!(vec, expected);
+
+        let mut vec = vec![42, 7];
+        vec.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(IterationOrder::Arbitrary)
+                .map(|x| x * 2),
+        );
+        assert_eq!(&vec[..2], &[42, 7]);
+        vec.sort();
+        let mut expected: Vec<_> = [42, 7]
+            .into_iter()
+            .chain(input.iter().map(|x| x * 2))
+            .collect();
+        expected.sort();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_extend_split_vec(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let mut expected = vec![42, 7];
+        expected.extend(input.iter().flat_map(|x| [*x, x + 1]));
+
+        let mut split_vec: SplitVec<_> = [42, 7].into_iter().collect();
+        split_vec.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .flat_map(|x| [*x, x + 1]),
+        );
+        assert_eq!(split_vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_extend_sequential_collections(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let filter = |x: &usize| x % 5 != 1;
+        let mut expected = vec![42, 7];
+        expected.extend(input.iter().copied().filter(filter));
+
+        let mut deque = VecDeque::from([7]);
+        deque.push_front(42);
+        deque.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .copied()
+                .filter(filter),
+        );
+        assert_eq!(deque, expected);
+
+        let mut list = LinkedList::from([42, 7]);
+        list.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .copied()
+                .filter(filter),
+        );
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), expected);
+
+        let mut heap = BinaryHeap::from([42, 7]);
+        heap.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(IterationOrder::Arbitrary)
+                .copied()
+                .filter(filter),
+        );
+        let mut expected_sorted = expected.clone();
+        expected_sorted.sort();
+        assert_eq!(heap.into_sorted_vec(), expected_sorted);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_extend_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let mut expected = BTreeMap::from([(usize::MAX, 0)]);
+        expected.extend(input.iter().map(|x| (x % 17, *x)));
+
+        let mut map = BTreeMap::from([(usize::MAX, 0)]);
+        map.par_extend(
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .map(|x| (x % 17, *x)),
+        );
+        assert_eq!(map, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_extend_reserves_capacity(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+
+        let mut vec = vec![42, 7];
+        vec.par_extend(input.par().num_threads(nt).chunk_size(chunk).copied());
+        assert!(vec.capacity() >= n + 2);
+        assert_eq!(&vec[..2], &[42, 7]);
+        assert_eq!(&vec[2..], &input[..]);
+
+        let mut deque = VecDeque::from([42, 7]);
+        deque.par_extend(input.par().num_threads(nt).chunk_size(chunk).copied());
+        assert!(deque.capacity() >= n + 2);
+        assert_eq!(deque.len(), n + 2);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[cfg(feature = "std")]
+#[test_matrix([1, 4])]
+fn par_extend_panic_leaves_collection_empty(nt: usize) {
+    let mut vec = vec![42, 7];
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.par_extend((0..1000).par().num_threads(nt).map(|x| {
+            assert_ne!(x, 500);
+            x
+        }));
+    }));
+    assert!(result.is_err());
+    assert!(vec.is_empty());
+
+    vec.par_extend((0..10).par().num_threads(nt));
+    assert_eq!(vec, (0..10).collect::<Vec<_>>());
+}
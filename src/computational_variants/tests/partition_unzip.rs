@@ -0,0 +1,143 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_fixed_vec::FixedVec;
+use orx_split_vec::SplitVec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn partition_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let (exp_a, exp_b): (Vec<_>, Vec<_>) = input
+            .iter()
+            .map(|x| x.to_string())
+            .partition(|x| x.len() == 2);
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let (a, b): (Vec<_>, Vec<_>) = par().map(|x| x.to_string()).partition(|x| x.len() == 2);
+        assert_eq!(a, exp_a);
+        assert_eq!(b, exp_b);
+
+        let (a, b): (SplitVec<_>, FixedVec<_>) =
+            par().map(|x| x.to_string()).partition(|x| x.len() == 2);
+        assert_eq!(a.to_vec(), exp_a);
+        assert_eq!(Vec::from(b), exp_b);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn partition_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let (exp_a, exp_b): (Vec<_>, Vec<_>) = input
+            .iter()
+            .filter(|x| *x % 3 != 0)
+            .flat_map(|x| [*x, x * 2])
+            .partition(|x| x % 2 == 0);
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let (a, b): (Vec<_>, SplitVec<_>) = par()
+            .filter(|x| *x % 3 != 0)
+            .flat_map(|x| [*x, x * 2])
+            .partition(|x| x % 2 == 0);
+        assert_eq!(a, exp_a);
+        assert_eq!(b.to_vec(), exp_b);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn partition_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 2;
+        let (exp_a, exp_b): (Vec<_>, Vec<_>) = input
+            .iter()
+            .take_while(|x| **x != stop)
+            .copied()
+            .partition(|x| x % 3 == 0);
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let (a, b): (Vec<_>, Vec<_>) = par()
+            .take_while(|x| **x != stop)
+            .copied()
+            .partition(|x| x % 3 == 0);
+        assert_eq!(a, exp_a);
+        assert_eq!(b, exp_b);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn partition_arbitrary(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let (mut exp_a, mut exp_b): (Vec<_>, Vec<_>) =
+            input.iter().copied().partition(|x| x % 4 == 1);
+
+        let (mut a, mut b): (Vec<_>, Vec<_>) = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .copied()
+            .partition(|x| x % 4 == 1);
+
+        a.sort();
+        b.sort();
+        exp_a.sort();
+        exp_b.sort();
+        assert_eq!(a, exp_a);
+        assert_eq!(b, exp_b);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn unzip_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let (exp_a, exp_b): (Vec<_>, Vec<_>) = input.iter().map(|x| (*x, x.to_string())).unzip();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let (a, b): (Vec<usize>, Vec<String>) = par().map(|x| (*x, x.to_string())).unzip();
+        assert_eq!(a, exp_a);
+        assert_eq!(b, exp_b);
+
+        let (a, b): (FixedVec<usize>, SplitVec<String>) =
+            par().map(|x| (*x, x.to_string())).unzip();
+        assert_eq!(Vec::from(a), exp_a);
+        assert_eq!(b.to_vec(), exp_b);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn unzip_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 3;
+        let (exp_a, exp_b): (Vec<_>, Vec<_>) = input
+            .iter()
+            .filter(|x| *x % 2 == 0)
+            .map_while(|x| (*x < stop).then_some((*x, x * 10)))
+            .unzip();
+
+        let (a, b): (Vec<_>, SplitVec<_>) = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(|x| *x % 2 == 0)
+            .map_while(|x| (*x < stop).then_some((*x, x * 10)))
+            .unzip();
+        assert_eq!(a, exp_a);
+        assert_eq!(b.to_vec(), exp_b);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
@@ -0,0 +1,284 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn last_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+        assert_eq!(par().last(), input.last());
+        assert_eq!(par().map(|x| x.len()).last(), input.last().map(|x| x.len()));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn last_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let last = par().filter(|x| *x % 7 == 3).last();
+        assert_eq!(last, input.iter().rfind(|x| *x % 7 == 3));
+
+        let last = par().flat_map(|x| [*x, 2 * *x]).last();
+        assert_eq!(last, input.iter().flat_map(|x| [*x, 2 * *x]).last());
+
+        let last = par()
+            .map(|x| x.to_string())
+            .filter_map(|x| x.parse::<u32>().ok())
+            .last();
+        assert_eq!(last, input.last().map(|x| *x as u32));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn last_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let stop = n / 2;
+        let last = par()
+            .take_while(|x| **x != stop)
+            .filter(|x| *x % 3 == 0)
+            .last();
+        let expected = input
+            .iter()
+            .take_while(|x| **x != stop)
+            .filter(|x| *x % 3 == 0)
+            .last();
+        assert_eq!(last, expected);
+
+        let last = par()
+            .map_while(|x| (*x < stop).then(|| x.to_string()))
+            .last();
+        let expected = input
+            .iter()
+            .map_while(|x| (*x < stop).then(|| x.to_string()))
+            .last();
+        assert_eq!(last, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn find_last(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        let last = par().find_last(|x| x.ends_with('3'));
+        assert_eq!(last, input.iter().rev().find(|x| x.ends_with('3')));
+
+        let last = par().map(|x| x.len()).find_last(|x| *x == 1);
+        assert_eq!(last, input.iter().map(|x| x.len()).rev().find(|x| *x == 1));
+
+        let last = par()
+            .filter(|x| x.len() == 2)
+            .find_last(|x| x.starts_with('4'));
+        let expected = input
+            .iter()
+            .filter(|x| x.len() == 2)
+            .rev()
+            .find(|x| x.starts_with('4'));
+        assert_eq!(last, expected);
+
+        let last: Option<String> = par().cloned().find_last(|x| x.len() > 100);
+        assert_eq!(last, None);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn position_rposition(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| (x * 7) % 31).collect();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        for target in [0, 5, 30, 31] {
+            let position = par().position(|x| **x == target);
+            assert_eq!(position, input.iter().position(|x| *x == target));
+
+            let rposition = par().rposition(|x| **x == target);
+            assert_eq!(rposition, input.iter().rposition(|x| *x == target));
+
+            let rposition = par().take(n / 2).rposition(|x| **x == target);
+            assert_eq!(rposition, input[..n / 2].iter().rposition(|x| *x == target));
+        }
+
+        let position = par().skip(n / 2).position(|x| *x % 2 == 1);
+        let expected = input.iter().skip(n / 2).position(|x| *x % 2 == 1);
+        assert_eq!(position, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn position_rposition_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| (x * 7) % 31).collect();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+
+        for target in [0, 5, 30, 31] {
+            let position = par().filter(|x| *x % 2 == 0).position(|x| **x == target);
+            let expected = input
+                .iter()
+                .filter(|x| *x % 2 == 0)
+                .position(|x| *x == target);
+            assert_eq!(position, expected);
+
+            let rposition = par().filter(|x| *x % 2 == 0).rposition(|x| **x == target);
+            let expected = input.iter().filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+            let expected = expected.iter().rposition(|x| **x == target);
+            assert_eq!(rposition, expected);
+
+            let position = par().flat_map(|x| [*x; 3]).position(|x| *x == target);
+            let expected = input.iter().flat_map(|x| [*x; 3]).position(|x| x == target);
+            assert_eq!(position, expected);
+
+            let rposition = par()
+                .flat_map(|x| (0..(*x % 4)).map(|_| *x))
+                .rposition(|x| *x == target);
+            let expected = input
+                .iter()
+                .flat_map(|x| (0..(*x % 4)).map(|_| *x))
+                .collect::<Vec<_>>()
+                .iter()
+                .rposition(|x| *x == target);
+            assert_eq!(rposition, expected);
+        }
+
+        let stop = n / 2;
+        let position = par()
+            .take_while(|x| **x != 30 || n < 10)
+            .position(|x| **x == 5);
+        let expected = input
+            .iter()
+            .take_while(|x| **x != 30 || n < 10)
+            .position(|x| *x == 5);
+        assert_eq!(position, expected);
+
+        let rposition = par()
+            .map_while(|x| (*x != stop).then_some(*x))
+            .rposition(|x| *x % 3 == 0);
+        let expected = input
+            .iter()
+            .map_while(|x| (*x != stop).then_some(*x))
+            .collect::<Vec<_>>()
+            .iter()
+            .rposition(|x| *x % 3 == 0);
+        assert_eq!(rposition, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn last_position_fallible(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk);
+        let parse = |x: &String| x.parse::<usize>();
+
+        let last = par().map(parse).into_fallible_result().last();
+        assert_eq!(last, Ok(n.checked_sub(1)));
+
+        let last = par()
+            .map(parse)
+            .into_fallible_result()
+            .find_last(|x| x % 7 == 2);
+        assert_eq!(last, Ok((0..n).rev().find(|x| x % 7 == 2)));
+
+        let position = par()
+            .map(parse)
+            .into_fallible_result()
+            .position(|x| *x > 20);
+        assert_eq!(position, Ok((0..n).position(|x| x > 20)));
+
+        let rposition = par()
+            .map(parse)
+            .into_fallible_result()
+            .filter(|x| x % 2 == 0)
+            .rposition(|x| x % 3 == 0);
+        let expected = (0..n)
+            .filter(|x| x % 2 == 0)
+            .collect::<Vec<_>>()
+            .iter()
+            .rposition(|x| x % 3 == 0);
+        assert_eq!(rposition, Ok(expected));
+
+        let checked = |x: &String| parse(x).ok();
+
+        let last = par().map(checked).into_fallible_option().last();
+        assert_eq!(last, Some(n.checked_sub(1)));
+
+        let position = par()
+            .map(checked)
+            .into_fallible_option()
+            .position(|x| *x > 20);
+        assert_eq!(position, Some((0..n).position(|x| x > 20)));
+
+        let rposition = par()
+            .map(checked)
+            .into_fallible_option()
+            .rposition(|x| *x < 3);
+        assert_eq!(rposition, Some((0..n).rposition(|x| x < 3)));
+
+        let faulty = |x: &String| match x.as_str() {
+            "7" => None,
+            x => x.parse::<usize>().ok(),
+        };
+
+        let rposition = par()
+            .map(faulty)
+            .into_fallible_option()
+            .rposition(|x| *x < 3);
+        match n > 7 {
+            true => assert_eq!(rposition, None),
+            false => assert_eq!(rposition, Some((0..n).rposition(|x| x < 3))),
+        }
+
+        let last = par().map(faulty).into_fallible_option().last();
+        match n {
+            0..8 => assert_eq!(last, Some(n.checked_sub(1))),
+            8 => assert_eq!(last, None),
+            _ => assert!(last == Some(Some(n - 1)) || last.is_none()),
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn last_arbitrary(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+
+        let par = || {
+            let par = input.par().num_threads(nt).chunk_size(chunk);
+            par.iteration_order(IterationOrder::Arbitrary)
+        };
+
+        let any = par().find_last(|x| *x % 5 == 0);
+        match n {
+            0 => assert_eq!(any, None),
+            _ => assert_eq!(any.map(|x| x % 5), Some(0)),
+        }
+
+        let any = par().filter(|x| *x % 5 == 0).last();
+        match n {
+            0 => assert_eq!(any, None),
+            _ => assert_eq!(any.map(|x| x % 5), Some(0)),
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
@@ -0,0 +1,197 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_fixed_vec::FixedVec;
+use orx_split_vec::SplitVec;
+use test_case::test_matrix;
+
+fn inclusive<T: Clone>(input: impl IntoIterator<Item = T>, op: impl Fn(&T, &T) -> T) -> Vec<T> {
+    let mut output: Vec<T> = Vec::new();
+    for x in input {
+        let y = match output.last() {
+            Some(prev) => op(prev, &x),
+            None => x,
+        };
+        output.push(y);
+    }
+    output
+}
+
+fn exclusive<T: Clone>(input: Vec<T>, init: T, op: impl Fn(&T, &T) -> T) -> Vec<T> {
+    let n = input.len();
+    let mut output: Vec<T> = Vec::with_capacity(n);
+    let mut acc = init;
+    for x in input {
+        let next = op(&acc, &x);
+        output.push(acc);
+        acc = next;
+    }
+    output
+}
+
+/// Last 8 characters of the concatenation, which is associative but not commutative.
+fn suffix_concat(a: &String, b: &String) -> String {
+    let s = alloc::format!("{a}{b}");
+    s[s.len().saturating_sub(8)..].to_string()
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn scan_inclusive_par(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n as u64).collect();
+        let expected = inclusive(input.iter().copied(), |a, b| a + b);
+
+        let par = || input.clone().into_par().num_threads(nt).chunk_size(chunk);
+
+        let output: Vec<_> = par().scan_inclusive(|a, b| a + b);
+        assert_eq!(output, expected);
+
+        let output: FixedVec<_> = par().scan_inclusive(|a, b| a + b);
+        assert_eq!(Vec::from(output), expected);
+
+        let output: SplitVec<_> = par().scan_inclusive(|a, b| a + b);
+        assert_eq!(output.to_vec(), expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn scan_inclusive_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| (x % 7).to_string()).collect();
+
+        let concat = suffix_concat;
+        let expected = inclusive(input.iter().map(|x| x.to_string()), concat);
+
+        let output: Vec<_> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|x| x.to_string())
+            .scan_inclusive(concat);
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn scan_exclusive_par(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| x % 5).collect();
+        let expected = exclusive(input.clone(), 10, |a, b| a + b);
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk).copied();
+
+        let output: Vec<_> = par().scan_exclusive(10, |a, b| a + b);
+        assert_eq!(output, expected);
+
+        let output: SplitVec<_> = par().scan_exclusive(10, |a, b| a + b);
+        assert_eq!(output.to_vec(), expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn scan_exclusive_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+
+        let concat = suffix_concat;
+        let init = "x".to_string();
+        let expected = exclusive(
+            input.iter().map(|x| (x % 3).to_string()).collect(),
+            init.clone(),
+            concat,
+        );
+
+        let output: FixedVec<_> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|x| (x % 3).to_string())
+            .scan_exclusive(init, concat);
+        assert_eq!(Vec::from(output), expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn scan_skip_take(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let expected = inclusive(input.iter().skip(3).take(n / 2).copied(), |a, b| a + b);
+
+        let output: Vec<_> = input
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .skip(3)
+            .take(n / 2)
+            .scan_inclusive(|a, b| a + b);
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn scan_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let values = || {
+            input
+                .iter()
+                .filter(|x| *x % 3 != 0)
+                .flat_map(|x| [*x, x % 4])
+        };
+        let expected_inc = inclusive(values(), |a, b| a + b);
+        let expected_exc = exclusive(values().collect(), 7, |a, b| a + b);
+
+        let par = || {
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .filter(|x| *x % 3 != 0)
+                .flat_map(|x| [*x, x % 4])
+        };
+
+        let output: Vec<_> = par().scan_inclusive(|a, b| a + b);
+        assert_eq!(output, expected_inc);
+
+        let output: SplitVec<_> = par().scan_exclusive(7, |a, b| a + b);
+        assert_eq!(output.to_vec(), expected_exc);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn scan_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 2;
+        let values = || {
+            input
+                .iter()
+                .flat_map(|x| [*x, *x])
+                .take_while(|x| *x != stop)
+        };
+        let expected_inc = inclusive(values(), |a, b| a.max(b) + 1);
+        let expected_exc = exclusive(values().collect(), 0, |a, b| a.max(b) + 1);
+
+        let par = || {
+            input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .flat_map(|x| [*x, *x])
+                .take_while(|x| *x != stop)
+        };
+
+        let output: Vec<_> = par().scan_inclusive(|a, b| a.max(b) + 1);
+        assert_eq!(output, expected_inc);
+
+        let output: Vec<_> = par().scan_exclusive(0, |a, b| a.max(b) + 1);
+        assert_eq!(output, expected_exc);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
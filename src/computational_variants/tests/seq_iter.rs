@@ -0,0 +1,149 @@
+use crate::{test_utils::*, *};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK, [1, 64])]
+fn into_ordered_seq_iter(n: &[usize], nt: &[usize], chunk: &[usize], buffer_len: usize) {
+    let test = |n, nt, chunk| {
+        let input = || (0..n).collect::<Vec<_>>();
+        let filter = |x: &usize| x % 7 != 3;
+        let expected: Vec<_> = input().into_iter().filter(filter).map(|x| x * 2).collect();
+
+        let output: Vec<_> = input()
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(filter)
+            .map(|x| x * 2)
+            .into_ordered_seq_iter(buffer_len)
+            .collect();
+        assert_eq!(output, expected);
+
+        let mut output: Vec<_> = input()
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .filter(filter)
+            .map(|x| x * 2)
+            .into_ordered_seq_iter(buffer_len)
+            .collect();
+        output.sort();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK, [IterationOrder::Ordered, IterationOrder::Arbitrary])]
+fn into_ordered_seq_iter_dropped_early(
+    n: &[usize],
+    nt: &[usize],
+    chunk: &[usize],
+    order: IterationOrder,
+) {
+    let test = |n: usize, nt, chunk| {
+        let num_computed = Arc::new(AtomicUsize::new(0));
+        let counter = num_computed.clone();
+        let mut iter = (0..n)
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(order)
+            .map(move |x| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                x
+            })
+            .into_ordered_seq_iter(4);
+
+        let first = iter.next();
+        assert!(first.is_some());
+        if order == IterationOrder::Ordered {
+            assert_eq!(first, Some(0));
+        }
+        drop(iter);
+
+        // threads are paused by the bounded buffer, and hence, the computation is stopped before completion
+        assert!(num_computed.load(Ordering::Relaxed) < n);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix([1, 4], [1, 8], [IterationOrder::Ordered, IterationOrder::Arbitrary])]
+#[should_panic]
+fn into_ordered_seq_iter_propagates_panic(nt: usize, buffer_len: usize, order: IterationOrder) {
+    let iter = (0..1000)
+        .par()
+        .num_threads(nt)
+        .iteration_order(order)
+        .map(|x| {
+            assert_ne!(x, 500, "failed at 500");
+            x
+        })
+        .into_ordered_seq_iter(buffer_len);
+    let _ = iter.count();
+}
+
+#[test]
+#[should_panic]
+fn into_ordered_seq_iter_zero_buffer() {
+    let _ = (0..10).par().into_ordered_seq_iter(0);
+}
+
+#[test_matrix(N, NT, CHUNK, [1, 64])]
+fn with_ordered_seq_iter(n: &[usize], nt: &[usize], chunk: &[usize], buffer_len: usize) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let filter = |x: &&usize| *x % 7 != 3;
+        let expected: Vec<_> = input.iter().filter(filter).map(|x| x * 2).collect();
+
+        let output: Vec<_> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(filter)
+            .map(|x| x * 2)
+            .with_ordered_seq_iter(buffer_len, |iter| iter.collect());
+        assert_eq!(output, expected);
+
+        let first_two: Vec<_> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(filter)
+            .map(|x| x * 2)
+            .with_ordered_seq_iter(buffer_len, |iter| iter.take(2).collect());
+        assert_eq!(
+            first_two,
+            expected.iter().copied().take(2).collect::<Vec<_>>()
+        );
+
+        let mut output: Vec<_> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .filter(filter)
+            .map(|x| x * 2)
+            .with_ordered_seq_iter(buffer_len, |iter| iter.collect());
+        output.sort();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix([1, 4], [1, 8], [IterationOrder::Ordered, IterationOrder::Arbitrary])]
+#[should_panic]
+fn with_ordered_seq_iter_propagates_panic(nt: usize, buffer_len: usize, order: IterationOrder) {
+    let input: Vec<_> = (0..1000).collect();
+    let _ = input
+        .par()
+        .num_threads(nt)
+        .iteration_order(order)
+        .map(|x| {
+            assert_ne!(*x, 500, "failed at 500");
+            x
+        })
+        .with_ordered_seq_iter(buffer_len, |iter| iter.count());
+}
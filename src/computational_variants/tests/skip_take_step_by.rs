@@ -0,0 +1,131 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn skip_take_collect(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+        for (skip, take) in [(0, n), (1, n / 2), (n / 3, n / 3), (n - 7, 100), (n + 1, 5)] {
+            let par = input.par().num_threads(nt).chunk_size(chunk);
+            let vec: Vec<_> = par.skip(skip).take(take).collect();
+            let expected: Vec<_> = input.iter().skip(skip).take(take).collect();
+            assert_eq!(vec, expected);
+
+            let par = input.clone().into_par().num_threads(nt).chunk_size(chunk);
+            let vec: Vec<String> = par.take(take).skip(skip).collect();
+            let expected: Vec<_> = input.iter().take(take).skip(skip).cloned().collect();
+            assert_eq!(vec, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn step_by_collect(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+        for step in [1, 2, 7, 100, n + 1] {
+            let par = input.par().num_threads(nt).chunk_size(chunk);
+            let vec: Vec<_> = par.step_by(step).collect();
+            let expected: Vec<_> = input.iter().step_by(step).collect();
+            assert_eq!(vec, expected);
+
+            let par = input.par().num_threads(nt).chunk_size(chunk);
+            let vec: Vec<_> = par.skip(3).step_by(step).take(50).collect();
+            let expected: Vec<_> = input.iter().skip(3).step_by(step).take(50).collect();
+            assert_eq!(vec, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn skip_take_step_by_reduce(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let par = (0..n).into_par().num_threads(nt).chunk_size(chunk);
+        let sum: usize = par.skip(n / 4).step_by(3).take(n / 2).sum();
+        let expected: usize = (0..n).skip(n / 4).step_by(3).take(n / 2).sum();
+        assert_eq!(sum, expected);
+
+        let par = (0..n).into_par().num_threads(nt).chunk_size(chunk);
+        let count = par.step_by(5).map(|x| x + 1).filter(|x| x % 2 == 0).count();
+        let expected = (0..n)
+            .step_by(5)
+            .map(|x| x + 1)
+            .filter(|x| x % 2 == 0)
+            .count();
+        assert_eq!(count, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn skip_take_step_by_enumerate(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let par = (0..n).into_par().num_threads(nt).chunk_size(chunk);
+        let vec: Vec<_> = par.skip(10).step_by(4).take(n / 10).enumerate().collect();
+        let expected: Vec<_> = (0..n)
+            .skip(10)
+            .step_by(4)
+            .take(n / 10)
+            .enumerate()
+            .collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn skip_take_step_by_arbitrary(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let par = (0..n).into_par().num_threads(nt).chunk_size(chunk);
+        let par = par.iteration_order(IterationOrder::Arbitrary);
+        let mut vec: Vec<_> = par.skip(5).step_by(2).take(n / 3).collect();
+        vec.sort();
+        let expected: Vec<_> = (0..n).skip(5).step_by(2).take(n / 3).collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn skip_take_step_by_unknown_len(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n: usize, nt, chunk| {
+        let iter = (0..n).filter(|x| x % 3 != 0);
+        let par = iter
+            .clone()
+            .iter_into_par()
+            .num_threads(nt)
+            .chunk_size(chunk);
+        let vec: Vec<_> = par.skip(7).step_by(3).take(n / 5).collect();
+        let expected: Vec<_> = iter.skip(7).step_by(3).take(n / 5).collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn skip_take_step_by_len() {
+    let par = (0..100).into_par().skip(10);
+    assert_eq!(par.con_iter().try_get_len(), Some(90));
+
+    let par = (0..100).into_par().take(10);
+    assert_eq!(par.con_iter().try_get_len(), Some(10));
+
+    let par = (0..100).into_par().step_by(7);
+    assert_eq!(par.con_iter().try_get_len(), Some(15));
+
+    let par = (0..100).into_par().skip(3).step_by(7).take(10);
+    assert_eq!(par.con_iter().try_get_len(), Some(10));
+}
+
+#[test]
+#[should_panic]
+fn step_by_zero() {
+    let _ = (0..100).into_par().step_by(0);
+}
@@ -0,0 +1,117 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_fixed_vec::FixedVec;
+use orx_split_vec::SplitVec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn sorted_par(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(value).collect();
+        let mut expected = input.clone();
+        expected.sort();
+
+        let par = || input.par().num_threads(nt).chunk_size(chunk).copied();
+
+        let output: Vec<_> = par().sorted();
+        assert_eq!(output, expected);
+
+        let output: FixedVec<_> = par().sorted();
+        assert_eq!(Vec::from(output), expected);
+
+        let output: SplitVec<_> = par().sorted();
+        assert_eq!(output.to_vec(), expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn sorted_map_filter(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| value(i).to_string();
+        let filter = |x: &String| !x.ends_with('3');
+        let mut expected: Vec<_> = input.iter().copied().map(map).filter(filter).collect();
+        expected.sort();
+
+        let output: Vec<_> = input
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(map)
+            .filter(filter)
+            .sorted();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn sorted_flat_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let flat_map = |i: usize| [value(i), value(i + n)];
+        let mut expected: Vec<_> = input.iter().copied().flat_map(flat_map).collect();
+        expected.sort_by(|a, b| b.cmp(a));
+
+        let output: Vec<_> = input
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .flat_map(flat_map)
+            .sorted_by(|a, b| b.cmp(a));
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn sorted_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 3;
+        let mut expected: Vec<_> = input
+            .iter()
+            .copied()
+            .take_while(|i| *i < stop)
+            .map(value)
+            .collect();
+        expected.sort();
+
+        let output: Vec<_> = input
+            .into_par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .take_while(|i| *i < stop)
+            .map(value)
+            .sorted();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn sorted_by_key_is_stable(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(|i| (value(i) % 17, i)).collect();
+        let mut expected = input.clone();
+        expected.sort_by_key(|x| x.0);
+
+        let output: Vec<_> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .sorted_by_key(|x| x.0);
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn sorted_empty() {
+    let input: Vec<usize> = Vec::new();
+    let output: Vec<_> = input.into_par().num_threads(4).sorted();
+    assert!(output.is_empty());
+}
@@ -0,0 +1,143 @@
+use crate::{test_utils::*, *};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+/// Computes the statistics sequentially with the two-pass algorithm.
+fn expected(
+    values: &[f64],
+) -> (
+    usize,
+    f64,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+) {
+    let count = values.len();
+    let sum: f64 = values.iter().sum();
+    let mean = (count > 0).then(|| sum / count as f64);
+    let variance =
+        mean.map(|m| values.iter().map(|x| (x - m) * (x - m)).sum::<f64>() / count as f64);
+    let min = values.iter().copied().reduce(f64::min);
+    let max = values.iter().copied().reduce(f64::max);
+    (count, sum, mean, variance, min, max)
+}
+
+fn is_close(a: Option<f64>, b: Option<f64>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => (a - b).abs() <= 1e-7 * a.abs().max(b.abs()).max(1.0),
+        _ => false,
+    }
+}
+
+fn assert_stats(stats: Stats, values: &[f64]) {
+    let (count, sum, mean, variance, min, max) = expected(values);
+    assert_eq!(stats.count(), count);
+    assert!(is_close(Some(stats.sum()), Some(sum)));
+    assert!(is_close(stats.mean(), mean));
+    assert!(is_close(stats.variance(), variance));
+    assert_eq!(stats.min(), min);
+    assert_eq!(stats.max(), max);
+    let sample_variance = variance
+        .filter(|_| count > 1)
+        .map(|v| v * count as f64 / (count - 1) as f64);
+    assert!(is_close(stats.sample_variance(), sample_variance));
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn stats_par(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(value).collect();
+        let values: Vec<_> = input.iter().map(|x| *x as f64).collect();
+
+        let stats = input.par().num_threads(nt).chunk_size(chunk).stats();
+        assert_stats(stats, &values);
+
+        let stats = input.into_par().num_threads(nt).chunk_size(chunk).stats();
+        assert_stats(stats, &values);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn stats_map_filter(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| value(i) as f32 / 7.0;
+        let filter = |x: &f32| *x > 50.0;
+        let values: Vec<_> = input
+            .iter()
+            .map(|i| map(*i))
+            .filter(filter)
+            .map(|x| x as f64)
+            .collect();
+
+        let stats = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| map(*i))
+            .filter(filter)
+            .stats();
+        assert_stats(stats, &values);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn stats_flat_map_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 3;
+        let flat_map = |i: usize| [value(i) as i64, -(value(i + n) as i64)];
+        let values: Vec<_> = input
+            .iter()
+            .copied()
+            .take_while(|i| *i < stop)
+            .flat_map(flat_map)
+            .map(|x| x as f64)
+            .collect();
+
+        let stats = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .take_while(|i| *i < stop)
+            .flat_map(flat_map)
+            .stats();
+        assert_stats(stats, &values);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn stats_large_offset(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        // naive sum of squares loses all precision with such an offset
+        let offset = 1e9;
+        let input: Vec<_> = (0..n).map(|i| offset + (value(i) % 10) as f64).collect();
+
+        let stats = input.par().num_threads(nt).chunk_size(chunk).stats();
+        assert_stats(stats, &input);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn stats_empty_and_single() {
+    let empty: Vec<f64> = Vec::new();
+    let stats = empty.par().num_threads(4).stats();
+    assert_eq!(stats, Stats::default());
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.variance(), None);
+    assert_eq!(stats.min(), None);
+
+    let single = alloc::vec![42u8];
+    let stats = single.par().num_threads(4).stats();
+    assert_eq!(stats.count(), 1);
+    assert_eq!(stats.mean(), Some(42.0));
+    assert_eq!(stats.variance(), Some(0.0));
+    assert_eq!(stats.sample_variance(), None);
+}
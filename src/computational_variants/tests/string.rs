@@ -0,0 +1,214 @@
+use crate::{test_utils::*, *};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+fn sorted_chars(s: &str) -> Vec<char> {
+    let mut chars: Vec<_> = s.chars().collect();
+    chars.sort();
+    chars
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_string_from_strings(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| format!("{};", value(i));
+        let expected: String = input.iter().map(|i| map(*i)).collect();
+
+        let output: String = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| map(*i))
+            .collect();
+        assert_eq!(output, expected);
+
+        let output: String = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .map(|i| map(*i))
+            .collect();
+        assert_eq!(sorted_chars(&output), sorted_chars(&expected));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_string_from_str_and_char(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let words: Vec<_> = (0..n).map(|i| value(i).to_string()).collect();
+        let filter = |x: &&String| !x.ends_with('3');
+        let expected: String = words.iter().filter(filter).map(|x| x.as_str()).collect();
+
+        let output: String = words
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(filter)
+            .map(|x| x.as_str())
+            .collect();
+        assert_eq!(output, expected);
+
+        let output: String = words
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(filter)
+            .flat_map(|x| x.chars().collect::<Vec<_>>())
+            .collect();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_string_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = 2 * n / 3;
+        let map = |i: usize| format!("{},", value(i));
+        let expected: String = input
+            .iter()
+            .copied()
+            .take_while(|i| *i < stop)
+            .map(map)
+            .collect();
+
+        let output: String = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .take_while(|i| *i < stop)
+            .map(map)
+            .collect();
+        assert_eq!(output, expected);
+
+        let output: String = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .copied()
+            .map(|i| map(i).chars().collect::<Vec<_>>())
+            .flat_map(|x| x)
+            .take_while(|c| *c != '7')
+            .collect();
+        let expected: String = input
+            .iter()
+            .flat_map(|i| map(*i).chars().collect::<Vec<_>>())
+            .take_while(|c| *c != '7')
+            .collect();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_into_non_empty_string(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| format!("{};", value(i));
+        let expected: String = "initial;"
+            .chars()
+            .chain(
+                input
+                    .iter()
+                    .flat_map(|i| map(*i).chars().collect::<Vec<_>>()),
+            )
+            .collect();
+
+        let output = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| map(*i))
+            .collect_into("initial;".to_string());
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn collect_string_fallible(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| format!("{};", value(i));
+        let expected: String = input.iter().map(|i| map(*i)).collect();
+
+        let output: Result<String, usize> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| Ok(map(*i)))
+            .into_fallible_result()
+            .collect();
+        assert_eq!(output, Ok(expected));
+
+        let output: Result<String, usize> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .map(|i| match *i == n / 2 {
+                true => Err(*i),
+                false => Ok(map(*i)),
+            })
+            .into_fallible_result()
+            .collect();
+        assert_eq!(output, Err(n / 2));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn join(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let words: Vec<_> = (0..n).map(|i| value(i).to_string()).collect();
+        let filter = |x: &&String| !x.ends_with('3');
+        let expected = words.iter().filter(filter).cloned().collect::<Vec<_>>();
+
+        let output = words
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .filter(filter)
+            .join(", ");
+        assert_eq!(output, expected.join(", "));
+
+        let output = words
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .filter(filter)
+            .join(", ");
+        let mut output: Vec<_> = output.split(", ").collect();
+        output.sort();
+        let mut expected: Vec<_> = expected.iter().map(|x| x.as_str()).collect();
+        expected.sort();
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(NT, CHUNK)]
+fn join_empty_and_single(nt: &[usize], chunk: &[usize]) {
+    let test = |_, nt, chunk| {
+        let empty: Vec<String> = Vec::new();
+        let output = empty.par().num_threads(nt).chunk_size(chunk).join(", ");
+        assert_eq!(output, "");
+
+        let single = vec!["xyz"];
+        let output = single.par().num_threads(nt).chunk_size(chunk).join(", ");
+        assert_eq!(output, "xyz");
+
+        let blanks = vec!["", "", ""];
+        let output = blanks.par().num_threads(nt).chunk_size(chunk).join(",");
+        assert_eq!(output, ",,");
+    };
+    test_n_nt_chunk(&[0], nt, chunk, test);
+}
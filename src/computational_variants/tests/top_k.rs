@@ -0,0 +1,101 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn top_k_par(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).map(value).collect();
+        let mut sorted = input.clone();
+        sorted.sort_by(|a, b| b.cmp(a));
+
+        for k in [0, 1, 7, 100, n, n + 10, usize::MAX] {
+            let expected: Vec<_> = sorted.iter().copied().take(k).collect();
+            let output = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .copied()
+                .top_k(k);
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn bottom_k_map_filter(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| value(i).to_string();
+        let filter = |x: &String| !x.ends_with('3');
+        let mut sorted: Vec<_> = input.iter().copied().map(map).filter(filter).collect();
+        sorted.sort();
+
+        for k in [0, 1, 33, 1000, usize::MAX] {
+            let expected: Vec<_> = sorted.iter().take(k).cloned().collect();
+            let output = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .map(|x| map(*x))
+                .filter(filter)
+                .bottom_k(k);
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn top_k_by_key_flat_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let flat_map = |i: usize| [(value(i), i), (value(i + n), i + n)];
+        let key = |x: &(usize, usize)| x.0;
+        let mut sorted: Vec<_> = input.iter().copied().flat_map(flat_map).collect();
+        sorted.sort_by_key(|x| core::cmp::Reverse(x.0));
+
+        for k in [1, 10, 250] {
+            let expected: Vec<_> = sorted.iter().take(k).map(key).collect();
+            let output = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .flat_map(|x| flat_map(*x))
+                .top_k_by_key(k, key);
+            let output_keys: Vec<_> = output.iter().map(key).collect();
+            assert_eq!(output_keys, expected);
+            // elements with equal keys may be any of the ties, but they must be from the input
+            assert!(output.iter().all(|x| value(x.1) == x.0));
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn top_k_take_while(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let stop = n / 3;
+        let mut sorted: Vec<_> = input
+            .iter()
+            .copied()
+            .take_while(|i| *i < stop)
+            .map(value)
+            .collect();
+        sorted.sort_by(|a, b| b.cmp(a));
+        let expected: Vec<_> = sorted.into_iter().take(50).collect();
+
+        let output = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .take_while(|i| **i < stop)
+            .map(|i| value(*i))
+            .top_k(50);
+        assert_eq!(output, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
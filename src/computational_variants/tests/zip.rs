@@ -0,0 +1,92 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn zip_collect(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+        let b: Vec<_> = (0..n).map(|x| x * 2).collect();
+
+        let par = a.par().num_threads(nt).chunk_size(chunk).zip(&b);
+        let vec: Vec<_> = par.collect();
+
+        let expected: Vec<_> = a.iter().zip(&b).collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn zip_different_lengths(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..(n / 2)).map(|x| x.to_string()).collect();
+
+        let par = a.par().num_threads(nt).chunk_size(chunk).zip(b.clone());
+        let vec: Vec<(&usize, String)> = par.collect();
+
+        let expected: Vec<_> = a.iter().zip(b).collect();
+        assert_eq!(vec, expected);
+
+        let par = (0..(n / 3)).into_par().num_threads(nt).chunk_size(chunk);
+        let count = par.zip(&a).count();
+        assert_eq!(count, n / 3);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn zip_map_reduce(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..n).map(|x| x + 1).collect();
+
+        let par = a.par().num_threads(nt).chunk_size(chunk);
+        let sum: usize = par.zip(&b).map(|(x, y)| x * y).sum();
+
+        let expected: usize = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+        assert_eq!(sum, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn zip_zip_map_filter(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+        let par = a.par().num_threads(nt).chunk_size(chunk);
+        let par = par.zip(&b).zip(0..n).map(|((x, y), z)| (x + 1, y, z));
+        let vec: Vec<_> = par.filter(|(x, _, _)| x % 3 == 0).collect();
+
+        let expected: Vec<_> = a
+            .iter()
+            .zip(&b)
+            .zip(0..n)
+            .map(|((x, y), z)| (x + 1, y, z))
+            .filter(|(x, _, _)| x % 3 == 0)
+            .collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn zip_arbitrary(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..n).map(|x| x + 10).collect();
+
+        let par = a.par().num_threads(nt).chunk_size(chunk);
+        let par = par.iteration_order(IterationOrder::Arbitrary).zip(&b);
+        let mut vec: Vec<_> = par.collect();
+        vec.sort();
+
+        vec.iter().for_each(|(x, y)| assert_eq!(**x + 10, **y));
+        assert_eq!(vec.len(), n);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
@@ -1,3 +1,4 @@
+use super::xap_parts::XapParts;
 use crate::computational_variants::fallible_result::ParXapResult;
 use crate::executor::parallel_compute as prc;
 use crate::generic_values::TransformableValues;
@@ -5,7 +6,7 @@ use crate::generic_values::runner_results::Infallible;
 use crate::par_iter_result::IntoResult;
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::using::{UParXap, UsingClone, UsingFun};
-use crate::{ChunkSize, IterationOrder, NumThreads, ParCollectInto, ParIter, Params};
+use crate::{ChunkSize, IterationOrder, NumThreads, ParCollectTarget, ParIter, Params};
 use crate::{ParIterResult, ParIterUsing};
 use orx_concurrent_iter::ConcurrentIter;
 
@@ -77,6 +78,10 @@ where
         &self.iter
     }
 
+    fn into_xap(self) -> impl XapParts<R, Item = Self::Item> {
+        self
+    }
+
     fn params(&self) -> Params {
         self.params
     }
@@ -208,7 +213,7 @@ where
 
     fn collect_into<C>(self, output: C) -> C
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let (orchestrator, params, iter, x1) = self.destruct();
         output.x_collect_into(orchestrator, params, iter, x1)
@@ -244,4 +249,21 @@ where
             }
         }
     }
+
+    fn last(self) -> Option<Self::Item>
+    where
+        Self::Item: Send,
+    {
+        let (orchestrator, params, iter, x1) = self.destruct();
+        match params.iteration_order {
+            IterationOrder::Ordered => {
+                let (_num_threads, Ok(result)) = prc::last::x(orchestrator, params, iter, x1);
+                result.map(|x| x.1)
+            }
+            IterationOrder::Arbitrary => {
+                let (_num_threads, Ok(result)) = prc::next_any::x(orchestrator, params, iter, x1);
+                result
+            }
+        }
+    }
 }
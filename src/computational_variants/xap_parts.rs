@@ -0,0 +1,50 @@
+use crate::Params;
+use crate::computational_variants::ParXap;
+use crate::generic_values::TransformableValues;
+use crate::generic_values::runner_results::Infallible;
+use crate::runner::ParallelRunner;
+use orx_concurrent_iter::ConcurrentIter;
+
+/// Components of a parallel computation expressed as a single xap over a concurrent iterator.
+///
+/// Every parallel iterator can be decomposed into these parts, which allows to combine
+/// computations with different transformations such as chaining them.
+pub trait XapParts<R>
+where
+    R: ParallelRunner,
+{
+    /// Type of the elements yielded by the computation.
+    type Item;
+
+    /// Type of the underlying concurrent iterator.
+    type Iter: ConcurrentIter;
+
+    /// Values produced by the xap for each element of the concurrent iterator.
+    type Vo: TransformableValues<Item = Self::Item, Fallibility = Infallible>;
+
+    /// The xap transformation applied on elements of the concurrent iterator.
+    type X1: Fn(<Self::Iter as ConcurrentIter>::Item) -> Self::Vo + Sync;
+
+    /// Decomposes the computation into its runner, parameters, concurrent iterator and xap.
+    fn into_xap_parts(self) -> (R, Params, Self::Iter, Self::X1);
+}
+
+impl<I, Vo, X1, R> XapParts<R> for ParXap<I, Vo, X1, R>
+where
+    R: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: TransformableValues<Fallibility = Infallible>,
+    X1: Fn(I::Item) -> Vo + Sync,
+{
+    type Item = Vo::Item;
+
+    type Iter = I;
+
+    type Vo = Vo;
+
+    type X1 = X1;
+
+    fn into_xap_parts(self) -> (R, Params, Self::Iter, Self::X1) {
+        self.destruct()
+    }
+}
@@ -19,4 +19,75 @@ pub trait ParEnumerate<R: ParallelRunner>: ParIter<R> {
     /// assert_eq!(max_abs, Some((1, -27)));
     /// ```
     fn enumerate(self) -> impl ParIter<R, Item = (usize, Self::Item)>;
+
+    /// Creates an iterator that skips the first `n` elements.
+    ///
+    /// The skipped elements are pulled from the input and dropped once when the iterator is created;
+    /// they are discarded without being mapped or passed to any of the transformations. The executor
+    /// only distributes the remaining elements to the threads. Indices of the remaining elements
+    /// start from 0; i.e., `skip(n).enumerate()` yields `(0, x_n), (1, x_{n+1}), ...`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// let skipped: Vec<_> = vec.par().skip(2).collect();
+    /// assert_eq!(skipped, vec![&3, &4, &5]);
+    ///
+    /// let sum: usize = (0..100).into_par().skip(90).sum();
+    /// assert_eq!(sum, (90..100).sum());
+    /// ```
+    fn skip(self, n: usize) -> impl ParEnumerate<R, Item = Self::Item>;
+
+    /// Creates an iterator that yields the first `n` elements, or fewer if the underlying
+    /// iterator ends sooner.
+    ///
+    /// Once position `n` is reached, the input is skipped to its end. Elements after position `n`
+    /// which are pulled in the meantime, such as the remainder of the chunk containing position `n`
+    /// or chunks concurrently pulled by other threads, are discarded without being mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// let taken: Vec<_> = vec.par().take(3).collect();
+    /// assert_eq!(taken, vec![&1, &2, &3]);
+    ///
+    /// let middle: Vec<_> = (0..100).into_par().skip(10).take(5).collect();
+    /// assert_eq!(middle, vec![10, 11, 12, 13, 14]);
+    /// ```
+    fn take(self, n: usize) -> impl ParEnumerate<R, Item = Self::Item>;
+
+    /// Creates an iterator starting at the same point, but stepping by the given amount at
+    /// each iteration.
+    ///
+    /// The first element of the iterator will always be returned, regardless of the step given.
+    ///
+    /// Chunks of the stepped iterator are pulled from the source as a whole; the elements in between
+    /// the steps are pulled as part of these chunks, but they are discarded without being mapped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given `step` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let vec = vec![0, 1, 2, 3, 4, 5, 6];
+    ///
+    /// let stepped: Vec<_> = vec.par().step_by(3).collect();
+    /// assert_eq!(stepped, vec![&0, &3, &6]);
+    ///
+    /// let stepped: Vec<_> = (0..100).into_par().skip(1).step_by(10).enumerate().collect();
+    /// assert_eq!(stepped[..3], [(0, 1), (1, 11), (2, 21)]);
+    /// ```
+    fn step_by(self, step: usize) -> impl ParEnumerate<R, Item = Self::Item>;
 }
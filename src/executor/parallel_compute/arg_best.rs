@@ -0,0 +1,59 @@
+use crate::Params;
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::Infallible;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use core::sync::atomic::AtomicUsize;
+use orx_concurrent_iter::ConcurrentIter;
+
+pub fn x<C, I, Vo, X1, B>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    is_better: B,
+) -> (NumSpawned, Option<(usize, Vo::Item)>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    Vo::Item: Send,
+    X1: Fn(I::Item) -> Vo + Sync,
+    B: Fn(&Vo::Item, &Vo::Item) -> bool + Sync,
+{
+    // earliest position at which a thread is stopped by a while condition
+    let stop_idx = AtomicUsize::new(usize::MAX);
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        Ok(th::arg_best::x(
+            thread_runner,
+            iter,
+            state,
+            &xap1,
+            &is_better,
+            &stop_idx,
+        ))
+    };
+    let (num_spawned, result) =
+        orchestrator.map_infallible(params, iter, ComputationKind::Collect, thread_map);
+    let Ok(results) = result;
+
+    let idx_bound = results
+        .iter()
+        .filter_map(|x| x.1)
+        .min()
+        .unwrap_or(usize::MAX);
+
+    // candidates found after the earliest stop are not within the iteration;
+    // among the equally good candidates, the one at the smallest position wins
+    let best = results
+        .into_iter()
+        .flat_map(|x| x.0)
+        .filter(|(idx, _)| *idx <= idx_bound)
+        .reduce(
+            |a, b| match is_better(&b.1, &a.1) || (!is_better(&a.1, &b.1) && b.0 < a.0) {
+                true => b,
+                false => a,
+            },
+        );
+    (num_spawned, best)
+}
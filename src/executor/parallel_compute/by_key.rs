@@ -0,0 +1,167 @@
+#[cfg(not(feature = "std"))]
+use alloc::collections::{BTreeMap, btree_map};
+#[cfg(feature = "std")]
+use {
+    crate::executor::thread_compute as th,
+    crate::generic_values::TransformableValues,
+    crate::generic_values::runner_results::Infallible,
+    crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf},
+    crate::{IntoParIter, ParIter, Params},
+    alloc::vec::Vec,
+    core::hash::{BuildHasher, Hash},
+    orx_concurrent_bag::ConcurrentBag,
+    orx_concurrent_iter::ConcurrentIter,
+    std::collections::{HashMap, hash_map},
+    std::hash::RandomState,
+};
+
+macro_rules! reduce_into_map {
+    ($(#[$attr:meta])* $name:ident, $map:ident, $entry:ident, [$($key_bounds:tt)+]) => {
+        /// Reduces the `value` into the value of the `key` in the `map` in place, or inserts it if the
+        /// key is absent, with a single lookup of the key.
+        $(#[$attr])*
+        pub(crate) fn $name<K, V, R>(map: &mut $map<K, V>, key: K, value: V, reduce: &R)
+        where
+            K: $($key_bounds)+,
+            R: Fn(V, V) -> V,
+        {
+            /// Removes the entry without dropping its value, unless released; this is the case only
+            /// when `reduce` panics while the value is moved out of the entry.
+            struct ForgetOnUnwind<'a, K: $($key_bounds)+, V>(Option<$entry::OccupiedEntry<'a, K, V>>);
+
+            impl<K: $($key_bounds)+, V> Drop for ForgetOnUnwind<'_, K, V> {
+                fn drop(&mut self) {
+                    if let Some(entry) = self.0.take() {
+                        core::mem::forget(entry.remove());
+                    }
+                }
+            }
+
+            match map.entry(key) {
+                $entry::Entry::Vacant(entry) => _ = entry.insert(value),
+                $entry::Entry::Occupied(entry) => {
+                    let mut guard = ForgetOnUnwind(Some(entry));
+                    let slot: *mut V = guard.0.as_mut().expect("is some").get_mut();
+                    // SAFETY: the value is moved out of the slot and a new value is written back before the
+                    // guard is released; if reduce panics, the guard removes the entry without dropping it
+                    let acc = unsafe { slot.read() };
+                    let reduced = reduce(acc, value);
+                    unsafe { slot.write(reduced) };
+                    _ = guard.0.take();
+                }
+            }
+        }
+    };
+}
+
+reduce_into_map!(
+    #[cfg(not(feature = "std"))]
+    reduce_into_btree_map,
+    BTreeMap,
+    btree_map,
+    [Ord]
+);
+reduce_into_map!(
+    #[cfg(feature = "std")]
+    reduce_into_hash_map,
+    HashMap,
+    hash_map,
+    [Hash + Eq]
+);
+
+/// Merges the smaller of the two maps into the larger one.
+#[cfg(feature = "std")]
+fn merge_maps<K, V, Mrg>(mut a: HashMap<K, V>, mut b: HashMap<K, V>, merge: &Mrg) -> HashMap<K, V>
+where
+    Mrg: Fn(&mut HashMap<K, V>, K, V),
+{
+    if a.len() < b.len() {
+        core::mem::swap(&mut a, &mut b);
+    }
+    for (k, v) in b {
+        merge(&mut a, k, v);
+    }
+    a
+}
+
+/// Aggregates the values into maps sharded by the hashes of their keys.
+///
+/// Each thread aggregates the values it processes into `num_shards` maps, where each key is assigned to
+/// a shard by its hash. Then, the maps of the threads belonging to the same shard are merged in parallel.
+/// Since the shards have disjoint keys, they are returned as they are without being combined into a single map.
+#[cfg(feature = "std")]
+pub fn x<C, I, Vo, X1, K, V, GetKey, Ins, Mrg>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    key: GetKey,
+    insert: Ins,
+    merge: Mrg,
+) -> (NumSpawned, Vec<HashMap<K, V>>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: TransformableValues<Fallibility = Infallible>,
+    X1: Fn(I::Item) -> Vo + Sync,
+    K: Hash + Eq + Send,
+    V: Send,
+    GetKey: Fn(&Vo::Item) -> K + Sync,
+    Ins: Fn(&mut HashMap<K, V>, K, Vo::Item) + Sync,
+    Mrg: Fn(&mut HashMap<K, V>, K, V) + Sync,
+{
+    let num_shards = orchestrator
+        .max_num_threads_for_computation(params, iter.try_get_len())
+        .get();
+    let hasher = RandomState::new();
+    let shard_of = |k: &K| (hasher.hash_one(k) % num_shards as u64) as usize;
+
+    let fold = |mut shards: Vec<HashMap<K, V>>, x: Vo::Item| {
+        let k = key(&x);
+        insert(&mut shards[shard_of(&k)], k, x);
+        shards
+    };
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        let shards = (0..num_shards).map(|_| HashMap::new()).collect();
+        Ok(th::fold::x(
+            thread_runner,
+            iter,
+            state,
+            &xap1,
+            shards,
+            &fold,
+        ))
+    };
+    let (num_spawned, result) =
+        orchestrator.map_infallible(params, iter, ComputationKind::Collect, thread_map);
+    let Ok(results) = result;
+
+    let mut shards: Vec<Vec<_>> = (0..num_shards).map(|_| Vec::new()).collect();
+    for thread_shards in results {
+        for (s, map) in thread_shards.into_iter().enumerate() {
+            if !map.is_empty() {
+                shards[s].push(map);
+            }
+        }
+    }
+
+    let merge_shard = |maps: Vec<HashMap<K, V>>| {
+        maps.into_iter()
+            .reduce(|a, b| merge_maps(a, b, &merge))
+            .unwrap_or_default()
+    };
+    if num_shards == 1 {
+        return (num_spawned, shards.into_iter().map(merge_shard).collect());
+    }
+
+    let merged = ConcurrentBag::new();
+    shards
+        .into_par()
+        .with_runner(&mut orchestrator)
+        .num_threads(params.num_threads)
+        .chunk_size(1)
+        .map(merge_shard)
+        .for_each(|map| _ = merged.push(map));
+
+    (num_spawned, merged.into_inner().into_iter().collect())
+}
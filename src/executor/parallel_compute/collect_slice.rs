@@ -0,0 +1,195 @@
+use crate::Params;
+use crate::executor::parallel_compute as prc;
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::Infallible;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use orx_concurrent_iter::ConcurrentIter;
+
+/// A borrowed slice into which the threads write values at distinct positions.
+pub trait SliceWriter<T>: Sync {
+    fn len(&self) -> usize;
+
+    /// Writes the `value` to the `idx`-th position of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// Each position must be written by at most one thread at a time.
+    unsafe fn write(&self, idx: usize, value: T);
+}
+
+/// Writer to a slice of initialized values; each written value replaces and drops the prior one.
+pub struct InitSlice<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    phantom: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T> InitSlice<'a, T> {
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// SAFETY: values are sent to the slice from different threads, each position being written by one thread
+unsafe impl<T: Send> Sync for InitSlice<'_, T> {}
+
+impl<T: Send> SliceWriter<T> for InitSlice<'_, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn write(&self, idx: usize, value: T) {
+        assert_in_bounds(idx, self.len);
+        unsafe { *self.ptr.add(idx) = value };
+    }
+}
+
+/// Writer to a slice of uninitialized values; written values are never dropped by the writer.
+pub struct UninitSlice<'a, T> {
+    ptr: *mut MaybeUninit<T>,
+    len: usize,
+    phantom: PhantomData<&'a mut [MaybeUninit<T>]>,
+}
+
+impl<'a, T> UninitSlice<'a, T> {
+    pub fn new(slice: &'a mut [MaybeUninit<T>]) -> Self {
+        Self {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// SAFETY: values are sent to the slice from different threads, each position being written by one thread
+unsafe impl<T: Send> Sync for UninitSlice<'_, T> {}
+
+impl<T: Send> SliceWriter<T> for UninitSlice<'_, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn write(&self, idx: usize, value: T) {
+        assert_in_bounds(idx, self.len);
+        unsafe { (*self.ptr.add(idx)).write(value) };
+    }
+}
+
+/// Returns the slice as initialized.
+///
+/// # Safety
+///
+/// All elements of the `slice` must be written.
+pub unsafe fn assume_init<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    // SAFETY: MaybeUninit<T> has the same layout as T and all elements are initialized
+    unsafe { &mut *(slice as *mut [MaybeUninit<T>] as *mut [T]) }
+}
+
+#[inline(always)]
+fn assert_in_bounds(idx: usize, len: usize) {
+    assert!(
+        idx < len,
+        "number of elements of the parallel iterator must be equal to the length of the slice"
+    );
+}
+
+fn assert_len_match(slice_len: usize, iter_len: Option<usize>) {
+    assert_eq!(
+        Some(slice_len),
+        iter_len,
+        "length of the slice must be equal to the number of elements of the parallel iterator"
+    );
+}
+
+/// Writes the mapped values directly to their source positions in the `slice` when the length of the
+/// `iter` is known; falls back to collecting into ordered runs otherwise.
+///
+/// Since the known length might be reported incorrectly, such as by the size hint of an iterator
+/// converted into a parallel iterator, every write is bounds-checked and the number of writes is
+/// verified to be equal to the length of the slice.
+///
+/// # Panics
+///
+/// Panics if the number of values is not equal to the length of the `slice`.
+pub fn m<C, I, O, M1, W>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    map1: M1,
+    slice: W,
+) -> NumSpawned
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    M1: Fn(I::Item) -> O + Sync,
+    O: Send,
+    W: SliceWriter<O>,
+{
+    let iter_len = iter.try_get_len();
+    if iter_len.is_none() {
+        let xap1 = |x| Some(map1(x));
+        return x(orchestrator, params, iter, xap1, slice);
+    }
+    assert_len_match(slice.len(), iter_len);
+
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        Ok(th::collect_slice::m(
+            thread_runner,
+            iter,
+            state,
+            &map1,
+            &slice,
+        ))
+    };
+    let (num_spawned, result) =
+        orchestrator.map_infallible(params, iter, ComputationKind::Collect, thread_map);
+    let num_written = match result {
+        Ok(num_written) => num_written.into_iter().sum(),
+    };
+    assert_len_match(slice.len(), Some(num_written));
+    num_spawned
+}
+
+/// Collects the values into ordered runs and writes them to the `slice` once their number is known,
+/// since positions of the values of a computation with filtering or flattening are not known in advance.
+///
+/// # Panics
+///
+/// Panics if the number of collected values is not equal to the length of the `slice`.
+pub fn x<C, I, Vo, X1, W>(
+    orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    slice: W,
+) -> NumSpawned
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    Vo::Item: Send,
+    X1: Fn(I::Item) -> Vo + Sync,
+    W: SliceWriter<Vo::Item>,
+{
+    let (num_spawned, result) = prc::collect_runs::x(orchestrator, params, iter, xap1, Vec::new);
+    let Ok(runs) = result;
+
+    assert_len_match(slice.len(), Some(runs.iter().map(Vec::len).sum()));
+    for (idx, value) in runs.into_iter().flatten().enumerate() {
+        // SAFETY: number of values is equal to the length of the slice which is written by this thread only
+        unsafe { slice.write(idx, value) };
+    }
+    num_spawned
+}
@@ -0,0 +1,66 @@
+use crate::Params;
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::{Fallibility, ThreadCollect};
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+
+/// Values collected by each thread split into two vectors together with their indices,
+/// and the smallest index at which the computation is stopped due to a while condition, if any.
+pub type UnzipCollect<A, B> = (Vec<Vec<(usize, A)>>, Vec<Vec<(usize, B)>>, Option<usize>);
+
+type ResultUnzip<A, B, Vo> =
+    Result<UnzipCollect<A, B>, <<Vo as Values>::Fallibility as Fallibility>::Error>;
+
+pub fn x<C, I, Vo, X1, S, A, B>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    split: S,
+) -> (NumSpawned, ResultUnzip<A, B, Vo>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values,
+    Vo::Item: Send,
+    <Vo::Fallibility as Fallibility>::Error: Send,
+    X1: Fn(I::Item) -> Vo + Sync,
+    S: Fn(Vec<(usize, Vo::Item)>) -> (Vec<(usize, A)>, Vec<(usize, B)>) + Sync,
+    A: Send,
+    B: Send,
+{
+    // each thread splits its own collected values so that the split runs in parallel as well
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        match th::collect_ordered::x(thread_runner, iter, state, &xap1) {
+            ThreadCollect::AllCollected { vec } => Ok((split(vec), None)),
+            ThreadCollect::StoppedByWhileCondition { vec, stopped_idx } => {
+                Ok((split(vec), Some(stopped_idx)))
+            }
+            ThreadCollect::StoppedByError { error } => Err(error),
+        }
+    };
+    let (num_spawned, result) = orchestrator.map_all::<Vo::Fallibility, _, _, _>(
+        params,
+        iter,
+        ComputationKind::Collect,
+        thread_map,
+    );
+
+    let result = result.map(|results| {
+        let mut left = Vec::with_capacity(results.len());
+        let mut right = Vec::with_capacity(results.len());
+        let mut stopped_idx: Option<usize> = None;
+        for ((a, b), idx) in results {
+            left.push(a);
+            right.push(b);
+            stopped_idx = match (stopped_idx, idx) {
+                (Some(x), Some(y)) => Some(x.min(y)),
+                (x, y) => x.or(y),
+            };
+        }
+        (left, right, stopped_idx)
+    });
+    (num_spawned, result)
+}
@@ -0,0 +1,44 @@
+use crate::Params;
+use crate::executor::thread_compute as th;
+use crate::generic_values::TransformableValues;
+use crate::generic_values::runner_results::Infallible;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use orx_concurrent_iter::ConcurrentIter;
+
+pub fn x<C, I, Vo, X1, Acc, Id, Fold, Combine>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    identity: Id,
+    fold: Fold,
+    combine: Combine,
+) -> (NumSpawned, Acc)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: TransformableValues<Fallibility = Infallible>,
+    X1: Fn(I::Item) -> Vo + Sync,
+    Acc: Send,
+    Id: Fn() -> Acc + Sync,
+    Fold: Fn(Acc, Vo::Item) -> Acc + Sync,
+    Combine: Fn(Acc, Acc) -> Acc,
+{
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        Ok(th::fold::x(
+            thread_runner,
+            iter,
+            state,
+            &xap1,
+            identity(),
+            &fold,
+        ))
+    };
+    let (num_spawned, result) =
+        orchestrator.map_infallible(params, iter, ComputationKind::Collect, thread_map);
+
+    let acc = match result {
+        Ok(results) => results.into_iter().reduce(combine),
+    };
+    (num_spawned, acc.unwrap_or_else(identity))
+}
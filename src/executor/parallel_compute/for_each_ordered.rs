@@ -0,0 +1,246 @@
+use crate::Params;
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::Fallibility;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+/// Default number of positions that the threads are allowed to compute ahead of the sink.
+pub const DEFAULT_REORDER_WINDOW: usize = 1024;
+
+/// Values computed for a chunk, paired with their positions.
+type ChunkValues<T> = Vec<(usize, T)>;
+
+struct WindowState<T> {
+    /// Position of the next values to be sent to the sink.
+    next: usize,
+    /// Position of the next values to be taken out of the slots; positions in `next..drained` are being
+    /// sent to the sink.
+    drained: usize,
+    /// Exclusive bound of the positions whose values are sent to the sink.
+    end: usize,
+    /// Whether or not a thread is currently sending values to the sink.
+    draining: bool,
+    /// Whether or not a thread panicked while computing or sending values.
+    poisoned: bool,
+    /// Chunks computed ahead of `next`, the one beginning at position `begin` being stored at `begin % slots.len()`
+    /// together with its length and its values paired with their positions.
+    slots: Vec<Option<(usize, ChunkValues<T>)>>,
+}
+
+/// A bounded reorder buffer which sends the values to the sink sequentially in the order of their positions.
+///
+/// Threads wait before computing a chunk which ends `len` or more positions ahead of the next position
+/// to be sent to the sink; hence, at most `len` positions are buffered at any time. Chunks must therefore
+/// not be longer than `len`.
+///
+/// The sink is called outside of the lock on the buffer, by one thread at a time.
+pub struct ReorderWindow<T, F> {
+    len: usize,
+    state: Mutex<WindowState<T>>,
+    advanced: Condvar,
+    sink: Mutex<F>,
+}
+
+impl<T, F> ReorderWindow<T, F>
+where
+    F: FnMut(T) -> bool,
+{
+    fn new(len: usize, sink: F) -> Self {
+        assert!(len > 0, "reorder window must be positive");
+        let state = WindowState {
+            next: 0,
+            drained: 0,
+            end: usize::MAX,
+            draining: false,
+            poisoned: false,
+            slots: (0..len).map(|_| None).collect(),
+        };
+        Self {
+            len,
+            state: Mutex::new(state),
+            advanced: Condvar::new(),
+            sink: Mutex::new(sink),
+        }
+    }
+
+    // the state is never left inconsistent while the lock is held; poisoning is tracked by `poisoned` instead
+    fn lock(&self) -> MutexGuard<'_, WindowState<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn into_sink(self) -> F {
+        self.sink
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Length of the window.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Blocks until the chunk of `chunk_len` positions beginning at `begin` is within the window.
+    ///
+    /// Returns false if none of the values of the chunk will be sent to the sink, and hence, the chunk need not
+    /// be computed; this is also the case once the window is poisoned.
+    pub fn wait_for(&self, begin: usize, chunk_len: usize) -> bool {
+        debug_assert!(chunk_len <= self.len);
+        let last = begin + chunk_len.saturating_sub(1);
+        let mut state = self.lock();
+        while !state.poisoned && begin < state.end && last >= state.next + self.len {
+            state = self
+                .advanced
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        !state.poisoned && begin < state.end
+    }
+
+    /// Marks the window as poisoned due to a panic while computing the values at position `idx`, and
+    /// wakes up all waiting threads so that they stop.
+    pub fn poison(&self, idx: usize) {
+        let mut state = self.lock();
+        state.poisoned = true;
+        state.end = state.end.min(idx);
+        drop(state);
+        self.advanced.notify_all();
+    }
+
+    /// Returns a guard which poisons the window if it is dropped while the thread computing the values
+    /// at position `idx` is panicking.
+    pub fn panic_guard(&self, idx: usize) -> PanicGuard<'_, T, F> {
+        PanicGuard { window: self, idx }
+    }
+
+    /// Places the `values` computed for the chunk of `chunk_len` positions beginning at `begin`, and sends all
+    /// values that are ready to the sink in order.
+    ///
+    /// If `end` is provided, values at positions greater than or equal to `end` are not sent to the sink.
+    ///
+    /// If another thread is already sending values to the sink, the values are only placed and will be sent
+    /// by that thread.
+    pub fn push(
+        &self,
+        begin: usize,
+        chunk_len: usize,
+        values: Vec<(usize, T)>,
+        end: Option<usize>,
+    ) {
+        let mut state = self.lock();
+        if let Some(end) = end {
+            state.end = state.end.min(end);
+        }
+        state.slots[begin % self.len] = Some((chunk_len, values));
+
+        if state.draining {
+            return;
+        }
+        state.draining = true;
+
+        loop {
+            let mut ready = Vec::new();
+            while state.drained < state.end {
+                let slot = state.drained % self.len;
+                let Some((chunk_len, values)) = state.slots[slot].take() else {
+                    break;
+                };
+                ready.push(values);
+                state.drained += chunk_len;
+            }
+
+            if ready.is_empty() {
+                state.draining = false;
+                return;
+            }
+
+            let end = state.end;
+            drop(state);
+
+            let stopped_at = self.send(ready, end);
+
+            state = self.lock();
+            if let Some(position) = stopped_at {
+                state.end = state.end.min(position + 1);
+            }
+            state.next = state.drained;
+            self.advanced.notify_all();
+        }
+    }
+
+    /// Sends the values of the `ready` consecutive chunks at positions before `end` to the sink.
+    ///
+    /// Returns the position at which the sink returned false, if any.
+    fn send(&self, ready: Vec<ChunkValues<T>>, end: usize) -> Option<usize> {
+        let mut sink = self.sink.lock().unwrap_or_else(PoisonError::into_inner);
+        for (position, value) in ready.into_iter().flatten() {
+            if position >= end {
+                break;
+            }
+            if !(sink)(value) {
+                return Some(position);
+            }
+        }
+        None
+    }
+}
+
+/// Poisons the reorder window when dropped during a panic, so that the remaining threads do not wait forever.
+pub struct PanicGuard<'a, T, F>
+where
+    F: FnMut(T) -> bool,
+{
+    window: &'a ReorderWindow<T, F>,
+    idx: usize,
+}
+
+impl<T, F> Drop for PanicGuard<'_, T, F>
+where
+    F: FnMut(T) -> bool,
+{
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            self.window.poison(self.idx);
+        }
+    }
+}
+
+/// Sends the values to the `sink` sequentially in the order of their positions, while the threads compute
+/// at most `reorder_window` positions ahead of the sink.
+///
+/// The iteration stops early once the `sink` returns false.
+pub fn x<C, I, Vo, X1, F>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    reorder_window: usize,
+    sink: F,
+) -> (
+    NumSpawned,
+    Result<F, <Vo::Fallibility as Fallibility>::Error>,
+)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values,
+    Vo::Item: Send,
+    X1: Fn(I::Item) -> Vo + Sync,
+    F: FnMut(Vo::Item) -> bool + Send,
+{
+    let window = ReorderWindow::new(reorder_window, sink);
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        th::for_each_ordered::x(thread_runner, iter, state, &xap1, &window)
+    };
+    let (num_spawned, result) = orchestrator.map_all::<Vo::Fallibility, _, _, _>(
+        params,
+        iter,
+        ComputationKind::Collect,
+        thread_map,
+    );
+
+    let result = result.map(|_| window.into_sink());
+    (num_spawned, result)
+}
@@ -0,0 +1,59 @@
+use crate::executor::parallel_compute as prc;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::Infallible;
+use crate::runner::{NumSpawned, ParallelRunner};
+use crate::{IterationOrder, Params};
+use alloc::string::String;
+use orx_concurrent_iter::ConcurrentIter;
+
+/// A string buffer in which every item is preceded by the separator.
+struct SeparatedString<'a> {
+    separator: &'a str,
+    string: String,
+}
+
+impl<T: AsRef<str>> Extend<T> for SeparatedString<'_> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.string.push_str(self.separator);
+            self.string.push_str(x.as_ref());
+        }
+    }
+}
+
+pub fn x<C, I, Vo, X1>(
+    orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    separator: &str,
+) -> (NumSpawned, String)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    Vo::Item: AsRef<str>,
+    X1: Fn(I::Item) -> Vo + Sync,
+{
+    let empty = || SeparatedString {
+        separator,
+        string: String::new(),
+    };
+    let (num_spawned, result) = match (params.is_sequential(), params.iteration_order) {
+        (false, IterationOrder::Ordered) => {
+            prc::collect_runs::x(orchestrator, params, iter, xap1, empty)
+        }
+        _ => prc::collect_partial::x(orchestrator, params, iter, xap1, empty),
+    };
+    let Ok(buffers) = result;
+
+    // every buffer that is not empty starts with a separator, which is dropped for the first one
+    let len: usize = buffers.iter().map(|x| x.string.len()).sum();
+    let mut joined = String::with_capacity(len.saturating_sub(separator.len()));
+    let mut skip = separator.len();
+    for buffer in buffers.iter().filter(|x| !x.string.is_empty()) {
+        joined.push_str(&buffer.string[skip..]);
+        skip = 0;
+    }
+    (num_spawned, joined)
+}
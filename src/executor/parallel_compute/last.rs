@@ -0,0 +1,87 @@
+use crate::Params;
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::{Fallibility, LastSuccess, LastWithIdx};
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf};
+use core::sync::atomic::AtomicUsize;
+use orx_concurrent_iter::ConcurrentIter;
+
+pub fn m<C, I, O, M1, P>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    map1: M1,
+    predicate: P,
+) -> (NumSpawned, Option<(usize, O)>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    O: Send,
+    M1: Fn(I::Item) -> O + Sync,
+    P: Fn(&O) -> bool + Sync,
+{
+    let last_found = AtomicUsize::new(0);
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner| {
+        Ok(th::last::m(
+            thread_runner,
+            iter,
+            state,
+            &map1,
+            &predicate,
+            &last_found,
+        ))
+    };
+    let (num_spawned, result) =
+        orchestrator.map_infallible(params, iter, ComputationKind::Collect, thread_map);
+
+    let last = match result {
+        Ok(results) => results.into_iter().flatten().max_by_key(|x| x.0),
+    };
+    (num_spawned, last)
+}
+
+type ResultLast<Vo> = Result<
+    Option<(usize, <Vo as Values>::Item)>,
+    <<Vo as Values>::Fallibility as Fallibility>::Error,
+>;
+
+pub fn x<C, I, Vo, X1>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+) -> (NumSpawned, ResultLast<Vo>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values,
+    Vo::Item: Send,
+    X1: Fn(I::Item) -> Vo + Sync,
+{
+    let last_found = AtomicUsize::new(0);
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, th_runner| match th::last::x(
+        th_runner,
+        iter,
+        state,
+        &xap1,
+        &last_found,
+    ) {
+        LastWithIdx::Completed { candidates } => Ok(LastSuccess {
+            candidates,
+            stopped_idx: None,
+        }),
+        LastWithIdx::StoppedByWhileCondition { candidates, idx } => Ok(LastSuccess {
+            candidates,
+            stopped_idx: Some(idx),
+        }),
+        LastWithIdx::StoppedByError { error } => Err(error),
+    };
+    let (num_spawned, result) = orchestrator.map_all::<Vo::Fallibility, _, _, _>(
+        params,
+        iter,
+        ComputationKind::Collect,
+        thread_map,
+    );
+    let last = result.map(LastSuccess::reduce);
+    (num_spawned, last)
+}
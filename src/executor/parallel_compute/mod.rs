@@ -1,5 +1,23 @@
+pub(crate) mod arg_best;
+pub(crate) mod by_key;
 pub(crate) mod collect_arbitrary;
 pub(crate) mod collect_ordered;
+pub(crate) mod collect_partial;
+pub(crate) mod collect_runs;
+pub(crate) mod collect_slice;
+pub(crate) mod collect_unzip;
+pub(crate) mod fold;
+#[cfg(feature = "std")]
+pub(crate) mod for_each_ordered;
+pub(crate) mod join;
+pub(crate) mod last;
 pub(crate) mod next;
 pub(crate) mod next_any;
+pub(crate) mod position;
 pub(crate) mod reduce;
+pub(crate) mod scan;
+pub(crate) mod send;
+#[cfg(feature = "std")]
+pub(crate) mod seq_iter;
+pub(crate) mod sort;
+pub(crate) mod top_k;
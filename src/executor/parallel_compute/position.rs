@@ -0,0 +1,91 @@
+use crate::Params;
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::{
+    Fallibility, PositionSegment, PositionSuccess, PositionWithIdx,
+};
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf};
+use alloc::vec::Vec;
+use core::sync::atomic::AtomicUsize;
+use orx_concurrent_iter::ConcurrentIter;
+
+type ResultPosition<Vo> =
+    Result<Option<usize>, <<Vo as Values>::Fallibility as Fallibility>::Error>;
+
+fn segments<C, I, Vo, X1>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    first_found: Option<&AtomicUsize>,
+) -> (
+    NumSpawned,
+    Result<Vec<PositionSuccess>, <Vo::Fallibility as Fallibility>::Error>,
+)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Item = PositionSegment>,
+    X1: Fn(I::Item) -> Vo + Sync,
+{
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, th_runner| match th::position::x(
+        th_runner,
+        iter,
+        state,
+        &xap1,
+        first_found,
+    ) {
+        PositionWithIdx::Completed { segments } => Ok(PositionSuccess {
+            segments,
+            stopped_idx: None,
+        }),
+        PositionWithIdx::StoppedByWhileCondition { segments, idx } => Ok(PositionSuccess {
+            segments,
+            stopped_idx: Some(idx),
+        }),
+        PositionWithIdx::StoppedByError { error } => Err(error),
+    };
+    orchestrator.map_all::<Vo::Fallibility, _, _, _>(
+        params,
+        iter,
+        ComputationKind::Collect,
+        thread_map,
+    )
+}
+
+/// Position of the first value satisfying the predicate; exits early once it is found.
+pub fn x<C, I, Vo, X1>(
+    orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+) -> (NumSpawned, ResultPosition<Vo>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Item = PositionSegment>,
+    X1: Fn(I::Item) -> Vo + Sync,
+{
+    let first_found = AtomicUsize::new(usize::MAX);
+    let (num_spawned, result) = segments(orchestrator, params, iter, xap1, Some(&first_found));
+    (num_spawned, result.map(PositionSuccess::reduce_first))
+}
+
+/// Position of the last value satisfying the predicate.
+///
+/// Since positions are counted from the front, all values are required to be evaluated.
+pub fn x_rev<C, I, Vo, X1>(
+    orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+) -> (NumSpawned, ResultPosition<Vo>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Item = PositionSegment>,
+    X1: Fn(I::Item) -> Vo + Sync,
+{
+    let (num_spawned, result) = segments(orchestrator, params, iter, xap1, None);
+    (num_spawned, result.map(PositionSuccess::reduce_last))
+}
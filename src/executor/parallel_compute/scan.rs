@@ -0,0 +1,136 @@
+use crate::Params;
+use crate::default_fns::reduce_unit;
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::Infallible;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use alloc::vec;
+use alloc::vec::Vec;
+use orx_concurrent_iter::{ConcurrentIter, IntoConcurrentIter};
+
+/// Computes the scan in two passes, and returns the scanned values as contiguous segments in order.
+///
+/// * In the first pass, each thread computes the inclusive scan of the chunks it pulls independently.
+/// * Then, the carry of each segment, the scan of all elements before it, is computed sequentially.
+/// * In the second pass, each segment is fixed up by its carry in parallel.
+///
+/// `init`, if provided, is the carry of the first segment.
+fn scan<C, I, Vo, X1, Op>(
+    orchestrator: &mut C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    init: Option<Vo::Item>,
+    op: &Op,
+) -> (NumSpawned, Vec<Vec<Vo::Item>>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    Vo::Item: Clone + Send + Sync,
+    X1: Fn(I::Item) -> Vo + Sync,
+    Op: Fn(&Vo::Item, &Vo::Item) -> Vo::Item + Sync,
+{
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        Ok(th::scan::x(thread_runner, iter, state, &xap1, op))
+    };
+    let (num_spawned, result) =
+        orchestrator.map_infallible(params, iter, ComputationKind::Collect, thread_map);
+
+    let Ok(results) = result;
+    // segments after the earliest position where a while condition is not satisfied are discarded
+    let stopped_idx = results.iter().filter_map(|x| x.1).min();
+    let mut segments: Vec<_> = results
+        .into_iter()
+        .flat_map(|x| x.0)
+        .filter(|x| stopped_idx.is_none_or(|idx| x.begin_idx <= idx))
+        .collect();
+    segments.sort_unstable_by_key(|x| x.begin_idx);
+
+    let mut carries = Vec::with_capacity(segments.len());
+    let mut carry = init;
+    for segment in &segments {
+        let next = match (&carry, segment.values.last()) {
+            (Some(c), Some(last)) => Some(op(c, last)),
+            (None, last) => last.cloned(),
+            (c, None) => c.clone(),
+        };
+        carries.push(core::mem::replace(&mut carry, next));
+    }
+
+    let fixups: Vec<_> = carries
+        .iter()
+        .zip(segments.iter_mut())
+        .filter_map(|(carry, x)| carry.as_ref().map(|c| (c, &mut x.values)))
+        .collect();
+    let fixup =
+        |(c, vec): (&Vo::Item, &mut Vec<Vo::Item>)| vec.iter_mut().for_each(|x| *x = op(c, x));
+    let thread_map = |_, iter: &_, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        Ok(th::reduce::m(
+            thread_runner,
+            iter,
+            state,
+            &fixup,
+            &reduce_unit,
+        ))
+    };
+    // segments are few and large; hence, they are distributed one at a time
+    let params = params.with_chunk_size(1);
+    let _ = orchestrator.map_infallible(
+        params,
+        fixups.into_con_iter(),
+        ComputationKind::Collect,
+        thread_map,
+    );
+
+    let vectors = segments.into_iter().map(|x| x.values).collect();
+    (num_spawned, vectors)
+}
+
+pub fn inclusive<C, I, Vo, X1, Op>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    op: Op,
+) -> (NumSpawned, Vec<Vec<Vo::Item>>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    Vo::Item: Clone + Send + Sync,
+    X1: Fn(I::Item) -> Vo + Sync,
+    Op: Fn(&Vo::Item, &Vo::Item) -> Vo::Item + Sync,
+{
+    scan(&mut orchestrator, params, iter, xap1, None, &op)
+}
+
+pub fn exclusive<C, I, Vo, X1, Op>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    init: Vo::Item,
+    op: Op,
+) -> (NumSpawned, Vec<Vec<Vo::Item>>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    Vo::Item: Clone + Send + Sync,
+    X1: Fn(I::Item) -> Vo + Sync,
+    Op: Fn(&Vo::Item, &Vo::Item) -> Vo::Item + Sync,
+{
+    let first = init.clone();
+    let (num_spawned, mut vectors) = scan(&mut orchestrator, params, iter, xap1, Some(init), &op);
+
+    // exclusive scan is the inclusive scan starting at init shifted by one position
+    match vectors.iter_mut().rev().find(|x| !x.is_empty()) {
+        Some(last) => {
+            _ = last.pop();
+            vectors.insert(0, vec![first]);
+        }
+        None => vectors.clear(),
+    }
+    (num_spawned, vectors)
+}
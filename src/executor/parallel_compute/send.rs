@@ -0,0 +1,42 @@
+use crate::Params;
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::Fallibility;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use crate::sink::Sink;
+use orx_concurrent_iter::ConcurrentIter;
+
+/// Sends the values to the `sink` in batches built by the threads.
+///
+/// Returns whether or not all values are sent; i.e., false if the computation is stopped early
+/// due to a disconnected sink.
+pub fn x<C, I, Vo, X1, S>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    sink: S,
+) -> (
+    NumSpawned,
+    Result<bool, <Vo::Fallibility as Fallibility>::Error>,
+)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values,
+    X1: Fn(I::Item) -> Vo + Sync,
+    S: Sink<Vo::Item>,
+{
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        th::send::x(thread_runner, iter, state, &xap1, &sink)
+    };
+    let (num_spawned, result) = orchestrator.map_all::<Vo::Fallibility, _, _, _>(
+        params,
+        iter,
+        ComputationKind::EarlyReturn,
+        thread_map,
+    );
+
+    let result = result.map(|disconnected| !disconnected.into_iter().any(|x| x));
+    (num_spawned, result)
+}
@@ -0,0 +1,130 @@
+use crate::computational_variants::XapParts;
+use crate::executor::parallel_compute as prc;
+use crate::runner::ParallelRunner;
+use crate::{IterationOrder, ParIter};
+use std::sync::mpsc::{Receiver, SyncSender, sync_channel};
+use std::thread::{self, JoinHandle, ScopedJoinHandle};
+
+/// Handle of the background thread driving the computation.
+pub trait ComputeHandle {
+    /// Waits for the computation thread to complete.
+    fn join(self) -> thread::Result<()>;
+}
+
+impl ComputeHandle for JoinHandle<()> {
+    fn join(self) -> thread::Result<()> {
+        JoinHandle::join(self)
+    }
+}
+
+impl ComputeHandle for ScopedJoinHandle<'_, ()> {
+    fn join(self) -> thread::Result<()> {
+        ScopedJoinHandle::join(self)
+    }
+}
+
+/// A sequential iterator over the results of a parallel computation running in the background.
+///
+/// Results are received through a bounded buffer; the threads of the computation pause while the buffer is full.
+/// Dropping the iterator stops the computation.
+pub struct SeqIter<T, H: ComputeHandle = JoinHandle<()>> {
+    receiver: Option<Receiver<T>>,
+    handle: Option<H>,
+}
+
+impl<T, H: ComputeHandle> SeqIter<T, H> {
+    /// Waits for the computation to complete, propagating the panic of the computation, if any.
+    fn join(&mut self) {
+        if let Some(handle) = self.handle.take()
+            && let Err(panic) = handle.join()
+        {
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
+
+impl<T, H: ComputeHandle> Iterator for SeqIter<T, H> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.receiver.as_ref().and_then(|x| x.recv().ok());
+        if value.is_none() {
+            self.receiver = None;
+            self.join();
+        }
+        value
+    }
+}
+
+impl<T, H: ComputeHandle> Drop for SeqIter<T, H> {
+    fn drop(&mut self) {
+        // dropping the receiver makes the pending and subsequent sends fail, which stops the computation
+        self.receiver = None;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Computes `par` and sends its results to the buffer through the `sender`.
+///
+/// The computation completes when either all results are sent or the receiver is dropped, and
+/// panics if the parallel computation panics; in all cases the sender is dropped, which releases the receiver.
+fn compute<R, P>(par: P, buffer_len: usize, sender: SyncSender<P::Item>)
+where
+    R: ParallelRunner,
+    P: ParIter<R>,
+    P::Item: Send,
+{
+    match par.params().iteration_order {
+        IterationOrder::Ordered => {
+            let (orchestrator, params, iter, x1) = par.into_xap().into_xap_parts();
+            let sink = |x| sender.send(x).is_ok();
+            let _ = prc::for_each_ordered::x(orchestrator, params, iter, x1, buffer_len, sink);
+        }
+        IterationOrder::Arbitrary => {
+            let send = |x| sender.send(x).is_err();
+            let _ = par.map(send).find(|failed| *failed);
+        }
+    }
+}
+
+/// Starts the computation of `par` on a background thread and returns the iterator receiving its results
+/// through a buffer of length `buffer_len`.
+pub fn x<R, P>(par: P, buffer_len: usize) -> SeqIter<P::Item>
+where
+    R: ParallelRunner,
+    P: ParIter<R> + 'static,
+    P::Item: Send + 'static,
+{
+    assert!(buffer_len > 0, "buffer length must be positive");
+    let (sender, receiver) = sync_channel(buffer_len);
+    let handle = thread::spawn(move || compute(par, buffer_len, sender));
+    SeqIter {
+        receiver: Some(receiver),
+        handle: Some(handle),
+    }
+}
+
+/// Starts the computation of `par` on a scoped background thread and calls `f` with the iterator receiving its
+/// results through a buffer of length `buffer_len`.
+///
+/// The computation is stopped and joined before returning.
+pub fn scoped<R, P, F, T>(par: P, buffer_len: usize, f: F) -> T
+where
+    R: ParallelRunner,
+    P: ParIter<R>,
+    P::Item: Send,
+    F: FnOnce(&mut dyn Iterator<Item = P::Item>) -> T,
+{
+    assert!(buffer_len > 0, "buffer length must be positive");
+    let (sender, receiver) = sync_channel(buffer_len);
+    thread::scope(|s| {
+        let handle = s.spawn(move || compute(par, buffer_len, sender));
+        let mut iter = SeqIter {
+            receiver: Some(receiver),
+            handle: Some(handle),
+        };
+        f(&mut iter)
+    })
+}
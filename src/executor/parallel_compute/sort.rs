@@ -0,0 +1,83 @@
+use crate::executor::thread_compute as th;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::{Infallible, ThreadCollect};
+use crate::par_sort::merge_runs;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use crate::{IntoParIter, ParCollectInto, ParIter, Params};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use orx_concurrent_iter::ConcurrentIter;
+
+pub fn x<C, I, Vo, X1, Compare, P>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    compare: Compare,
+    output: P,
+) -> (NumSpawned, P)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    Vo::Item: Send,
+    X1: Fn(I::Item) -> Vo + Sync,
+    Compare: Fn(&Vo::Item, &Vo::Item) -> Ordering + Sync,
+    P: ParCollectInto<Vo::Item>,
+{
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        let (mut vec, stopped_idx) = match th::collect_ordered::x(thread_runner, iter, state, &xap1)
+        {
+            ThreadCollect::AllCollected { vec } => (vec, None),
+            ThreadCollect::StoppedByWhileCondition { vec, stopped_idx } => (vec, Some(stopped_idx)),
+            ThreadCollect::StoppedByError { error } => match error {},
+        };
+        // values of a thread are collected in increasing order of their indices;
+        // hence, the stable sort keeps the ties in the input order
+        vec.sort_by(|a, b| compare(&a.1, &b.1));
+        Ok((vec, stopped_idx))
+    };
+    let (num_spawned, result) =
+        orchestrator.map_infallible(params, iter, ComputationKind::Collect, thread_map);
+    let Ok(results) = result;
+
+    // values after the earliest position where a while condition is not satisfied are discarded
+    let stopped_idx = results.iter().filter_map(|x| x.1).min();
+    let len = results.iter().map(|x| x.0.len()).sum();
+    let mut values = Vec::with_capacity(len);
+    let mut runs = vec![0];
+    for (mut vec, _) in results {
+        if let Some(stopped_idx) = stopped_idx {
+            vec.retain(|x| x.0 <= stopped_idx);
+        }
+        if !vec.is_empty() {
+            values.extend(vec);
+            runs.push(values.len());
+        }
+    }
+
+    // ties between the values sorted by different threads are broken by their positions in the input
+    let is_less = |a: &(usize, Vo::Item), b: &(usize, Vo::Item)| match compare(&a.1, &b.1) {
+        Ordering::Equal => a.0 < b.0,
+        ordering => ordering == Ordering::Less,
+    };
+    let job_len = values.len().div_ceil(runs.len().max(2) - 1).max(1);
+    merge_runs(
+        &mut values,
+        runs,
+        job_len,
+        &mut orchestrator,
+        params.num_threads,
+        &is_less,
+    );
+
+    let output = values
+        .into_par()
+        .with_runner(&mut orchestrator)
+        .num_threads(params.num_threads)
+        .chunk_size(params.chunk_size)
+        .map(|x| x.1)
+        .collect_into(output);
+    (num_spawned, output)
+}
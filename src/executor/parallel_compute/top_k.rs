@@ -0,0 +1,119 @@
+use crate::Params;
+use crate::executor::thread_compute as th;
+use crate::generic_values::TransformableValues;
+use crate::generic_values::runner_results::Infallible;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+use orx_priority_queue::{BinaryHeap, PriorityQueue};
+
+/// Keeps the `k` values with the greatest keys observed so far.
+///
+/// Values are stored in `values`, while the heap holds the positions of the values in `values`
+/// together with their keys. Since the heap is a min-heap, its root is the worst of the best `k`
+/// values, which is the one to be replaced when a better value arrives.
+struct BoundedHeap<T, K>
+where
+    K: PartialOrd + Clone,
+{
+    k: usize,
+    values: Vec<T>,
+    heap: BinaryHeap<usize, K>,
+}
+
+impl<T, K> BoundedHeap<T, K>
+where
+    K: PartialOrd + Clone,
+{
+    /// Creates an empty bounded heap keeping at most `k` values, preallocating for `capacity` values.
+    fn new(k: usize, capacity: usize) -> Self {
+        Self {
+            k,
+            values: Vec::with_capacity(capacity),
+            heap: BinaryHeap::with_capacity(capacity),
+        }
+    }
+
+    fn push(&mut self, value: T, key: K) {
+        if self.values.len() < self.k {
+            self.heap.push(self.values.len(), key);
+            self.values.push(value);
+        } else if let Some((_, worst_key)) = self.heap.peek()
+            && key > *worst_key
+        {
+            let (position, _) = self.heap.pop().expect("heap is not empty");
+            self.values[position] = value;
+            self.heap.push(position, key);
+        }
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        let mut values: Vec<_> = other.values.into_iter().map(Some).collect();
+        for (position, key) in other.heap.as_slice() {
+            if let Some(value) = values[*position].take() {
+                self.push(value, key.clone());
+            }
+        }
+        self
+    }
+
+    /// Returns the values in descending order of their keys.
+    fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut positions = Vec::with_capacity(self.heap.len());
+        while let Some(position) = self.heap.pop_node() {
+            positions.push(position);
+        }
+        let mut values: Vec<_> = self.values.into_iter().map(Some).collect();
+        positions
+            .into_iter()
+            .rev()
+            .filter_map(|position| values[position].take())
+            .collect()
+    }
+}
+
+pub fn x<C, I, Vo, X1, K, GetKey>(
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    k: usize,
+    key: GetKey,
+) -> (NumSpawned, Vec<Vo::Item>)
+where
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: TransformableValues<Fallibility = Infallible>,
+    Vo::Item: Send,
+    X1: Fn(I::Item) -> Vo + Sync,
+    K: PartialOrd + Clone + Send,
+    GetKey: Fn(&Vo::Item) -> K + Sync,
+{
+    let fold = |mut heap: BoundedHeap<Vo::Item, K>, value: Vo::Item| {
+        let value_key = key(&value);
+        heap.push(value, value_key);
+        heap
+    };
+    // k might be much larger than the number of elements, such as usize::MAX to sort all
+    let capacity = k.min(iter.try_get_len().unwrap_or(0));
+    let thread_map = |_, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+        Ok(th::fold::x(
+            thread_runner,
+            iter,
+            state,
+            &xap1,
+            BoundedHeap::new(k, capacity),
+            &fold,
+        ))
+    };
+    let (num_spawned, result) =
+        orchestrator.map_infallible(params, iter, ComputationKind::Collect, thread_map);
+
+    let Ok(heaps) = result;
+    let values = heaps
+        .into_iter()
+        .reduce(BoundedHeap::merge)
+        .map(BoundedHeap::into_sorted_vec)
+        .unwrap_or_default();
+    (num_spawned, values)
+}
@@ -0,0 +1,163 @@
+use crate::{
+    ThreadExecutor,
+    generic_values::{
+        Values,
+        runner_results::{Infallible, Reduce},
+    },
+};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Pushes the value to the candidates if it is strictly better than the last candidate.
+///
+/// Since positions of the values pulled by a thread are increasing, the candidates are the prefix bests
+/// of the thread; and the best value before any position is the last candidate at or before it.
+/// Unless `keep_all`, only the best value is kept.
+#[inline(always)]
+fn push_candidate<T, B>(
+    candidates: &mut Vec<(usize, T)>,
+    idx: usize,
+    value: T,
+    is_better: &B,
+    keep_all: bool,
+) where
+    B: Fn(&T, &T) -> bool,
+{
+    if candidates
+        .last()
+        .is_none_or(|(_, best)| is_better(&value, best))
+    {
+        if !keep_all {
+            candidates.clear();
+        }
+        candidates.push((idx, value));
+    }
+}
+
+/// Removes the candidates after the earliest position at which the iteration is stopped so far.
+#[inline(always)]
+fn prune_candidates<T>(candidates: &mut Vec<(usize, T)>, stop_idx: &AtomicUsize) {
+    let stop_idx = stop_idx.load(Ordering::Relaxed);
+    let len = candidates.partition_point(|(idx, _)| *idx <= stop_idx);
+    candidates.truncate(len);
+}
+
+pub fn x<C, I, Vo, X1, B>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    is_better: &B,
+    stop_idx: &AtomicUsize,
+) -> (Vec<(usize, Vo::Item)>, Option<usize>)
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    X1: Fn(I::Item) -> Vo,
+    B: Fn(&Vo::Item, &Vo::Item) -> bool,
+{
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller_with_idx();
+
+    // candidates are kept rather than only the best value when a while condition stopping the
+    // iteration at an earlier position in another thread might invalidate the later values
+    let keep_all = Vo::CAN_STOP_BY_WHILE;
+    let mut candidates = Vec::new();
+    let best = |a: Vo::Item, b: Vo::Item| match is_better(&b, &a) {
+        true => b,
+        false => a,
+    };
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some((idx, i)) => match xap1(i).acc_reduce(None, best) {
+                    Reduce::Done { acc } => {
+                        if let Some(value) = acc {
+                            push_candidate(&mut candidates, idx, value, is_better, keep_all);
+                        }
+                    }
+                    Reduce::StoppedByWhileCondition { acc } => {
+                        if let Some(value) = acc {
+                            push_candidate(&mut candidates, idx, value, is_better, keep_all);
+                        }
+                        _ = stop_idx.fetch_min(idx, Ordering::Relaxed);
+                        iter.skip_to_end();
+                        runner.complete_chunk(shared_state, chunk_size);
+                        runner.complete_task(shared_state);
+                        return (candidates, Some(idx));
+                    }
+                    Reduce::StoppedByError { error } => match error {},
+                },
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull_with_idx() {
+                    Some((begin_idx, chunk)) => {
+                        for (j, i) in chunk.enumerate() {
+                            let idx = begin_idx + j;
+                            match xap1(i).acc_reduce(None, best) {
+                                Reduce::Done { acc } => {
+                                    if let Some(value) = acc {
+                                        push_candidate(
+                                            &mut candidates,
+                                            idx,
+                                            value,
+                                            is_better,
+                                            keep_all,
+                                        );
+                                    }
+                                }
+                                Reduce::StoppedByWhileCondition { acc } => {
+                                    if let Some(value) = acc {
+                                        push_candidate(
+                                            &mut candidates,
+                                            idx,
+                                            value,
+                                            is_better,
+                                            keep_all,
+                                        );
+                                    }
+                                    _ = stop_idx.fetch_min(idx, Ordering::Relaxed);
+                                    iter.skip_to_end();
+                                    runner.complete_chunk(shared_state, chunk_size);
+                                    runner.complete_task(shared_state);
+                                    return (candidates, Some(idx));
+                                }
+                                Reduce::StoppedByError { error } => match error {},
+                            }
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        if keep_all {
+            prune_candidates(&mut candidates, stop_idx);
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+    (candidates, None)
+}
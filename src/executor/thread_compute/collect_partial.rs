@@ -0,0 +1,95 @@
+use crate::ThreadExecutor;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::{Stop, ThreadCollectArbitrary};
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+pub fn x<C, I, Vo, X1, P>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    partial: &mut P,
+) -> ThreadCollectArbitrary<Vo::Fallibility>
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: Values,
+    X1: Fn(I::Item) -> Vo,
+    P: Extend<Vo::Item>,
+{
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller();
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some(value) => {
+                    let vo = xap1(value);
+                    let done = vo.push_to_extend(partial);
+
+                    if let Some(stop) = Vo::arbitrary_push_to_stop(done) {
+                        iter.skip_to_end();
+                        runner.complete_chunk(shared_state, chunk_size);
+                        runner.complete_task(shared_state);
+                        match stop {
+                            Stop::DueToWhile => {
+                                return ThreadCollectArbitrary::StoppedByWhileCondition;
+                            }
+                            Stop::DueToError { error } => {
+                                return ThreadCollectArbitrary::StoppedByError { error };
+                            }
+                        }
+                    }
+                }
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull() {
+                    Some(chunk) => {
+                        for value in chunk {
+                            let vo = xap1(value);
+                            let done = vo.push_to_extend(partial);
+
+                            if let Some(stop) = Vo::arbitrary_push_to_stop(done) {
+                                iter.skip_to_end();
+                                runner.complete_chunk(shared_state, chunk_size);
+                                runner.complete_task(shared_state);
+                                match stop {
+                                    Stop::DueToWhile => {
+                                        return ThreadCollectArbitrary::StoppedByWhileCondition;
+                                    }
+                                    Stop::DueToError { error } => {
+                                        return ThreadCollectArbitrary::StoppedByError { error };
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+
+    ThreadCollectArbitrary::AllCollected
+}
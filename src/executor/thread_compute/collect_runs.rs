@@ -0,0 +1,115 @@
+use crate::ThreadExecutor;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::{Fallibility, Stop};
+use alloc::vec::Vec;
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Returns the run which the value at position `begin` must be pushed to.
+///
+/// The last run is continued when `begin` directly follows its end; a new run is started otherwise.
+#[inline(always)]
+fn run_at<'a, P, E>(runs: &'a mut Vec<(usize, P)>, end: usize, begin: usize, empty: &E) -> &'a mut P
+where
+    E: Fn() -> P,
+{
+    if runs.is_empty() || end != begin {
+        runs.push((begin, empty()));
+    }
+    &mut runs
+        .last_mut()
+        .expect("a run is pushed if there was none")
+        .1
+}
+
+/// Collects the values into runs of contiguous positions, each run being extended in order.
+///
+/// Returns the runs together with their begin positions and the position of the element
+/// that stopped the iteration due to a while condition, if any.
+#[allow(clippy::type_complexity)]
+pub fn x<C, I, Vo, X1, P, E>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    empty: &E,
+) -> Result<(Vec<(usize, P)>, Option<usize>), <Vo::Fallibility as Fallibility>::Error>
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: Values,
+    X1: Fn(I::Item) -> Vo,
+    P: Extend<Vo::Item>,
+    E: Fn() -> P,
+{
+    let mut runs = Vec::new();
+    let mut end = 0;
+
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller_with_idx();
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some((idx, i)) => {
+                    let run = run_at(&mut runs, end, idx, empty);
+                    end = idx + 1;
+                    let done = xap1(i).push_to_extend(run);
+                    if let Some(stop) = Vo::arbitrary_push_to_stop(done) {
+                        iter.skip_to_end();
+                        runner.complete_chunk(shared_state, chunk_size);
+                        runner.complete_task(shared_state);
+                        return match stop {
+                            Stop::DueToWhile => Ok((runs, Some(idx))),
+                            Stop::DueToError { error } => Err(error),
+                        };
+                    }
+                }
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull_with_idx() {
+                    Some((chunk_begin_idx, chunk)) => {
+                        let run = run_at(&mut runs, end, chunk_begin_idx, empty);
+                        end = chunk_begin_idx;
+                        for value in chunk {
+                            let done = xap1(value).push_to_extend(run);
+                            if let Some(stop) = Vo::arbitrary_push_to_stop(done) {
+                                iter.skip_to_end();
+                                runner.complete_chunk(shared_state, chunk_size);
+                                runner.complete_task(shared_state);
+                                return match stop {
+                                    Stop::DueToWhile => Ok((runs, Some(end))),
+                                    Stop::DueToError { error } => Err(error),
+                                };
+                            }
+                            end += 1;
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+
+    Ok((runs, None))
+}
@@ -0,0 +1,68 @@
+use crate::ThreadExecutor;
+use crate::executor::parallel_compute::collect_slice::SliceWriter;
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Writes the mapped values to their source positions in the `slice`, and returns the number of written values.
+pub fn m<C, I, O, M1, W>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    map1: &M1,
+    slice: &W,
+) -> usize
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    M1: Fn(I::Item) -> O,
+    W: SliceWriter<O>,
+{
+    let mut num_written = 0;
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller_with_idx();
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+        runner.begin_chunk(chunk_size);
+
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some((idx, value)) => {
+                    // SAFETY: each position is pulled by exactly one thread
+                    unsafe { slice.write(idx, map1(value)) };
+                    num_written += 1;
+                }
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull_with_idx() {
+                    Some((begin_idx, chunk)) => {
+                        for (i, value) in chunk.enumerate() {
+                            // SAFETY: each position is pulled by exactly one thread
+                            unsafe { slice.write(begin_idx + i, map1(value)) };
+                            num_written += 1;
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+
+    num_written
+}
@@ -0,0 +1,97 @@
+use crate::{
+    ThreadExecutor,
+    generic_values::{
+        TransformableValues, Values,
+        runner_results::{Infallible, Reduce},
+    },
+};
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+pub fn x<C, I, Vo, X1, Acc, Fold>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    identity: Acc,
+    fold: &Fold,
+) -> Acc
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: TransformableValues<Fallibility = Infallible>,
+    X1: Fn(I::Item) -> Vo,
+    Fold: Fn(Acc, Vo::Item) -> Acc,
+{
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller();
+
+    // values are folded into the accumulator of this thread while being mapped to unit;
+    // the unit values are then reduced only to drive the computation and detect the while condition
+    let mut acc = Some(identity);
+    let acc_ptr: *mut Option<Acc> = &mut acc;
+    let fold_into = |acc: *mut Option<Acc>, x: Vo::Item| {
+        // SAFETY: acc points to the accumulator of this thread which outlives the computation,
+        // and it is exclusively accessed by this closure during the computation
+        let acc = unsafe { &mut *acc };
+        *acc = acc.take().map(|a| fold(a, x));
+    };
+    let unit = |_: (), _: ()| ();
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some(i) => {
+                    let vo = xap1(i).u_map(acc_ptr, fold_into);
+                    if !matches!(vo.acc_reduce(None, unit), Reduce::Done { .. }) {
+                        iter.skip_to_end();
+                        runner.complete_chunk(shared_state, chunk_size);
+                        break;
+                    }
+                }
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull() {
+                    Some(chunk) => {
+                        let mut stopped = false;
+                        for i in chunk {
+                            let vo = xap1(i).u_map(acc_ptr, fold_into);
+                            if !matches!(vo.acc_reduce(None, unit), Reduce::Done { .. }) {
+                                stopped = true;
+                                break;
+                            }
+                        }
+                        if stopped {
+                            iter.skip_to_end();
+                            runner.complete_chunk(shared_state, chunk_size);
+                            break;
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+
+    acc.expect("accumulator is put back after each fold")
+}
@@ -0,0 +1,110 @@
+use crate::ThreadExecutor;
+use crate::executor::parallel_compute::for_each_ordered::ReorderWindow;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::{Fallibility, StopWithIdx};
+use alloc::vec::Vec;
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Computes the values of the `chunk` beginning at position `begin` and pushes them to the `window` at once.
+///
+/// Returns whether or not the iteration must continue.
+#[inline(always)]
+fn compute<T, Vo, X1, F>(
+    window: &ReorderWindow<Vo::Item, F>,
+    xap1: &X1,
+    begin: usize,
+    chunk: impl ExactSizeIterator<Item = T>,
+) -> Result<bool, <Vo::Fallibility as Fallibility>::Error>
+where
+    Vo: Values,
+    X1: Fn(T) -> Vo,
+    F: FnMut(Vo::Item) -> bool,
+{
+    let chunk_len = chunk.len();
+    if !window.wait_for(begin, chunk_len) {
+        return Ok(false);
+    }
+
+    // a panic in `xap1` or in the sink must release the threads waiting for this chunk
+    let _guard = window.panic_guard(begin);
+
+    let mut values = Vec::new();
+    for (i, value) in chunk.enumerate() {
+        let done = xap1(value).push_to_vec_with_idx(begin + i, &mut values);
+        match Vo::ordered_push_to_stop(done) {
+            None => {}
+            Some(StopWithIdx::DueToWhile { idx }) => {
+                window.push(begin, chunk_len, values, Some(idx + 1));
+                return Ok(false);
+            }
+            Some(StopWithIdx::DueToError { idx, error }) => {
+                window.push(begin, chunk_len, values, Some(idx));
+                return Err(error);
+            }
+        }
+    }
+
+    window.push(begin, chunk_len, values, None);
+    Ok(true)
+}
+
+pub fn x<C, I, Vo, X1, F>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    window: &ReorderWindow<Vo::Item, F>,
+) -> Result<(), <Vo::Fallibility as Fallibility>::Error>
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: Values,
+    X1: Fn(I::Item) -> Vo,
+    F: FnMut(Vo::Item) -> bool,
+{
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller_with_idx();
+
+    loop {
+        // a chunk is computed once it entirely fits into the window, hence, it cannot be longer than the window
+        let chunk_size = runner.next_chunk_size(shared_state, iter).min(window.len());
+
+        runner.begin_chunk(chunk_size);
+
+        let proceed = match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some((idx, i)) => compute(window, xap1, idx, core::iter::once(i)),
+                None => match iter.is_completed_when_none_returned() {
+                    true => break,
+                    false => Ok(true),
+                },
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull_with_idx() {
+                    Some((chunk_begin_idx, chunk)) => compute(window, xap1, chunk_begin_idx, chunk),
+                    None => match iter.is_completed_when_none_returned() {
+                        true => break,
+                        false => Ok(true),
+                    },
+                }
+            }
+        };
+
+        if !matches!(proceed, Ok(true)) {
+            iter.skip_to_end();
+            runner.complete_chunk(shared_state, chunk_size);
+            runner.complete_task(shared_state);
+            return proceed.map(|_| ());
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+use crate::{
+    ThreadExecutor,
+    generic_values::Values,
+    generic_values::runner_results::{LastWithIdx, Reduce},
+};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Returns true if an element satisfying the predicate is already found at a position after `idx`.
+///
+/// `last_found` holds one plus the position of the last element found so far, or zero if none.
+#[inline(always)]
+fn is_found_after(last_found: &AtomicUsize, idx: usize) -> bool {
+    last_found.load(Ordering::Relaxed) > idx + 1
+}
+
+pub fn m<C, I, O, M1, P>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    map1: &M1,
+    predicate: &P,
+    last_found: &AtomicUsize,
+) -> Option<(usize, O)>
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    M1: Fn(I::Item) -> O,
+    P: Fn(&O) -> bool,
+{
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller_with_idx();
+    let mut buffer = Vec::new();
+    let mut last = None;
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some((idx, i)) => {
+                    if !is_found_after(last_found, idx) {
+                        let value = map1(i);
+                        if predicate(&value) {
+                            _ = last_found.fetch_max(idx + 1, Ordering::Relaxed);
+                            last = Some((idx, value));
+                        }
+                    }
+                }
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull_with_idx() {
+                    Some((begin_idx, chunk)) => {
+                        // scan the chunk from the back; the first match is the last of the chunk
+                        buffer.extend(chunk);
+                        for (j, i) in buffer.drain(..).enumerate().rev() {
+                            let idx = begin_idx + j;
+                            if is_found_after(last_found, idx) {
+                                break;
+                            }
+
+                            let value = map1(i);
+                            if predicate(&value) {
+                                _ = last_found.fetch_max(idx + 1, Ordering::Relaxed);
+                                last = Some((idx, value));
+                                break;
+                            }
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+    last
+}
+
+/// Adds the last value of the element at position `idx` to the `candidates`.
+///
+/// Unless `keep_all` is set, the candidate replaces the earlier ones and `last_found` is updated,
+/// so that the elements positioned before it are not evaluated by any of the threads.
+#[inline(always)]
+fn push_candidate<T>(
+    candidates: &mut Vec<(usize, T)>,
+    candidate: (usize, T),
+    keep_all: bool,
+    last_found: &AtomicUsize,
+) {
+    if !keep_all {
+        _ = last_found.fetch_max(candidate.0 + 1, Ordering::Relaxed);
+        candidates.clear();
+    }
+    candidates.push(candidate);
+}
+
+pub fn x<C, I, Vo, X1>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    last_found: &AtomicUsize,
+) -> LastWithIdx<Vo>
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: Values,
+    X1: Fn(I::Item) -> Vo,
+{
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller_with_idx();
+    let mut buffer = Vec::new();
+
+    // when a while condition can stop the iteration at an earlier position in another thread,
+    // the later values might be invalidated; therefore, every element is evaluated and the last
+    // value of each pulled chunk is kept. Otherwise, only the last value found so far is kept,
+    // chunks are scanned from the back and the elements before a found value are skipped.
+    let keep_all = Vo::CAN_STOP_BY_WHILE;
+    let mut candidates = Vec::new();
+    let last = |_: Vo::Item, b: Vo::Item| b;
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some((idx, i)) => {
+                    if keep_all || !is_found_after(last_found, idx) {
+                        match xap1(i).acc_reduce(None, last) {
+                            Reduce::Done { acc } => {
+                                if let Some(value) = acc {
+                                    push_candidate(
+                                        &mut candidates,
+                                        (idx, value),
+                                        keep_all,
+                                        last_found,
+                                    );
+                                }
+                            }
+                            Reduce::StoppedByWhileCondition { acc } => {
+                                if let Some(value) = acc {
+                                    candidates.push((idx, value));
+                                }
+                                iter.skip_to_end();
+                                runner.complete_chunk(shared_state, chunk_size);
+                                runner.complete_task(shared_state);
+                                return LastWithIdx::StoppedByWhileCondition { candidates, idx };
+                            }
+                            Reduce::StoppedByError { error } => {
+                                iter.skip_to_end();
+                                runner.complete_chunk(shared_state, chunk_size);
+                                runner.complete_task(shared_state);
+                                return LastWithIdx::StoppedByError { error };
+                            }
+                        }
+                    }
+                }
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull_with_idx() {
+                    Some((begin_idx, chunk)) if keep_all => {
+                        let mut chunk_last = None;
+                        for (j, i) in chunk.enumerate() {
+                            let idx = begin_idx + j;
+                            match xap1(i).acc_reduce(None, last) {
+                                Reduce::Done { acc } => {
+                                    if let Some(value) = acc {
+                                        chunk_last = Some((idx, value));
+                                    }
+                                }
+                                Reduce::StoppedByWhileCondition { acc } => {
+                                    if let Some(value) = acc {
+                                        chunk_last = Some((idx, value));
+                                    }
+                                    candidates.extend(chunk_last);
+                                    iter.skip_to_end();
+                                    runner.complete_chunk(shared_state, chunk_size);
+                                    runner.complete_task(shared_state);
+                                    return LastWithIdx::StoppedByWhileCondition {
+                                        candidates,
+                                        idx,
+                                    };
+                                }
+                                Reduce::StoppedByError { error } => {
+                                    iter.skip_to_end();
+                                    runner.complete_chunk(shared_state, chunk_size);
+                                    runner.complete_task(shared_state);
+                                    return LastWithIdx::StoppedByError { error };
+                                }
+                            }
+                        }
+                        candidates.extend(chunk_last);
+                    }
+                    Some((begin_idx, chunk)) => {
+                        // scan the chunk from the back; the first value is the last of the chunk
+                        buffer.extend(chunk);
+                        for (j, i) in buffer.drain(..).enumerate().rev() {
+                            let idx = begin_idx + j;
+                            if is_found_after(last_found, idx) {
+                                break;
+                            }
+
+                            match xap1(i).acc_reduce(None, last) {
+                                // values cannot be stopped by a while condition unless keep_all
+                                Reduce::Done { acc } | Reduce::StoppedByWhileCondition { acc } => {
+                                    if let Some(value) = acc {
+                                        push_candidate(
+                                            &mut candidates,
+                                            (idx, value),
+                                            keep_all,
+                                            last_found,
+                                        );
+                                        break;
+                                    }
+                                }
+                                Reduce::StoppedByError { error } => {
+                                    iter.skip_to_end();
+                                    runner.complete_chunk(shared_state, chunk_size);
+                                    runner.complete_task(shared_state);
+                                    return LastWithIdx::StoppedByError { error };
+                                }
+                            }
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+    LastWithIdx::Completed { candidates }
+}
@@ -1,5 +1,16 @@
+pub(super) mod arg_best;
 pub(super) mod collect_arbitrary;
 pub(super) mod collect_ordered;
+pub(super) mod collect_partial;
+pub(super) mod collect_runs;
+pub(super) mod collect_slice;
+pub(super) mod fold;
+#[cfg(feature = "std")]
+pub(super) mod for_each_ordered;
+pub(super) mod last;
 pub(super) mod next;
 pub(super) mod next_any;
+pub(super) mod position;
 pub(super) mod reduce;
+pub(super) mod scan;
+pub(super) mod send;
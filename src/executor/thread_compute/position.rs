@@ -0,0 +1,151 @@
+use crate::{
+    ThreadExecutor,
+    generic_values::Values,
+    generic_values::runner_results::{Fallibility, PositionSegment, PositionWithIdx, Reduce},
+};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Returns true if a value satisfying the predicate is already found for an element positioned
+/// before `idx`; `first_found` is None when the iteration must not exit early.
+#[inline(always)]
+fn is_found_before(first_found: Option<&AtomicUsize>, idx: usize) -> bool {
+    first_found.is_some_and(|x| x.load(Ordering::Relaxed) < idx)
+}
+
+/// Reason to stop evaluating the remaining elements.
+enum Stop<E> {
+    Found,
+    StoppedByWhileCondition { idx: usize },
+    StoppedByError { error: E },
+}
+
+/// Evaluates the elements of a pulled chunk which begins at position `begin_idx`, and adds the
+/// segment of their values to `segments`.
+fn evaluate_chunk<T, Vo, X1>(
+    chunk: impl Iterator<Item = T>,
+    begin_idx: usize,
+    xap1: &X1,
+    first_found: Option<&AtomicUsize>,
+    segments: &mut Vec<(usize, PositionSegment)>,
+) -> Option<Stop<<Vo::Fallibility as Fallibility>::Error>>
+where
+    Vo: Values<Item = PositionSegment>,
+    X1: Fn(T) -> Vo,
+{
+    let mut segment: Option<PositionSegment> = None;
+    let mut stop = None;
+
+    for (j, i) in chunk.enumerate() {
+        let idx = begin_idx + j;
+        if is_found_before(first_found, idx) {
+            break;
+        }
+
+        let (values, stopped) = match xap1(i).acc_reduce(None, PositionSegment::append) {
+            Reduce::Done { acc } => (acc, false),
+            Reduce::StoppedByWhileCondition { acc } => (acc, true),
+            Reduce::StoppedByError { error } => return Some(Stop::StoppedByError { error }),
+        };
+
+        if let Some(values) = values {
+            segment = Some(match segment {
+                Some(x) => x.append(values),
+                None => values,
+            });
+
+            if let Some(first_found) = first_found
+                && values.has_match()
+            {
+                _ = first_found.fetch_min(idx, Ordering::Relaxed);
+                stop = Some(Stop::Found);
+            }
+        }
+
+        if stopped {
+            stop = Some(Stop::StoppedByWhileCondition { idx });
+        }
+
+        if stop.is_some() {
+            break;
+        }
+    }
+
+    segments.extend(segment.map(|x| (begin_idx, x)));
+    stop
+}
+
+/// Computes the position segments of the chunks pulled by this thread.
+///
+/// When `first_found` is provided, the iteration exits early once a value satisfying the
+/// predicate is found, and the elements positioned after a found value are not evaluated.
+pub fn x<C, I, Vo, X1>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    first_found: Option<&AtomicUsize>,
+) -> PositionWithIdx<Vo>
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: Values<Item = PositionSegment>,
+    X1: Fn(I::Item) -> Vo,
+{
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller_with_idx();
+    let mut segments = Vec::new();
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        let stop = match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some((idx, i)) => {
+                    let chunk = core::iter::once(i);
+                    evaluate_chunk(chunk, idx, xap1, first_found, &mut segments)
+                }
+                None => match iter.is_completed_when_none_returned() {
+                    true => break,
+                    false => None,
+                },
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull_with_idx() {
+                    Some((begin_idx, chunk)) => {
+                        evaluate_chunk(chunk, begin_idx, xap1, first_found, &mut segments)
+                    }
+                    None => match iter.is_completed_when_none_returned() {
+                        true => break,
+                        false => None,
+                    },
+                }
+            }
+        };
+
+        if let Some(stop) = stop {
+            iter.skip_to_end();
+            runner.complete_chunk(shared_state, chunk_size);
+            runner.complete_task(shared_state);
+            return match stop {
+                Stop::Found => PositionWithIdx::Completed { segments },
+                Stop::StoppedByWhileCondition { idx } => {
+                    PositionWithIdx::StoppedByWhileCondition { segments, idx }
+                }
+                Stop::StoppedByError { error } => PositionWithIdx::StoppedByError { error },
+            };
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+    PositionWithIdx::Completed { segments }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    ThreadExecutor,
+    generic_values::{
+        Values,
+        runner_results::{Infallible, StopWithIdx},
+    },
+};
+use alloc::vec::Vec;
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Inclusive scan of the values computed from a contiguous range of input positions.
+pub struct Segment<O> {
+    /// Position of the first input of the segment.
+    pub begin_idx: usize,
+    /// Position after the last input of the segment.
+    pub end_idx: usize,
+    /// Scanned values computed from the inputs of the segment.
+    pub values: Vec<O>,
+}
+
+/// Returns the segment continuing from position `begin_idx`;
+/// creates a new segment unless the last segment of the thread ends at `begin_idx`.
+#[inline(always)]
+fn segment_at<O>(segments: &mut Vec<Segment<O>>, begin_idx: usize) -> &mut Segment<O> {
+    if segments.last().is_none_or(|x| x.end_idx != begin_idx) {
+        segments.push(Segment {
+            begin_idx,
+            end_idx: begin_idx,
+            values: Vec::new(),
+        });
+    }
+    let last = segments.len() - 1;
+    &mut segments[last]
+}
+
+/// Pushes the values of the input at position `idx` to the `segment` continuing its inclusive scan;
+/// returns the position of the input if the computation is stopped due to a while condition.
+#[inline(always)]
+fn push_scanned<Vo, Op>(
+    segment: &mut Segment<Vo::Item>,
+    buffer: &mut Vec<(usize, Vo::Item)>,
+    idx: usize,
+    vo: Vo,
+    op: &Op,
+) -> Option<usize>
+where
+    Vo: Values<Fallibility = Infallible>,
+    Op: Fn(&Vo::Item, &Vo::Item) -> Vo::Item,
+{
+    let done = vo.push_to_vec_with_idx(idx, buffer);
+    for (_, x) in buffer.drain(..) {
+        let y = match segment.values.last() {
+            Some(prev) => op(prev, &x),
+            None => x,
+        };
+        segment.values.push(y);
+    }
+    segment.end_idx = idx + 1;
+
+    match Vo::ordered_push_to_stop(done) {
+        None => None,
+        Some(StopWithIdx::DueToWhile { idx }) => Some(idx),
+        Some(StopWithIdx::DueToError { idx: _, error }) => match error {},
+    }
+}
+
+pub fn x<C, I, Vo, X1, Op>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    op: &Op,
+) -> (Vec<Segment<Vo::Item>>, Option<usize>)
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: Values<Fallibility = Infallible>,
+    X1: Fn(I::Item) -> Vo,
+    Op: Fn(&Vo::Item, &Vo::Item) -> Vo::Item,
+{
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller_with_idx();
+
+    let mut segments = Vec::new();
+    let mut buffer = Vec::new();
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some((idx, i)) => {
+                    let segment = segment_at(&mut segments, idx);
+                    if let Some(stopped_idx) = push_scanned(segment, &mut buffer, idx, xap1(i), op)
+                    {
+                        iter.skip_to_end();
+                        runner.complete_chunk(shared_state, chunk_size);
+                        runner.complete_task(shared_state);
+                        return (segments, Some(stopped_idx));
+                    }
+                }
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull_with_idx() {
+                    Some((begin_idx, chunk)) => {
+                        let segment = segment_at(&mut segments, begin_idx);
+                        for (j, i) in chunk.enumerate() {
+                            let idx = begin_idx + j;
+                            if let Some(stopped_idx) =
+                                push_scanned(segment, &mut buffer, idx, xap1(i), op)
+                            {
+                                iter.skip_to_end();
+                                runner.complete_chunk(shared_state, chunk_size);
+                                runner.complete_task(shared_state);
+                                return (segments, Some(stopped_idx));
+                            }
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+    (segments, None)
+}
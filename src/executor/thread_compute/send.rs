@@ -0,0 +1,97 @@
+use crate::ThreadExecutor;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::{Fallibility, Stop};
+use crate::sink::Sink;
+use alloc::vec::Vec;
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Sends the `batch` to the `sink` and clears it; returns false if the sink is disconnected.
+#[inline(always)]
+fn flush<T, S: Sink<T>>(sink: &S, batch: &mut Vec<T>) -> bool {
+    let connected = batch.is_empty() || sink.send_batch(batch);
+    batch.clear();
+    connected
+}
+
+/// Sends the values to the `sink` in batches, each batch containing the values computed from a pulled chunk.
+///
+/// Returns whether or not the computation is stopped early due to a disconnected sink.
+pub fn x<C, I, Vo, X1, S>(
+    mut runner: C,
+    iter: &I,
+    shared_state: &C::SharedState,
+    xap1: &X1,
+    sink: &S,
+) -> Result<bool, <Vo::Fallibility as Fallibility>::Error>
+where
+    C: ThreadExecutor,
+    I: ConcurrentIter,
+    Vo: Values,
+    X1: Fn(I::Item) -> Vo,
+    S: Sink<Vo::Item>,
+{
+    let mut batch = Vec::new();
+
+    let mut chunk_puller = iter.chunk_puller(0);
+    let mut item_puller = iter.item_puller();
+
+    loop {
+        let chunk_size = runner.next_chunk_size(shared_state, iter);
+
+        runner.begin_chunk(chunk_size);
+
+        let mut stop = None;
+        match chunk_size {
+            0 | 1 => match item_puller.next() {
+                Some(i) => stop = Vo::arbitrary_push_to_stop(xap1(i).push_to_extend(&mut batch)),
+                None => {
+                    if iter.is_completed_when_none_returned() {
+                        break;
+                    }
+                }
+            },
+            c => {
+                if c > chunk_puller.chunk_size() {
+                    chunk_puller = iter.chunk_puller(c);
+                }
+
+                match chunk_puller.pull() {
+                    Some(chunk) => {
+                        for value in chunk {
+                            let done = xap1(value).push_to_extend(&mut batch);
+                            stop = Vo::arbitrary_push_to_stop(done);
+                            if stop.is_some() {
+                                break;
+                            }
+                        }
+                    }
+                    None => {
+                        if iter.is_completed_when_none_returned() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        let result = match stop {
+            None if flush(sink, &mut batch) => None,
+            None => Some(Ok(true)),
+            Some(Stop::DueToWhile) => Some(Ok(!flush(sink, &mut batch))),
+            Some(Stop::DueToError { error }) => Some(Err(error)),
+        };
+
+        if let Some(result) = result {
+            iter.skip_to_end();
+            runner.complete_chunk(shared_state, chunk_size);
+            runner.complete_task(shared_state);
+            return result;
+        }
+
+        runner.complete_chunk(shared_state, chunk_size);
+    }
+
+    runner.complete_task(shared_state);
+
+    Ok(false)
+}
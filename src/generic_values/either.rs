@@ -0,0 +1,278 @@
+use crate::generic_values::runner_results::{
+    ArbitraryPush, Fallible, Next, OrderedPush, Reduce, SequentialPush,
+};
+use crate::generic_values::{TransformableValues, Values};
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+use orx_pinned_vec::{IntoConcurrentPinnedVec, PinnedVec};
+
+/// Either a value of the left type or a value of the right type.
+///
+/// It is used as the element type of chained concurrent iterators and as the values
+/// produced by chained computations; i.e., values of the left and right computations
+/// are collected and reduced together although they are of different types.
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R>
+where
+    L: Values,
+    R: Values<Item = L::Item, Fallibility = L::Fallibility>,
+{
+    #[inline(always)]
+    fn from_left_reduce(reduce: Reduce<L>) -> Reduce<Self> {
+        match reduce {
+            Reduce::Done { acc } => Reduce::Done { acc },
+            Reduce::StoppedByWhileCondition { acc } => Reduce::StoppedByWhileCondition { acc },
+            Reduce::StoppedByError { error } => Reduce::StoppedByError { error },
+        }
+    }
+
+    #[inline(always)]
+    fn from_right_reduce(reduce: Reduce<R>) -> Reduce<Self> {
+        match reduce {
+            Reduce::Done { acc } => Reduce::Done { acc },
+            Reduce::StoppedByWhileCondition { acc } => Reduce::StoppedByWhileCondition { acc },
+            Reduce::StoppedByError { error } => Reduce::StoppedByError { error },
+        }
+    }
+
+    #[inline(always)]
+    fn from_left_next(next: Next<L>) -> Next<Self> {
+        match next {
+            Next::Done { value } => Next::Done { value },
+            Next::StoppedByWhileCondition => Next::StoppedByWhileCondition,
+            Next::StoppedByError { error } => Next::StoppedByError { error },
+        }
+    }
+
+    #[inline(always)]
+    fn from_right_next(next: Next<R>) -> Next<Self> {
+        match next {
+            Next::Done { value } => Next::Done { value },
+            Next::StoppedByWhileCondition => Next::StoppedByWhileCondition,
+            Next::StoppedByError { error } => Next::StoppedByError { error },
+        }
+    }
+}
+
+impl<L, R> Values for Either<L, R>
+where
+    L: Values,
+    R: Values<Item = L::Item, Fallibility = L::Fallibility>,
+{
+    type Item = L::Item;
+
+    type Fallibility = L::Fallibility;
+
+    const CAN_STOP_BY_WHILE: bool = L::CAN_STOP_BY_WHILE || R::CAN_STOP_BY_WHILE;
+
+    #[inline(always)]
+    fn push_to_pinned_vec<P>(self, vector: &mut P) -> SequentialPush<Self::Fallibility>
+    where
+        P: PinnedVec<Self::Item>,
+    {
+        match self {
+            Self::Left(x) => x.push_to_pinned_vec(vector),
+            Self::Right(x) => x.push_to_pinned_vec(vector),
+        }
+    }
+
+    #[inline(always)]
+    fn push_to_vec_with_idx(
+        self,
+        idx: usize,
+        vec: &mut Vec<(usize, Self::Item)>,
+    ) -> OrderedPush<Self::Fallibility> {
+        match self {
+            Self::Left(x) => x.push_to_vec_with_idx(idx, vec),
+            Self::Right(x) => x.push_to_vec_with_idx(idx, vec),
+        }
+    }
+
+    #[inline(always)]
+    fn push_to_bag<P>(self, bag: &ConcurrentBag<Self::Item, P>) -> ArbitraryPush<Self::Fallibility>
+    where
+        P: IntoConcurrentPinnedVec<Self::Item>,
+        Self::Item: Send,
+    {
+        match self {
+            Self::Left(x) => x.push_to_bag(bag),
+            Self::Right(x) => x.push_to_bag(bag),
+        }
+    }
+
+    #[inline(always)]
+    fn acc_reduce<X>(self, acc: Option<Self::Item>, reduce: X) -> Reduce<Self>
+    where
+        X: Fn(Self::Item, Self::Item) -> Self::Item,
+    {
+        match self {
+            Self::Left(x) => Self::from_left_reduce(x.acc_reduce(acc, reduce)),
+            Self::Right(x) => Self::from_right_reduce(x.acc_reduce(acc, reduce)),
+        }
+    }
+
+    #[inline(always)]
+    fn u_acc_reduce<U, X>(self, u: *mut U, acc: Option<Self::Item>, reduce: X) -> Reduce<Self>
+    where
+        X: Fn(*mut U, Self::Item, Self::Item) -> Self::Item,
+    {
+        match self {
+            Self::Left(x) => Self::from_left_reduce(x.u_acc_reduce(u, acc, reduce)),
+            Self::Right(x) => Self::from_right_reduce(x.u_acc_reduce(u, acc, reduce)),
+        }
+    }
+
+    fn next(self) -> Next<Self> {
+        match self {
+            Self::Left(x) => Self::from_left_next(x.next()),
+            Self::Right(x) => Self::from_right_next(x.next()),
+        }
+    }
+}
+
+impl<L, R> TransformableValues for Either<L, R>
+where
+    L: TransformableValues,
+    R: TransformableValues<Item = L::Item, Fallibility = L::Fallibility>,
+{
+    #[inline(always)]
+    fn map<M, O>(
+        self,
+        map: M,
+    ) -> impl TransformableValues<Item = O, Fallibility = Self::Fallibility>
+    where
+        M: Fn(Self::Item) -> O + Clone,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.map(map)),
+            Self::Right(x) => Either::Right(x.map(map)),
+        }
+    }
+
+    #[inline(always)]
+    fn filter<F>(
+        self,
+        filter: F,
+    ) -> impl TransformableValues<Item = Self::Item, Fallibility = Self::Fallibility>
+    where
+        F: Fn(&Self::Item) -> bool + Clone,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.filter(filter)),
+            Self::Right(x) => Either::Right(x.filter(filter)),
+        }
+    }
+
+    #[inline(always)]
+    fn flat_map<Fm, Vo>(
+        self,
+        flat_map: Fm,
+    ) -> impl TransformableValues<Item = Vo::Item, Fallibility = Self::Fallibility>
+    where
+        Vo: IntoIterator,
+        Fm: Fn(Self::Item) -> Vo + Clone,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.flat_map(flat_map)),
+            Self::Right(x) => Either::Right(x.flat_map(flat_map)),
+        }
+    }
+
+    #[inline(always)]
+    fn filter_map<Fm, O>(
+        self,
+        filter_map: Fm,
+    ) -> impl TransformableValues<Item = O, Fallibility = Self::Fallibility>
+    where
+        Fm: Fn(Self::Item) -> Option<O>,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.filter_map(filter_map)),
+            Self::Right(x) => Either::Right(x.filter_map(filter_map)),
+        }
+    }
+
+    fn whilst(
+        self,
+        whilst: impl Fn(&Self::Item) -> bool,
+    ) -> impl TransformableValues<Item = Self::Item, Fallibility = Self::Fallibility> {
+        match self {
+            Self::Left(x) => Either::Left(x.whilst(whilst)),
+            Self::Right(x) => Either::Right(x.whilst(whilst)),
+        }
+    }
+
+    fn map_while_ok<Mr, O, E>(self, map_res: Mr) -> impl Values<Item = O, Fallibility = Fallible<E>>
+    where
+        Mr: Fn(Self::Item) -> Result<O, E>,
+        E: Send,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.map_while_ok(map_res)),
+            Self::Right(x) => Either::Right(x.map_while_ok(map_res)),
+        }
+    }
+
+    #[inline(always)]
+    fn u_map<U, M, O>(
+        self,
+        u: *mut U,
+        map: M,
+    ) -> impl TransformableValues<Item = O, Fallibility = Self::Fallibility>
+    where
+        M: Fn(*mut U, Self::Item) -> O,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.u_map(u, map)),
+            Self::Right(x) => Either::Right(x.u_map(u, map)),
+        }
+    }
+
+    #[inline(always)]
+    fn u_filter<U, F>(
+        self,
+        u: *mut U,
+        filter: F,
+    ) -> impl TransformableValues<Item = Self::Item, Fallibility = Self::Fallibility>
+    where
+        F: Fn(*mut U, &Self::Item) -> bool,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.u_filter(u, filter)),
+            Self::Right(x) => Either::Right(x.u_filter(u, filter)),
+        }
+    }
+
+    fn u_flat_map<U, Fm, Vo>(
+        self,
+        u: *mut U,
+        flat_map: Fm,
+    ) -> impl TransformableValues<Item = Vo::Item, Fallibility = Self::Fallibility>
+    where
+        Vo: IntoIterator,
+        Fm: Fn(*mut U, Self::Item) -> Vo,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.u_flat_map(u, flat_map)),
+            Self::Right(x) => Either::Right(x.u_flat_map(u, flat_map)),
+        }
+    }
+
+    fn u_filter_map<U, Fm, O>(
+        self,
+        u: *mut U,
+        filter_map: Fm,
+    ) -> impl TransformableValues<Item = O, Fallibility = Self::Fallibility>
+    where
+        Fm: Fn(*mut U, Self::Item) -> Option<O>,
+    {
+        match self {
+            Self::Left(x) => Either::Left(x.u_filter_map(u, filter_map)),
+            Self::Right(x) => Either::Right(x.u_filter_map(u, filter_map)),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+mod either;
 pub mod fallible_iterators;
 mod option;
 mod option_result;
@@ -15,6 +16,7 @@ mod whilst_option_result;
 mod whilst_vector;
 mod whilst_vector_result;
 
+pub use either::Either;
 pub use transformable_values::TransformableValues;
 pub use values::Values;
 pub use vector::Vector;
@@ -3,6 +3,7 @@ mod collect_ordered;
 mod collect_sequential;
 mod fallibility;
 mod next;
+mod position;
 mod reduce;
 mod stop;
 
@@ -10,6 +11,7 @@ pub use collect_arbitrary::{ArbitraryPush, ParallelCollectArbitrary, ThreadColle
 pub use collect_ordered::{OrderedPush, ParallelCollect, ThreadCollect};
 pub use collect_sequential::SequentialPush;
 pub use fallibility::{Fallibility, Fallible, Infallible, Never};
-pub use next::{Next, NextSuccess, NextWithIdx};
+pub use next::{LastSuccess, LastWithIdx, Next, NextSuccess, NextWithIdx};
+pub use position::{PositionSegment, PositionSuccess, PositionWithIdx};
 pub use reduce::Reduce;
 pub use stop::{Stop, StopReduce, StopWithIdx};
@@ -1,4 +1,5 @@
 use crate::generic_values::{Values, runner_results::Fallibility};
+use alloc::vec::Vec;
 
 pub enum Next<V: Values> {
     Done {
@@ -52,3 +53,40 @@ impl<T> NextSuccess<T> {
         })
     }
 }
+
+pub enum LastWithIdx<V: Values> {
+    Completed {
+        candidates: Vec<(usize, V::Item)>,
+    },
+    StoppedByWhileCondition {
+        candidates: Vec<(usize, V::Item)>,
+        idx: usize,
+    },
+    StoppedByError {
+        error: <V::Fallibility as Fallibility>::Error,
+    },
+}
+
+pub struct LastSuccess<T> {
+    pub candidates: Vec<(usize, T)>,
+    pub stopped_idx: Option<usize>,
+}
+
+impl<T> LastSuccess<T> {
+    pub fn reduce(results: impl IntoIterator<Item = Self>) -> Option<(usize, T)> {
+        let mut all_candidates = Vec::new();
+        let mut idx_bound = usize::MAX;
+        for x in results {
+            if let Some(idx) = x.stopped_idx {
+                idx_bound = idx_bound.min(idx);
+            }
+            all_candidates.extend(x.candidates);
+        }
+
+        // candidates found after the earliest stop are not within the iteration
+        all_candidates
+            .into_iter()
+            .filter(|(idx, _)| *idx <= idx_bound)
+            .max_by_key(|(idx, _)| *idx)
+    }
+}
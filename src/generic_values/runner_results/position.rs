@@ -0,0 +1,107 @@
+use crate::generic_values::{Values, runner_results::Fallibility};
+use alloc::vec::Vec;
+
+/// Values yielded by a consecutive range of elements, reduced to their number and the positions
+/// of the first and last values satisfying a predicate within the range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PositionSegment {
+    len: usize,
+    first: Option<usize>,
+    last: Option<usize>,
+}
+
+impl PositionSegment {
+    /// Segment of a single value which satisfies the predicate if `is_match` is true.
+    pub fn new(is_match: bool) -> Self {
+        let position = is_match.then_some(0);
+        Self {
+            len: 1,
+            first: position,
+            last: position,
+        }
+    }
+
+    /// Segment of the values of `self` followed by the values of `next`.
+    pub fn append(self, next: Self) -> Self {
+        Self {
+            len: self.len + next.len,
+            first: self.first.or(next.first.map(|x| self.len + x)),
+            last: next.last.map(|x| self.len + x).or(self.last),
+        }
+    }
+
+    pub fn has_match(&self) -> bool {
+        self.first.is_some()
+    }
+}
+
+pub enum PositionWithIdx<V: Values> {
+    Completed {
+        segments: Vec<(usize, PositionSegment)>,
+    },
+    StoppedByWhileCondition {
+        segments: Vec<(usize, PositionSegment)>,
+        idx: usize,
+    },
+    StoppedByError {
+        error: <V::Fallibility as Fallibility>::Error,
+    },
+}
+
+pub struct PositionSuccess {
+    pub segments: Vec<(usize, PositionSegment)>,
+    pub stopped_idx: Option<usize>,
+}
+
+impl PositionSuccess {
+    /// Position of the first value satisfying the predicate among all values.
+    pub fn reduce_first(results: impl IntoIterator<Item = Self>) -> Option<usize> {
+        let mut position = None;
+        Self::for_each_in_order(results, |offset, segment| match segment.first {
+            Some(x) => {
+                position = Some(offset + x);
+                false
+            }
+            None => true,
+        });
+        position
+    }
+
+    /// Position of the last value satisfying the predicate among all values.
+    pub fn reduce_last(results: impl IntoIterator<Item = Self>) -> Option<usize> {
+        let mut position = None;
+        Self::for_each_in_order(results, |offset, segment| {
+            if let Some(x) = segment.last {
+                position = Some(offset + x);
+            }
+            true
+        });
+        position
+    }
+
+    /// Visits the segments in order together with the number of values before each of them,
+    /// until `visit` returns false.
+    fn for_each_in_order<F>(results: impl IntoIterator<Item = Self>, mut visit: F)
+    where
+        F: FnMut(usize, &PositionSegment) -> bool,
+    {
+        let mut all_segments = Vec::new();
+        let mut idx_bound = usize::MAX;
+        for x in results {
+            if let Some(idx) = x.stopped_idx {
+                idx_bound = idx_bound.min(idx);
+            }
+            all_segments.extend(x.segments);
+        }
+        all_segments.sort_unstable_by_key(|(idx, _)| *idx);
+
+        // segments beginning after the earliest stop are not within the iteration
+        let mut offset = 0;
+        for (_, segment) in all_segments.iter().take_while(|(idx, _)| *idx <= idx_bound) {
+            if !visit(offset, segment) {
+                break;
+            }
+            offset += segment.len;
+        }
+    }
+}
@@ -12,6 +12,9 @@ pub trait Values: Sized {
 
     type Fallibility: Fallibility;
 
+    /// Whether or not the values might stop the iteration due to a while condition.
+    const CAN_STOP_BY_WHILE: bool;
+
     fn push_to_pinned_vec<P>(self, vector: &mut P) -> SequentialPush<Self::Fallibility>
     where
         P: PinnedVec<Self::Item>;
@@ -39,6 +42,31 @@ pub trait Values: Sized {
 
     // provided
 
+    /// Extends the `collection` with the values, stopping at the first while condition or error.
+    fn push_to_extend<E>(self, collection: &mut E) -> ArbitraryPush<Self::Fallibility>
+    where
+        E: Extend<Self::Item>,
+    {
+        // all but the last value are extended while being reduced; the last one is the accumulator
+        let extend_one = |collection: *mut E, a: Self::Item, b: Self::Item| {
+            // SAFETY: collection is exclusively borrowed by this method and is not accessed
+            // anywhere else while the values are being reduced
+            unsafe { &mut *collection }.extend(core::iter::once(a));
+            b
+        };
+        match self.u_acc_reduce(collection, None, extend_one) {
+            Reduce::Done { acc } => {
+                collection.extend(acc);
+                ArbitraryPush::Done
+            }
+            Reduce::StoppedByWhileCondition { acc } => {
+                collection.extend(acc);
+                ArbitraryPush::StoppedByWhileCondition
+            }
+            Reduce::StoppedByError { error } => ArbitraryPush::StoppedByError { error },
+        }
+    }
+
     #[inline(always)]
     fn ordered_push_to_stop(
         ordered_push: OrderedPush<Self::Fallibility>,
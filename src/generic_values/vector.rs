@@ -22,6 +22,8 @@ where
 
     type Fallibility = Infallible;
 
+    const CAN_STOP_BY_WHILE: bool = false;
+
     #[inline(always)]
     fn push_to_pinned_vec<P>(self, vector: &mut P) -> SequentialPush<Self::Fallibility>
     where
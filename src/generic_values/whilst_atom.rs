@@ -28,6 +28,8 @@ impl<T> Values for WhilstAtom<T> {
 
     type Fallibility = Infallible;
 
+    const CAN_STOP_BY_WHILE: bool = true;
+
     fn push_to_pinned_vec<P>(self, vector: &mut P) -> SequentialPush<Self::Fallibility>
     where
         P: PinnedVec<Self::Item>,
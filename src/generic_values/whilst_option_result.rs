@@ -24,6 +24,8 @@ where
 
     type Fallibility = Fallible<E>;
 
+    const CAN_STOP_BY_WHILE: bool = true;
+
     fn push_to_pinned_vec<P>(self, vector: &mut P) -> SequentialPush<Self::Fallibility>
     where
         P: PinnedVec<Self::Item>,
@@ -0,0 +1,47 @@
+use super::con_iter::ConIterBuffered;
+use alloc::vec::Drain;
+use alloc::vec::Vec;
+use orx_concurrent_iter::ChunkPuller;
+
+/// Chunk puller of a concurrent iterator whose elements are buffered by a producer thread.
+///
+/// Each pull takes up to `chunk_size` contiguous elements of the buffer into the buffer of the puller.
+pub struct BufferedChunkPuller<'i, T: Send> {
+    iter: &'i ConIterBuffered<T>,
+    chunk_size: usize,
+    buffer: Vec<T>,
+}
+
+impl<'i, T: Send> BufferedChunkPuller<'i, T> {
+    pub(super) fn new(iter: &'i ConIterBuffered<T>, chunk_size: usize) -> Self {
+        let chunk_size = chunk_size.max(1);
+        Self {
+            iter,
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size),
+        }
+    }
+}
+
+impl<T: Send> ChunkPuller for BufferedChunkPuller<'_, T> {
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = Drain<'c, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.pull_with_idx().map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.buffer.clear();
+        let begin_idx = self.iter.pull_into(&mut self.buffer, self.chunk_size)?;
+        Some((begin_idx, self.buffer.drain(..)))
+    }
+}
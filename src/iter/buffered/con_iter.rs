@@ -0,0 +1,129 @@
+use super::chunk_puller::BufferedChunkPuller;
+use super::ring::Ring;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+use std::thread::{self, JoinHandle};
+
+/// Marks the ring as completed once the producer exits, including when the source iterator panics.
+struct CompleteOnDrop<'a, T>(&'a Ring<T>);
+
+impl<T> Drop for CompleteOnDrop<'_, T> {
+    fn drop(&mut self) {
+        self.0.complete(thread::panicking());
+    }
+}
+
+/// Concurrent iterator whose elements are pulled from a sequential iterator by a dedicated producer thread.
+///
+/// The producer thread drains the source iterator into a bounded lock-free ring buffer, pausing while the
+/// buffer is full. Threads of the parallel computation take contiguous chunks of elements from the buffer
+/// without contending on the source iterator.
+pub struct ConIterBuffered<T: Send> {
+    ring: Arc<Ring<T>>,
+    producer: Option<JoinHandle<()>>,
+}
+
+impl<T: Send> ConIterBuffered<T> {
+    pub(crate) fn new<I>(iter: I, buffer_len: usize) -> Self
+    where
+        I: Iterator<Item = T> + Send + 'static,
+        T: 'static,
+    {
+        assert!(buffer_len > 0, "buffer length must be positive");
+        let ring = Arc::new(Ring::new(buffer_len));
+        let producer_ring = ring.clone();
+        let produce = move || {
+            let _complete = CompleteOnDrop(&producer_ring);
+            for x in iter {
+                // SAFETY: this is the only thread pushing to the ring
+                if !unsafe { producer_ring.push(x) } {
+                    break;
+                }
+            }
+        };
+        Self {
+            ring,
+            producer: Some(thread::spawn(produce)),
+        }
+    }
+
+    /// Moves at least one and at most `chunk_size` elements into the `buffer` and returns the position
+    /// of the first element; returns None if there will not be any more elements.
+    pub(super) fn pull_into(&self, buffer: &mut Vec<T>, chunk_size: usize) -> Option<usize> {
+        let (begin_idx, len) = self.ring.claim(chunk_size)?;
+        // SAFETY: positions within begin_idx..begin_idx+len are claimed by this call
+        unsafe { self.ring.take_into(begin_idx, len, buffer) };
+        Some(begin_idx)
+    }
+}
+
+impl<T: Send> Drop for ConIterBuffered<T> {
+    fn drop(&mut self) {
+        // stops the producer if the iterator is dropped before the source iterator is consumed
+        self.ring.skip_to_end();
+        if let Some(producer) = self.producer.take() {
+            let _ = producer.join();
+        }
+    }
+}
+
+impl<T: Send> ConcurrentIter for ConIterBuffered<T> {
+    type Item = T;
+
+    type SequentialIter = SeqIterBuffered<T>;
+
+    type ChunkPuller<'i>
+        = BufferedChunkPuller<'i, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        SeqIterBuffered { iter: self }
+    }
+
+    fn skip_to_end(&self) {
+        self.ring.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.next_with_idx().map(|(_, x)| x)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        let (idx, _) = self.ring.claim(1)?;
+        // SAFETY: position idx is claimed by this call
+        Some((idx, unsafe { self.ring.take(idx) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.ring.is_skipped() {
+            true => (0, Some(0)),
+            false => match self.ring.num_available() {
+                (len, true) => (len, Some(len)),
+                (len, false) => (len, None),
+            },
+        }
+    }
+
+    fn is_completed_when_none_returned(&self) -> bool {
+        true
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        BufferedChunkPuller::new(self, chunk_size)
+    }
+}
+
+/// Sequential iterator over the remaining elements of a [`ConIterBuffered`].
+pub struct SeqIterBuffered<T: Send> {
+    iter: ConIterBuffered<T>,
+}
+
+impl<T: Send> Iterator for SeqIterBuffered<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
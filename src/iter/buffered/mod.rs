@@ -0,0 +1,5 @@
+mod chunk_puller;
+mod con_iter;
+mod ring;
+
+pub use con_iter::{ConIterBuffered, SeqIterBuffered};
@@ -0,0 +1,272 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering, fence};
+use std::sync::{Condvar, Mutex};
+
+/// Number of times a waiting thread re-checks its condition before blocking.
+const NUM_SPINS: usize = 64;
+
+/// Blocks threads until a condition holds, while allowing the notifying thread to skip the lock
+/// and the system call when there is no waiting thread.
+struct Signal {
+    lock: Mutex<()>,
+    condvar: Condvar,
+    num_waiting: AtomicUsize,
+}
+
+impl Signal {
+    fn new() -> Self {
+        Self {
+            lock: Mutex::new(()),
+            condvar: Condvar::new(),
+            num_waiting: AtomicUsize::new(0),
+        }
+    }
+
+    /// Blocks until `is_ready` returns true; briefly spins before blocking.
+    fn wait_until(&self, is_ready: impl Fn() -> bool) {
+        for _ in 0..NUM_SPINS {
+            if is_ready() {
+                return;
+            }
+            core::hint::spin_loop();
+        }
+
+        let mut guard = self.lock.lock().expect("buffer signal is poisoned");
+        self.num_waiting.fetch_add(1, Ordering::SeqCst);
+        // pairs with the fence of `notify`: either the waiter observes the change or the notifier observes the waiter
+        fence(Ordering::SeqCst);
+        while !is_ready() {
+            guard = self.condvar.wait(guard).expect("buffer signal is poisoned");
+        }
+        self.num_waiting.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Wakes up the waiting threads, if any, so that they re-check their conditions.
+    fn notify(&self) {
+        fence(Ordering::SeqCst);
+        if self.num_waiting.load(Ordering::SeqCst) > 0 {
+            // acquiring the lock makes sure that a waiter is either not yet checking its condition or already blocked
+            drop(self.lock.lock());
+            self.condvar.notify_all();
+        }
+    }
+}
+
+struct Slot<T> {
+    /// Stamp of the slot: `2 * position` when the slot is free to be written for the element at `position`,
+    /// and `2 * position + 1` once the element at `position` is written and not yet taken.
+    stamp: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// A bounded lock-free ring buffer with a single producer and multiple consumers.
+///
+/// The element at `position` is stored in the slot at `position % capacity`. The producer writes elements
+/// one by one, while consumers claim contiguous ranges of written positions with a single atomic operation.
+///
+/// Each slot holds a single element rather than a chunk of elements. Since the source iterator is expected to be
+/// expensive, an element is made available to the consumers as soon as it is produced, rather than once a chunk
+/// is filled; and consumers claim as many elements as their chunk sizes with a single operation, independent of
+/// how the elements are written. The release store per element is performed by the single producer on the stamp
+/// of its own slot; hence, it is not contended.
+///
+/// Accessing the ring never blocks while it is neither full nor empty. The producer waiting on a full ring and the
+/// consumers waiting on an empty ring briefly spin and then block until they are notified, so that they do not take
+/// the processor from the threads they are waiting for.
+pub struct Ring<T> {
+    slots: Box<[Slot<T>]>,
+    /// Number of elements written by the producer.
+    tail: AtomicUsize,
+    /// Number of elements claimed by the consumers.
+    head: AtomicUsize,
+    /// Whether or not the producer will not write any more elements.
+    completed: AtomicBool,
+    /// Whether or not the consumers will not claim any more elements.
+    skipped: AtomicBool,
+    /// Whether or not the producer stopped due to a panic.
+    panicked: AtomicBool,
+    /// Signal on which the producer waits while the ring is full.
+    not_full: Signal,
+    /// Signal on which the consumers wait while the ring is empty.
+    not_empty: Signal,
+}
+
+// SAFETY: values are written only by the single producer to free slots, and each written value is taken by
+// exactly one consumer which claimed its position; slot stamps synchronize the accesses.
+unsafe impl<T: Send> Sync for Ring<T> {}
+
+impl<T> Ring<T> {
+    pub fn new(capacity: usize) -> Self {
+        let slots = (0..capacity)
+            .map(|i| Slot {
+                stamp: AtomicUsize::new(2 * i),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            })
+            .collect();
+        Self {
+            slots,
+            tail: AtomicUsize::new(0),
+            head: AtomicUsize::new(0),
+            completed: AtomicBool::new(false),
+            skipped: AtomicBool::new(false),
+            panicked: AtomicBool::new(false),
+            not_full: Signal::new(),
+            not_empty: Signal::new(),
+        }
+    }
+
+    fn slot(&self, position: usize) -> &Slot<T> {
+        &self.slots[position % self.slots.len()]
+    }
+
+    /// Writes the `value` to the next position, waiting while the ring is full.
+    ///
+    /// Returns false without writing the value if the consumers are skipped to the end.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called by the single producer.
+    pub unsafe fn push(&self, value: T) -> bool {
+        let position = self.tail.load(Ordering::Relaxed);
+        let slot = self.slot(position);
+        let is_free = || slot.stamp.load(Ordering::Acquire) == 2 * position;
+        self.not_full.wait_until(|| is_free() || self.is_skipped());
+        if !is_free() {
+            return false;
+        }
+        // SAFETY: the slot is free since its element, if any, is already taken by a consumer
+        unsafe { (*slot.value.get()).write(value) };
+        slot.stamp.store(2 * position + 1, Ordering::Release);
+        self.tail.store(position + 1, Ordering::Release);
+        self.not_empty.notify();
+        true
+    }
+
+    /// Marks that the producer will not write any more elements.
+    pub fn complete(&self, panicked: bool) {
+        self.panicked.store(panicked, Ordering::Relaxed);
+        self.completed.store(true, Ordering::Release);
+        self.not_empty.notify();
+    }
+
+    /// Marks that the consumers will not claim any more elements.
+    pub fn skip_to_end(&self) {
+        self.skipped.store(true, Ordering::Relaxed);
+        self.not_full.notify();
+        self.not_empty.notify();
+    }
+
+    pub fn is_skipped(&self) -> bool {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Claims at least one and at most `max_len` contiguous elements, waiting while the ring is empty.
+    ///
+    /// Returns the position of the first claimed element and the number of claimed elements;
+    /// or None if there will not be any more elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the producer panicked.
+    pub fn claim(&self, max_len: usize) -> Option<(usize, usize)> {
+        loop {
+            if self.is_skipped() {
+                return None;
+            }
+
+            // completed is read before tail, so that no element written before completion is missed
+            let completed = self.completed.load(Ordering::Acquire);
+            let tail = self.tail.load(Ordering::Acquire);
+            let head = self.head.load(Ordering::Acquire);
+
+            match head < tail {
+                true => {
+                    let len = (tail - head).min(max_len.max(1));
+                    let claimed = self.head.compare_exchange_weak(
+                        head,
+                        head + len,
+                        Ordering::AcqRel,
+                        Ordering::Relaxed,
+                    );
+                    if claimed.is_ok() {
+                        return Some((head, len));
+                    }
+                }
+                false if completed => {
+                    assert!(
+                        !self.panicked.load(Ordering::Relaxed),
+                        "source iterator of the buffered parallel iterator panicked"
+                    );
+                    return None;
+                }
+                false => self.not_empty.wait_until(|| {
+                    self.is_skipped()
+                        || self.completed.load(Ordering::Acquire)
+                        || self.head.load(Ordering::Acquire) < self.tail.load(Ordering::Acquire)
+                }),
+            }
+        }
+    }
+
+    /// Takes the element at the `position` and frees its slot, without notifying the producer.
+    ///
+    /// # Safety
+    ///
+    /// The `position` must be claimed by the caller and must be taken only once.
+    unsafe fn take_silently(&self, position: usize) -> T {
+        let slot = self.slot(position);
+        // SAFETY: the element at position is written, since it is claimed, and is taken only by this caller
+        let value = unsafe { (*slot.value.get()).assume_init_read() };
+        let next_position = position + self.slots.len();
+        slot.stamp.store(2 * next_position, Ordering::Release);
+        value
+    }
+
+    /// Takes the element at the `position` and frees its slot.
+    ///
+    /// # Safety
+    ///
+    /// The `position` must be claimed by the caller and must be taken only once.
+    pub unsafe fn take(&self, position: usize) -> T {
+        // SAFETY: guaranteed by the caller
+        let value = unsafe { self.take_silently(position) };
+        self.not_full.notify();
+        value
+    }
+
+    /// Takes the elements at the `len` positions starting from `begin` into the `buffer` and frees their slots.
+    ///
+    /// # Safety
+    ///
+    /// The positions must be claimed by the caller and must be taken only once.
+    pub unsafe fn take_into(&self, begin: usize, len: usize, buffer: &mut Vec<T>) {
+        for position in begin..(begin + len) {
+            // SAFETY: guaranteed by the caller
+            buffer.push(unsafe { self.take_silently(position) });
+        }
+        self.not_full.notify();
+    }
+
+    /// Number of elements which are written and not yet claimed, and whether or not the producer is completed.
+    pub fn num_available(&self) -> (usize, bool) {
+        let completed = self.completed.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        (tail.saturating_sub(head), completed)
+    }
+}
+
+impl<T> Drop for Ring<T> {
+    fn drop(&mut self) {
+        let head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        for position in head..tail {
+            let slot = &mut self.slots[position % self.slots.len()];
+            // SAFETY: elements within head..tail are written and never taken
+            unsafe { slot.value.get_mut().assume_init_drop() };
+        }
+    }
+}
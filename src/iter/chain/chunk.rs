@@ -0,0 +1,48 @@
+use crate::generic_values::Either;
+
+/// Chunk pulled from either the left or the right iterator of a chain, yielding elements
+/// tagged with the side they are pulled from.
+pub(crate) enum EitherChunk<P, Q>
+where
+    P: ExactSizeIterator,
+    Q: ExactSizeIterator,
+{
+    Left(P),
+    Right(Q),
+}
+
+impl<P, Q> Iterator for EitherChunk<P, Q>
+where
+    P: ExactSizeIterator,
+    Q: ExactSizeIterator,
+{
+    type Item = Either<P::Item, Q::Item>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Left(p) => p.next().map(Either::Left),
+            Self::Right(q) => q.next().map(Either::Right),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Left(p) => p.size_hint(),
+            Self::Right(q) => q.size_hint(),
+        }
+    }
+}
+
+impl<P, Q> ExactSizeIterator for EitherChunk<P, Q>
+where
+    P: ExactSizeIterator,
+    Q: ExactSizeIterator,
+{
+    fn len(&self) -> usize {
+        match self {
+            Self::Left(p) => p.len(),
+            Self::Right(q) => q.len(),
+        }
+    }
+}
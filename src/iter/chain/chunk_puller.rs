@@ -0,0 +1,94 @@
+use super::{chunk::EitherChunk, con_iter::ConIterChain};
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Chunk puller of a chain of two concurrent iterators which first pulls from the left
+/// iterator and then from the right iterator once the left one is consumed.
+pub(crate) struct ChainChunkPuller<'i, I, J>
+where
+    I: ConcurrentIter + 'i,
+    J: ConcurrentIter + 'i,
+{
+    chain: &'i ConIterChain<I, J>,
+    p: I::ChunkPuller<'i>,
+    q: J::ChunkPuller<'i>,
+    p_consumed: bool,
+    offset_j: Option<usize>,
+}
+
+impl<'i, I, J> ChainChunkPuller<'i, I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    pub(super) fn new(chain: &'i ConIterChain<I, J>, chunk_size: usize) -> Self {
+        let p = chain.i.chunk_puller(chunk_size);
+        let q = chain.j.chunk_puller(chunk_size);
+        Self {
+            chain,
+            p,
+            q,
+            p_consumed: false,
+            offset_j: None,
+        }
+    }
+}
+
+impl<'i, I, J> ChunkPuller for ChainChunkPuller<'i, I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    type ChunkItem = <ConIterChain<I, J> as ConcurrentIter>::Item;
+
+    type Chunk<'c>
+        = EitherChunk<
+        <I::ChunkPuller<'i> as ChunkPuller>::Chunk<'c>,
+        <J::ChunkPuller<'i> as ChunkPuller>::Chunk<'c>,
+    >
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        // p and q have the same chunk size
+        self.p.chunk_size()
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        if !self.p_consumed {
+            self.chain.begin_pull_i();
+            match self.p.pull() {
+                Some(chunk) => {
+                    self.chain.end_pull_i(chunk.len());
+                    return Some(EitherChunk::Left(chunk));
+                }
+                None => {
+                    self.chain.end_pull_i(0);
+                    self.p_consumed = true;
+                }
+            }
+        }
+
+        self.q.pull().map(EitherChunk::Right)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        if !self.p_consumed {
+            self.chain.begin_pull_i();
+            match self.p.pull_with_idx() {
+                Some((begin_idx, chunk)) => {
+                    self.chain.end_pull_i(chunk.len());
+                    return Some((begin_idx, EitherChunk::Left(chunk)));
+                }
+                None => {
+                    self.chain.end_pull_i(0);
+                    self.p_consumed = true;
+                }
+            }
+        }
+
+        let offset = *self.offset_j.get_or_insert_with(|| self.chain.offset_j());
+        self.q
+            .pull_with_idx()
+            .map(|(begin_idx, chunk)| (offset + begin_idx, EitherChunk::Right(chunk)))
+    }
+}
@@ -0,0 +1,151 @@
+use super::chunk_puller::ChainChunkPuller;
+use crate::generic_values::Either;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use orx_concurrent_iter::ConcurrentIter;
+
+/// Chain of two concurrent iterators with possibly different element types.
+///
+/// Elements of the left iterator are yielded as [`Either::Left`] and elements of the right
+/// iterator as [`Either::Right`]. The right iterator is pulled only after the left iterator
+/// is consumed, and indices of its elements follow the indices of the left elements.
+///
+/// When the length of the left iterator is not known upfront, the number of elements pulled
+/// from it is counted. Indices of the right elements are then determined only after all
+/// in-flight pulls from the left iterator are completed.
+pub(crate) struct ConIterChain<I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    pub(super) i: I,
+    pub(super) j: J,
+    len_i: Option<usize>,
+    num_pulled_i: AtomicUsize,
+    num_pulling_i: AtomicUsize,
+}
+
+impl<I, J> ConIterChain<I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    pub(crate) fn new(i: I, j: J) -> Self {
+        let len_i = i.try_get_len();
+        Self {
+            i,
+            j,
+            len_i,
+            num_pulled_i: 0.into(),
+            num_pulling_i: 0.into(),
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn begin_pull_i(&self) {
+        if self.len_i.is_none() {
+            _ = self.num_pulling_i.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn end_pull_i(&self, num_pulled: usize) {
+        if self.len_i.is_none() {
+            _ = self.num_pulled_i.fetch_add(num_pulled, Ordering::SeqCst);
+            _ = self.num_pulling_i.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Index of the first element of the right iterator; i.e., the number of elements of the left
+    /// iterator.
+    ///
+    /// Must be called only after the left iterator returned None.
+    pub(super) fn offset_j(&self) -> usize {
+        match self.len_i {
+            Some(len_i) => len_i,
+            None => {
+                while self.num_pulling_i.load(Ordering::SeqCst) > 0 {
+                    core::hint::spin_loop();
+                }
+                self.num_pulled_i.load(Ordering::SeqCst)
+            }
+        }
+    }
+}
+
+impl<I, J> ConcurrentIter for ConIterChain<I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    type Item = Either<I::Item, J::Item>;
+
+    type SequentialIter = core::iter::Chain<
+        core::iter::Map<I::SequentialIter, fn(I::Item) -> Self::Item>,
+        core::iter::Map<J::SequentialIter, fn(J::Item) -> Self::Item>,
+    >;
+
+    type ChunkPuller<'i>
+        = ChainChunkPuller<'i, I, J>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let left: fn(I::Item) -> Self::Item = Either::Left;
+        let right: fn(J::Item) -> Self::Item = Either::Right;
+        let i = self.i.into_seq_iter().map(left);
+        let j = self.j.into_seq_iter().map(right);
+        i.chain(j)
+    }
+
+    fn skip_to_end(&self) {
+        self.i.skip_to_end();
+        self.j.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.begin_pull_i();
+        match self.i.next() {
+            Some(x) => {
+                self.end_pull_i(1);
+                Some(Either::Left(x))
+            }
+            None => {
+                self.end_pull_i(0);
+                self.j.next().map(Either::Right)
+            }
+        }
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.begin_pull_i();
+        match self.i.next_with_idx() {
+            Some((idx, x)) => {
+                self.end_pull_i(1);
+                Some((idx, Either::Left(x)))
+            }
+            None => {
+                self.end_pull_i(0);
+                self.j
+                    .next_with_idx()
+                    .map(|(idx, x)| (self.offset_j() + idx, Either::Right(x)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (l1, u1) = self.i.size_hint();
+        let (l2, u2) = self.j.size_hint();
+        match (u1, u2) {
+            (Some(u1), Some(u2)) => (l1 + l2, Some(u1 + u2)),
+            _ => (l1 + l2, None),
+        }
+    }
+
+    fn is_completed_when_none_returned(&self) -> bool {
+        self.i.is_completed_when_none_returned() && self.j.is_completed_when_none_returned()
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        ChainChunkPuller::new(self, chunk_size)
+    }
+}
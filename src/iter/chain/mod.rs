@@ -0,0 +1,5 @@
+mod chunk;
+mod chunk_puller;
+mod con_iter;
+
+pub(crate) use con_iter::ConIterChain;
@@ -0,0 +1,47 @@
+use super::con_iter::ConIterReceiver;
+use alloc::vec::Drain;
+use alloc::vec::Vec;
+use orx_concurrent_iter::ChunkPuller;
+
+/// Chunk puller of a concurrent iterator receiving its elements from a channel.
+///
+/// Each pull receives up to `chunk_size` elements into the buffer of the puller.
+pub struct ReceiverChunkPuller<'i, T: Send> {
+    iter: &'i ConIterReceiver<T>,
+    chunk_size: usize,
+    buffer: Vec<T>,
+}
+
+impl<'i, T: Send> ReceiverChunkPuller<'i, T> {
+    pub(super) fn new(iter: &'i ConIterReceiver<T>, chunk_size: usize) -> Self {
+        let chunk_size = chunk_size.max(1);
+        Self {
+            iter,
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size),
+        }
+    }
+}
+
+impl<T: Send> ChunkPuller for ReceiverChunkPuller<'_, T> {
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = Drain<'c, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.pull_with_idx().map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.buffer.clear();
+        let begin_idx = self.iter.recv_into(&mut self.buffer, self.chunk_size)?;
+        Some((begin_idx, self.buffer.drain(..)))
+    }
+}
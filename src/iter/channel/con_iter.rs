@@ -0,0 +1,167 @@
+use super::chunk_puller::ReceiverChunkPuller;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+/// Interval at which the thread blocked on an empty channel checks whether the iterator is skipped to its end.
+const RECV_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+struct ReceiverState<T> {
+    /// The receiver is None while it is taken by the thread blocked on the empty channel,
+    /// or once the channel is disconnected.
+    receiver: Option<Receiver<T>>,
+    num_pulled: usize,
+}
+
+/// Concurrent iterator which yields the elements received from a channel until all of its senders are dropped.
+///
+/// Threads pull elements in batches: a thread receives the elements which are already available in the channel
+/// while holding the lock, without blocking. When the channel is empty, a single thread takes the receiver and
+/// blocks on it outside the lock, while the other threads wait to be notified once an element is received,
+/// the channel is disconnected or the iterator is skipped to its end.
+pub struct ConIterReceiver<T: Send> {
+    state: Mutex<ReceiverState<T>>,
+    receiver_returned: Condvar,
+    completed: AtomicBool,
+}
+
+impl<T: Send> ConIterReceiver<T> {
+    pub(crate) fn new(receiver: Receiver<T>) -> Self {
+        let state = ReceiverState {
+            receiver: Some(receiver),
+            num_pulled: 0,
+        };
+        Self {
+            state: Mutex::new(state),
+            receiver_returned: Condvar::new(),
+            completed: AtomicBool::new(false),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ReceiverState<T>> {
+        self.state.lock().expect("channel iterator is poisoned")
+    }
+
+    fn complete(&self) {
+        self.completed.store(true, Ordering::Relaxed);
+        // acquiring the lock makes sure that the waiting threads either observe completion or are notified
+        drop(self.state.lock());
+        self.receiver_returned.notify_all();
+    }
+
+    /// Blocks until an element is received; returns it together with the locked state so that the batch can be
+    /// completed with the elements already available in the channel. Returns None once the channel is disconnected
+    /// or the iterator is skipped to its end.
+    fn recv_first(&self) -> Option<(MutexGuard<'_, ReceiverState<T>>, T)> {
+        let mut state = self.lock();
+        loop {
+            if self.completed.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            let Some(receiver) = state.receiver.take() else {
+                state = self
+                    .receiver_returned
+                    .wait(state)
+                    .expect("channel iterator is poisoned");
+                continue;
+            };
+
+            match receiver.try_recv() {
+                Ok(x) => {
+                    state.receiver = Some(receiver);
+                    return Some((state, x));
+                }
+                Err(TryRecvError::Disconnected) => {
+                    drop(state);
+                    self.complete();
+                    return None;
+                }
+                Err(TryRecvError::Empty) => {
+                    drop(state);
+                    let received = receiver.recv_timeout(RECV_POLL_INTERVAL);
+                    state = self.lock();
+                    match received {
+                        Ok(x) => {
+                            state.receiver = Some(receiver);
+                            self.receiver_returned.notify_all();
+                            return Some((state, x));
+                        }
+                        Err(RecvTimeoutError::Timeout) => state.receiver = Some(receiver),
+                        Err(RecvTimeoutError::Disconnected) => {
+                            drop(state);
+                            self.complete();
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Receives at least one and at most `chunk_size` elements into the `buffer` and returns the position
+    /// of the first received element; returns None if no element will be received any more.
+    pub(super) fn recv_into(&self, buffer: &mut Vec<T>, chunk_size: usize) -> Option<usize> {
+        let (mut state, first) = self.recv_first()?;
+        buffer.push(first);
+        if let Some(receiver) = state.receiver.as_ref() {
+            buffer.extend(receiver.try_iter().take(chunk_size - 1));
+        }
+        let begin_idx = state.num_pulled;
+        state.num_pulled += buffer.len();
+        Some(begin_idx)
+    }
+}
+
+impl<T: Send> orx_concurrent_iter::ConcurrentIter for ConIterReceiver<T> {
+    type Item = T;
+
+    type SequentialIter = core::iter::Flatten<core::option::IntoIter<std::sync::mpsc::IntoIter<T>>>;
+
+    type ChunkPuller<'i>
+        = ReceiverChunkPuller<'i, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let completed = self.completed.load(Ordering::Relaxed);
+        let state = self
+            .state
+            .into_inner()
+            .expect("channel iterator is poisoned");
+        let receiver = state.receiver.filter(|_| !completed);
+        receiver.map(|x| x.into_iter()).into_iter().flatten()
+    }
+
+    fn skip_to_end(&self) {
+        self.complete();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.next_with_idx().map(|(_, x)| x)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        let (mut state, value) = self.recv_first()?;
+        let idx = state.num_pulled;
+        state.num_pulled += 1;
+        Some((idx, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.completed.load(Ordering::Relaxed) {
+            true => (0, Some(0)),
+            false => (0, None),
+        }
+    }
+
+    fn is_completed_when_none_returned(&self) -> bool {
+        true
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        ReceiverChunkPuller::new(self, chunk_size)
+    }
+}
@@ -0,0 +1,53 @@
+use super::con_iter::ConIterReceiver;
+use crate::{DefaultRunner, Params, computational_variants::Par};
+use std::sync::mpsc::Receiver;
+
+/// Trait to convert the receiving half of a channel into a parallel iterator.
+///
+/// Created parallel iterator yields the elements received from the channel until all senders are dropped;
+/// hence, a long-lived service consuming a queue of tasks with a pool of workers can be defined as
+/// a regular parallel computation.
+///
+/// Threads receive elements in batches of the chunk size of the parallel computation: a thread blocks until
+/// the first element of its batch is received, and then, completes the batch with the elements which are
+/// already available in the channel without blocking.
+pub trait ReceiverIntoParIter<T: Send> {
+    /// Converts the receiving half of the channel into a parallel iterator which yields the elements received
+    /// from the channel until all senders are dropped.
+    ///
+    /// Positions of the elements are assigned in the order they are received; hence, the ordered computations
+    /// such as `collect` preserve the order in which the elements are received.
+    ///
+    /// The receiver is dropped once the computation completes, including when it stops early such as due to
+    /// a `find` or `take_while` call; and hence, subsequent sends fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    /// use std::sync::mpsc::channel;
+    ///
+    /// let (tx, rx) = channel();
+    /// let producer = std::thread::spawn(move || {
+    ///     for i in 0..1000 {
+    ///         tx.send(i).unwrap();
+    ///     }
+    /// });
+    ///
+    /// let sum: usize = rx.into_par().map(|x| x * 2).sum();
+    /// assert_eq!(sum, 999 * 1000);
+    ///
+    /// producer.join().unwrap();
+    /// ```
+    fn into_par(self) -> Par<ConIterReceiver<T>, DefaultRunner>;
+}
+
+impl<T: Send> ReceiverIntoParIter<T> for Receiver<T> {
+    fn into_par(self) -> Par<ConIterReceiver<T>, DefaultRunner> {
+        Par::new(
+            Default::default(),
+            Params::default(),
+            ConIterReceiver::new(self),
+        )
+    }
+}
@@ -0,0 +1,6 @@
+mod chunk_puller;
+mod con_iter;
+mod into_par_iter;
+
+pub use con_iter::ConIterReceiver;
+pub use into_par_iter::ReceiverIntoParIter;
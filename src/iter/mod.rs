@@ -1,5 +1,24 @@
+#[cfg(feature = "std")]
+mod buffered;
+mod chain;
+#[cfg(feature = "std")]
+mod channel;
 mod recursive;
+mod skip;
 mod special_iterators;
+mod step_by;
+mod take;
+mod zip;
 
+pub(crate) use chain::ConIterChain;
+pub(crate) use skip::ConIterSkip;
+pub(crate) use step_by::ConIterStepBy;
+pub(crate) use take::ConIterTake;
+
+#[cfg(feature = "std")]
+pub use buffered::{ConIterBuffered, SeqIterBuffered};
+#[cfg(feature = "std")]
+pub use channel::{ConIterReceiver, ReceiverIntoParIter};
 pub use recursive::IntoParIterRec;
 pub use special_iterators::{ParEmpty, empty};
+pub use zip::ConIterZip;
@@ -0,0 +1,46 @@
+use orx_concurrent_iter::ChunkPuller;
+
+/// Chunk puller of a concurrent iterator skipping its first `n` elements.
+pub(crate) struct SkipChunkPuller<P>
+where
+    P: ChunkPuller,
+{
+    puller: P,
+    n: usize,
+}
+
+impl<P> SkipChunkPuller<P>
+where
+    P: ChunkPuller,
+{
+    pub(super) fn new(puller: P, n: usize) -> Self {
+        Self { puller, n }
+    }
+}
+
+impl<P> ChunkPuller for SkipChunkPuller<P>
+where
+    P: ChunkPuller,
+{
+    type ChunkItem = P::ChunkItem;
+
+    type Chunk<'c>
+        = P::Chunk<'c>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.puller.chunk_size()
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.puller.pull()
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        let n = self.n;
+        self.puller
+            .pull_with_idx()
+            .map(|(begin_idx, chunk)| (begin_idx - n, chunk))
+    }
+}
@@ -0,0 +1,97 @@
+use super::chunk_puller::SkipChunkPuller;
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter, ExactSizeConcurrentIter};
+
+/// Maximum number of elements pulled at once while skipping the first elements of the input.
+const MAX_SKIP_CHUNK_SIZE: usize = 1 << 14;
+
+/// Concurrent iterator which skips the first `n` elements of the input concurrent iterator.
+///
+/// Skipped elements are pulled and dropped once on creation; the remaining elements are
+/// yielded with their positions shifted by `n`, so that the first yielded element has index 0.
+pub(crate) struct ConIterSkip<I>
+where
+    I: ConcurrentIter,
+{
+    iter: I,
+    n: usize,
+}
+
+impl<I> ConIterSkip<I>
+where
+    I: ConcurrentIter,
+{
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self::pull_first(&iter, n);
+        Self { iter, n }
+    }
+
+    fn pull_first(iter: &I, n: usize) {
+        if n == 0 {
+            return;
+        }
+
+        let chunk_size = n.min(MAX_SKIP_CHUNK_SIZE);
+        let mut puller = iter.chunk_puller(chunk_size);
+        for _ in 0..(n / chunk_size) {
+            if puller.pull().is_none() {
+                return;
+            }
+        }
+
+        let remainder = n % chunk_size;
+        if remainder > 0 {
+            let _ = iter.chunk_puller(remainder).pull();
+        }
+    }
+}
+
+impl<I> ConcurrentIter for ConIterSkip<I>
+where
+    I: ConcurrentIter,
+{
+    type Item = I::Item;
+
+    type SequentialIter = I::SequentialIter;
+
+    type ChunkPuller<'i>
+        = SkipChunkPuller<I::ChunkPuller<'i>>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        self.iter.into_seq_iter()
+    }
+
+    fn skip_to_end(&self) {
+        self.iter.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.iter.next_with_idx().map(|(idx, x)| (idx - self.n, x))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn is_completed_when_none_returned(&self) -> bool {
+        self.iter.is_completed_when_none_returned()
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        SkipChunkPuller::new(self.iter.chunk_puller(chunk_size), self.n)
+    }
+}
+
+impl<I> ExactSizeConcurrentIter for ConIterSkip<I>
+where
+    I: ExactSizeConcurrentIter,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,54 @@
+use super::con_iter::offset_to_next_step;
+use orx_concurrent_iter::ChunkPuller;
+
+/// Chunk puller of a concurrent iterator yielding every `step`-th element.
+pub(crate) struct StepByChunkPuller<P>
+where
+    P: ChunkPuller,
+{
+    puller: P,
+    step: usize,
+    chunk_size: usize,
+}
+
+impl<P> StepByChunkPuller<P>
+where
+    P: ChunkPuller,
+{
+    pub(super) fn new(puller: P, step: usize, chunk_size: usize) -> Self {
+        Self {
+            puller,
+            step,
+            chunk_size,
+        }
+    }
+}
+
+impl<P> ChunkPuller for StepByChunkPuller<P>
+where
+    P: ChunkPuller,
+{
+    type ChunkItem = P::ChunkItem;
+
+    type Chunk<'c>
+        = core::iter::StepBy<core::iter::Skip<P::Chunk<'c>>>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.pull_with_idx().map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        let step = self.step;
+        self.puller.pull_with_idx().map(|(begin_idx, chunk)| {
+            let offset = offset_to_next_step(begin_idx, step);
+            let begin_idx = (begin_idx + offset) / step;
+            (begin_idx, chunk.skip(offset).step_by(step))
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use super::chunk_puller::StepByChunkPuller;
+use orx_concurrent_iter::{ConcurrentIter, ExactSizeConcurrentIter};
+
+/// Concurrent iterator which yields every `step`-th element of the input concurrent iterator,
+/// starting from the first one.
+///
+/// Chunk pullers pull `step` times larger chunks from the input, so that a chunk of the stepped
+/// iterator is obtained by a single pull without visiting the elements in between.
+pub(crate) struct ConIterStepBy<I>
+where
+    I: ConcurrentIter,
+{
+    iter: I,
+    step: usize,
+    initial_len: Option<usize>,
+}
+
+impl<I> ConIterStepBy<I>
+where
+    I: ConcurrentIter,
+{
+    pub(crate) fn new(iter: I, step: usize) -> Self {
+        assert!(step > 0, "step of step_by must be positive");
+        let initial_len = iter.try_get_len();
+        Self {
+            iter,
+            step,
+            initial_len,
+        }
+    }
+
+    /// Position of the next element of the input to be pulled, if known.
+    fn num_pulled(&self) -> Option<usize> {
+        match (self.initial_len, self.iter.try_get_len()) {
+            (Some(initial_len), Some(len)) => Some(initial_len - len),
+            _ => None,
+        }
+    }
+}
+
+/// Number of elements to skip from the position `idx` to reach the next multiple of `step`.
+#[inline(always)]
+pub(super) fn offset_to_next_step(idx: usize, step: usize) -> usize {
+    (step - idx % step) % step
+}
+
+impl<I> ConcurrentIter for ConIterStepBy<I>
+where
+    I: ConcurrentIter,
+{
+    type Item = I::Item;
+
+    type SequentialIter = core::iter::StepBy<core::iter::Skip<I::SequentialIter>>;
+
+    type ChunkPuller<'i>
+        = StepByChunkPuller<I::ChunkPuller<'i>>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        // none of the elements are pulled yet when the length is unknown
+        let offset = offset_to_next_step(self.num_pulled().unwrap_or(0), self.step);
+        self.iter.into_seq_iter().skip(offset).step_by(self.step)
+    }
+
+    fn skip_to_end(&self) {
+        self.iter.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.next_with_idx().map(|(_, x)| x)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        loop {
+            match self.iter.next_with_idx() {
+                Some((idx, x)) if idx.is_multiple_of(self.step) => {
+                    return Some((idx / self.step, x));
+                }
+                Some(_) => continue,
+                None => return None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let step = self.step;
+        match (self.initial_len, self.num_pulled()) {
+            (Some(initial_len), Some(num_pulled)) => {
+                let len = initial_len.div_ceil(step) - num_pulled.div_ceil(step);
+                (len, Some(len))
+            }
+            _ => {
+                let (lower, upper) = self.iter.size_hint();
+                (lower / step, upper.map(|x| x.div_ceil(step)))
+            }
+        }
+    }
+
+    fn is_completed_when_none_returned(&self) -> bool {
+        self.iter.is_completed_when_none_returned()
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        let puller = self.iter.chunk_puller(chunk_size.saturating_mul(self.step));
+        StepByChunkPuller::new(puller, self.step, chunk_size)
+    }
+}
+
+impl<I> ExactSizeConcurrentIter for ConIterStepBy<I>
+where
+    I: ExactSizeConcurrentIter,
+{
+    fn len(&self) -> usize {
+        self.size_hint().0
+    }
+}
@@ -0,0 +1,58 @@
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Chunk puller of a concurrent iterator yielding only its first `n` elements.
+pub(crate) struct TakeChunkPuller<'i, I>
+where
+    I: ConcurrentIter + 'i,
+{
+    iter: &'i I,
+    puller: I::ChunkPuller<'i>,
+    n: usize,
+}
+
+impl<'i, I> TakeChunkPuller<'i, I>
+where
+    I: ConcurrentIter,
+{
+    pub(super) fn new(iter: &'i I, n: usize, chunk_size: usize) -> Self {
+        let puller = iter.chunk_puller(chunk_size);
+        Self { iter, puller, n }
+    }
+}
+
+impl<'i, I> ChunkPuller for TakeChunkPuller<'i, I>
+where
+    I: ConcurrentIter,
+{
+    type ChunkItem = I::Item;
+
+    type Chunk<'c>
+        = core::iter::Take<<I::ChunkPuller<'i> as ChunkPuller>::Chunk<'c>>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.puller.chunk_size()
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.pull_with_idx().map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        match self.puller.pull_with_idx() {
+            Some((begin_idx, chunk)) if begin_idx < self.n => {
+                let num_to_take = self.n - begin_idx;
+                if chunk.len() >= num_to_take {
+                    self.iter.skip_to_end();
+                }
+                Some((begin_idx, chunk.take(num_to_take)))
+            }
+            Some(_) => {
+                self.iter.skip_to_end();
+                None
+            }
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::chunk_puller::TakeChunkPuller;
+use orx_concurrent_iter::{ConcurrentIter, ExactSizeConcurrentIter};
+
+/// Concurrent iterator which yields only the first `n` elements of the input concurrent iterator.
+///
+/// Pulls are cut at position `n`, after which the input iterator is skipped to its end.
+pub(crate) struct ConIterTake<I>
+where
+    I: ConcurrentIter,
+{
+    iter: I,
+    n: usize,
+    initial_len: Option<usize>,
+}
+
+impl<I> ConIterTake<I>
+where
+    I: ConcurrentIter,
+{
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        let initial_len = iter.try_get_len();
+        if n == 0 {
+            iter.skip_to_end();
+        }
+
+        Self {
+            iter,
+            n,
+            initial_len,
+        }
+    }
+
+    /// Number of elements that can still be yielded before reaching position `n`,
+    /// if the number of already pulled elements is known.
+    fn num_remaining_to_take(&self) -> Option<usize> {
+        match (self.initial_len, self.iter.try_get_len()) {
+            (Some(initial_len), Some(len)) => Some(self.n.saturating_sub(initial_len - len)),
+            _ => None,
+        }
+    }
+}
+
+impl<I> ConcurrentIter for ConIterTake<I>
+where
+    I: ConcurrentIter,
+{
+    type Item = I::Item;
+
+    type SequentialIter = core::iter::Take<I::SequentialIter>;
+
+    type ChunkPuller<'i>
+        = TakeChunkPuller<'i, I>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        // none of the elements are pulled yet when the length is unknown
+        let n = self.num_remaining_to_take().unwrap_or(self.n);
+        self.iter.into_seq_iter().take(n)
+    }
+
+    fn skip_to_end(&self) {
+        self.iter.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.next_with_idx().map(|(_, x)| x)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        match self.iter.next_with_idx() {
+            Some((idx, x)) if idx < self.n => {
+                if idx + 1 == self.n {
+                    self.iter.skip_to_end();
+                }
+                Some((idx, x))
+            }
+            Some(_) => {
+                self.iter.skip_to_end();
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.num_remaining_to_take() {
+            Some(n) => (lower.min(n), upper.map(|x| x.min(n))),
+            None => (0, Some(upper.map(|x| x.min(self.n)).unwrap_or(self.n))),
+        }
+    }
+
+    fn is_completed_when_none_returned(&self) -> bool {
+        true
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        TakeChunkPuller::new(&self.iter, self.n, chunk_size)
+    }
+}
+
+impl<I> ExactSizeConcurrentIter for ConIterTake<I>
+where
+    I: ExactSizeConcurrentIter,
+{
+    fn len(&self) -> usize {
+        self.iter
+            .len()
+            .min(self.num_remaining_to_take().unwrap_or(self.n))
+    }
+}
@@ -0,0 +1,4 @@
+mod chunk_puller;
+mod con_iter;
+
+pub(crate) use con_iter::ConIterTake;
@@ -0,0 +1,67 @@
+use super::pull_lock::PullLock;
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter};
+
+/// Chunk puller of a zip of two concurrent iterators; i.e., [`ConIterZip`].
+///
+/// [`ConIterZip`]: crate::iter::ConIterZip
+pub struct ZipChunkPuller<'i, I, J>
+where
+    I: ConcurrentIter + 'i,
+    J: ConcurrentIter + 'i,
+{
+    i: &'i I,
+    j: &'i J,
+    p: I::ChunkPuller<'i>,
+    q: J::ChunkPuller<'i>,
+    lock: &'i PullLock,
+}
+
+impl<'i, I, J> ZipChunkPuller<'i, I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    pub(super) fn new(i: &'i I, j: &'i J, lock: &'i PullLock, chunk_size: usize) -> Self {
+        let p = i.chunk_puller(chunk_size);
+        let q = j.chunk_puller(chunk_size);
+        Self { i, j, p, q, lock }
+    }
+}
+
+impl<'i, I, J> ChunkPuller for ZipChunkPuller<'i, I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    type ChunkItem = (I::Item, J::Item);
+
+    type Chunk<'c>
+        = core::iter::Zip<
+        <I::ChunkPuller<'i> as ChunkPuller>::Chunk<'c>,
+        <J::ChunkPuller<'i> as ChunkPuller>::Chunk<'c>,
+    >
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        // p and q have the same chunk size
+        self.p.chunk_size()
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.pull_with_idx().map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        let _guard = self.lock.lock();
+        match (self.p.pull_with_idx(), self.q.pull()) {
+            (Some((begin_idx, p)), Some(q)) => Some((begin_idx, p.zip(q))),
+            _ => {
+                // one of the sides is consumed, remaining elements of the other will never be zipped
+                self.i.skip_to_end();
+                self.j.skip_to_end();
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::{chunk_puller::ZipChunkPuller, pull_lock::PullLock};
+use orx_concurrent_iter::{ConcurrentIter, ExactSizeConcurrentIter};
+
+/// Zip of two concurrent iterators which yields pairs of elements at the same positions of
+/// both iterators.
+///
+/// Elements of both iterators are always pulled together so that each pulled chunk contains
+/// pairs of elements with the same indices in the sources. The zipped iterator terminates
+/// as soon as either of the iterators is consumed.
+///
+/// It can be created by calling [`zip`] on a parallel iterator.
+///
+/// [`zip`]: crate::computational_variants::Par::zip
+pub struct ConIterZip<I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    i: I,
+    j: J,
+    lock: PullLock,
+}
+
+impl<I, J> ConIterZip<I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    pub(crate) fn new(i: I, j: J) -> Self {
+        Self {
+            i,
+            j,
+            lock: PullLock::new(),
+        }
+    }
+}
+
+impl<I, J> ConcurrentIter for ConIterZip<I, J>
+where
+    I: ConcurrentIter,
+    J: ConcurrentIter,
+{
+    type Item = (I::Item, J::Item);
+
+    type SequentialIter = core::iter::Zip<I::SequentialIter, J::SequentialIter>;
+
+    type ChunkPuller<'i>
+        = ZipChunkPuller<'i, I, J>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        self.i.into_seq_iter().zip(self.j.into_seq_iter())
+    }
+
+    fn skip_to_end(&self) {
+        self.i.skip_to_end();
+        self.j.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.next_with_idx().map(|(_, x)| x)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        let _guard = self.lock.lock();
+        match (self.i.next_with_idx(), self.j.next()) {
+            (Some((idx, x)), Some(y)) => Some((idx, (x, y))),
+            _ => {
+                self.skip_to_end();
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (l1, u1) = self.i.size_hint();
+        let (l2, u2) = self.j.size_hint();
+        let upper = match (u1, u2) {
+            (Some(u1), Some(u2)) => Some(u1.min(u2)),
+            (Some(u), None) | (None, Some(u)) => Some(u),
+            (None, None) => None,
+        };
+        (l1.min(l2), upper)
+    }
+
+    fn is_completed_when_none_returned(&self) -> bool {
+        true
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        ZipChunkPuller::new(&self.i, &self.j, &self.lock, chunk_size)
+    }
+}
+
+impl<I, J> ExactSizeConcurrentIter for ConIterZip<I, J>
+where
+    I: ExactSizeConcurrentIter,
+    J: ExactSizeConcurrentIter,
+{
+    fn len(&self) -> usize {
+        self.i.len().min(self.j.len())
+    }
+}
@@ -0,0 +1,5 @@
+mod chunk_puller;
+mod con_iter;
+mod pull_lock;
+
+pub use con_iter::ConIterZip;
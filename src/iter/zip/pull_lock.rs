@@ -0,0 +1,36 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Lock guaranteeing that the two sides of a zip are pulled together, so that both pulls
+/// start at the same position of their concurrent iterators.
+pub(super) struct PullLock {
+    is_pulling: AtomicBool,
+}
+
+impl PullLock {
+    pub(super) fn new() -> Self {
+        Self {
+            is_pulling: false.into(),
+        }
+    }
+
+    pub(super) fn lock(&self) -> PullGuard<'_> {
+        while self
+            .is_pulling
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        PullGuard { lock: self }
+    }
+}
+
+pub(super) struct PullGuard<'a> {
+    lock: &'a PullLock,
+}
+
+impl Drop for PullGuard<'_> {
+    fn drop(&mut self) {
+        self.lock.is_pulling.store(false, Ordering::Release);
+    }
+}
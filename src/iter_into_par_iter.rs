@@ -1,3 +1,5 @@
+#[cfg(feature = "std")]
+use crate::iter::ConIterBuffered;
 use crate::{Params, computational_variants::Par, runner::DefaultRunner};
 use orx_concurrent_iter::{IterIntoConcurrentIter, implementations::ConIterOfIter};
 
@@ -120,6 +122,50 @@ pub trait IterIntoParIter: Iterator {
     where
         Self: Sized,
         Self::Item: Send;
+
+    /// Converts the iterator into a parallel iterator whose elements are pulled from this iterator by a
+    /// dedicated producer thread.
+    ///
+    /// The producer thread drains this iterator into a bounded lock-free ring buffer of length `buffer_len`,
+    /// pausing while the buffer is full. Threads of the parallel computation take contiguous chunks of elements
+    /// from the buffer; hence, they do not wait for each other while the next element of the iterator is being
+    /// pulled, as they do with [`iter_into_par`].
+    ///
+    /// This is most useful when pulling the elements of the iterator is itself expensive,
+    /// such as when the iterator decompresses or parses its input.
+    ///
+    /// Since the iterator is moved to the producer thread, it must be `Send` and `'static`.
+    ///
+    /// [`iter_into_par`]: crate::IterIntoParIter::iter_into_par
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer_len` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let lines: Vec<_> = (0..1000).map(|x| x.to_string()).collect();
+    ///
+    /// // parsing is performed by the producer thread
+    /// let iter = lines.into_iter().map(|x| x.parse::<usize>().unwrap());
+    ///
+    /// let sum: usize = iter
+    ///     .iter_into_par_buffered(64)
+    ///     .map(|x| x * 2)
+    ///     .sum();
+    /// assert_eq!(sum, 999 * 1000);
+    /// ```
+    #[cfg(feature = "std")]
+    fn iter_into_par_buffered(
+        self,
+        buffer_len: usize,
+    ) -> Par<ConIterBuffered<Self::Item>, DefaultRunner>
+    where
+        Self: Sized + Send + 'static,
+        Self::Item: Send + 'static;
 }
 
 impl<I> IterIntoParIter for I
@@ -134,4 +180,20 @@ where
             self.iter_into_con_iter(),
         )
     }
+
+    #[cfg(feature = "std")]
+    fn iter_into_par_buffered(
+        self,
+        buffer_len: usize,
+    ) -> Par<ConIterBuffered<Self::Item>, DefaultRunner>
+    where
+        Self: Sized + Send + 'static,
+        Self::Item: Send + 'static,
+    {
+        Par::new(
+            Default::default(),
+            Params::default(),
+            ConIterBuffered::new(self, buffer_len),
+        )
+    }
 }
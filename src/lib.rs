@@ -31,17 +31,22 @@ mod into_par_iter;
 /// Module for creating special iterators.
 pub mod iter;
 mod iter_into_par_iter;
+mod min_max;
 mod par_iter;
 mod par_iter_option;
 mod par_iter_result;
+mod par_sort;
 mod par_thread_pool;
 mod parallel_drainable;
+mod parallel_extend;
+mod parallel_extend_concurrent;
 mod parallelizable;
 mod parallelizable_collection;
 mod parallelizable_collection_mut;
 mod parameters;
 /// ParallelRunner for parallel execution and managing threads.
 pub mod runner;
+mod sink;
 mod special_type_sets;
 /// Module defining parallel iterators with mutable access to values distributed to each thread.
 pub mod using;
@@ -63,7 +68,7 @@ pub use orx_concurrent_recursive_iter::Queue;
 
 // export
 
-pub use collect_into::ParCollectInto;
+pub use collect_into::{ParCollectInto, ParCollectTarget};
 pub use enumerate::ParEnumerate;
 pub use executor::{DefaultExecutor, ParallelExecutor, ThreadExecutor};
 pub use into_par_iter::IntoParIter;
@@ -72,20 +77,30 @@ pub use iter_into_par_iter::IterIntoParIter;
 pub use par_iter::ParIter;
 pub use par_iter_option::ParIterOption;
 pub use par_iter_result::ParIterResult;
+pub use par_sort::{ParSorter, ParallelSort};
 pub use par_thread_pool::ParThreadPool;
 pub use parallel_drainable::ParallelDrainableOverSlice;
+pub use parallel_extend::ParallelExtend;
+pub use parallel_extend_concurrent::ParallelExtendConcurrent;
 pub use parallelizable::Parallelizable;
 pub use parallelizable_collection::ParallelizableCollection;
 pub use parallelizable_collection_mut::ParallelizableCollectionMut;
 pub use parameters::{ChunkSize, IterationOrder, NumThreads, Params};
 pub use runner::{DefaultPool, DefaultRunner, ParallelRunner, RunnerWithPool, SequentialPool};
-pub use special_type_sets::Sum;
+pub use sink::Sink;
+pub use special_type_sets::{Stat, Stats, Sum};
 pub use using::ParIterOptionUsing;
 pub use using::ParIterResultUsing;
 pub use using::ParIterUsing;
 
 #[cfg(feature = "std")]
 pub use executor::ParallelExecutorWithDiagnostics;
+#[cfg(feature = "std")]
+pub use executor::parallel_compute::for_each_ordered::DEFAULT_REORDER_WINDOW;
+#[cfg(feature = "std")]
+pub use iter::ReceiverIntoParIter;
+#[cfg(feature = "std")]
+pub use sink::{BatchSender, SyncBatchSender};
 
 #[cfg(feature = "pond")]
 pub use runner::PondPool;
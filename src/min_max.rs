@@ -0,0 +1,78 @@
+use core::cmp::Ordering;
+
+/// Accumulator of the minimum and maximum elements observed so far.
+///
+/// The maximum is `None` when a single element is observed, which is then both the minimum and the maximum.
+/// This allows to compute the extremes without cloning every element; the only clone is required
+/// when the iterator yields exactly one element.
+pub type MinMax<T> = (T, Option<T>);
+
+/// Result of the fallible `min_max` computations.
+pub type ResultMinMax<T, E> = Result<Option<(T, T)>, E>;
+
+pub fn min_max_unit<T>(x: T) -> MinMax<T> {
+    (x, None)
+}
+
+/// Reduces the accumulators `a` and `b`, where all elements of `a` precede those of `b`.
+///
+/// Among the equal elements, the first one is the minimum and the last one is the maximum, as in
+/// [`Iterator::min_by`] and [`Iterator::max_by`].
+pub fn min_max_reduce<T, Compare>(compare: &Compare, a: MinMax<T>, b: MinMax<T>) -> MinMax<T>
+where
+    Compare: Fn(&T, &T) -> Ordering,
+{
+    let is_less = |x: &T, y: &T| compare(x, y) == Ordering::Less;
+    match (a, b) {
+        ((a, None), (b, None)) => match is_less(&b, &a) {
+            true => (b, Some(a)),
+            false => (a, Some(b)),
+        },
+        ((a_min, Some(a_max)), (b, None)) => match (is_less(&b, &a_min), is_less(&b, &a_max)) {
+            (true, _) => (b, Some(a_max)),
+            (false, true) => (a_min, Some(a_max)),
+            (false, false) => (a_min, Some(b)),
+        },
+        ((a, None), (b_min, Some(b_max))) => match is_less(&b_min, &a) {
+            false => (a, Some(b_max)),
+            true => match is_less(&b_max, &a) {
+                true => (b_min, Some(a)),
+                false => (b_min, Some(b_max)),
+            },
+        },
+        ((a_min, Some(a_max)), (b_min, Some(b_max))) => {
+            let min = match is_less(&b_min, &a_min) {
+                true => b_min,
+                false => a_min,
+            };
+            let max = match is_less(&b_max, &a_max) {
+                true => a_max,
+                false => b_max,
+            };
+            (min, Some(max))
+        }
+    }
+}
+
+/// Compares the elements paired with their positions in the input, breaking the ties by the positions.
+///
+/// Reducing with this comparison makes the result independent of how the elements are distributed
+/// to the threads.
+pub fn compare_with_idx<T, Compare>(compare: &Compare, a: &(usize, T), b: &(usize, T)) -> Ordering
+where
+    Compare: Fn(&T, &T) -> Ordering,
+{
+    compare(&a.1, &b.1).then(a.0.cmp(&b.0))
+}
+
+pub fn min_max_into_pair<T: Clone>((min, max): MinMax<T>) -> (T, T) {
+    match max {
+        Some(max) => (min, max),
+        None => (min.clone(), min),
+    }
+}
+
+pub fn min_max_into_pair_without_idx<T: Clone>(min_max: MinMax<(usize, T)>) -> (T, T) {
+    let ((_, min), (_, max)) = min_max_into_pair(min_max);
+    (min, max)
+}
@@ -1,18 +1,37 @@
 use crate::computational_variants::fallible_option::ParOption;
+use crate::computational_variants::{ParXap, XapParts};
+use crate::executor::parallel_compute as prc;
+use crate::executor::parallel_compute::collect_slice::{InitSlice, UninitSlice, assume_init};
+use crate::generic_values::runner_results::PositionSegment;
+use crate::generic_values::{Either, TransformableValues};
+use crate::iter::ConIterChain;
+use crate::min_max::{
+    compare_with_idx, min_max_into_pair_without_idx, min_max_reduce, min_max_unit,
+};
 use crate::par_iter_option::{IntoOption, ParIterOption};
 use crate::par_iter_result::IntoResult;
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::using::{UsingClone, UsingFun};
-use crate::{ParIterResult, ParThreadPool, RunnerWithPool};
 use crate::{
-    ParIterUsing, Params,
+    ParCollectTarget, ParIterUsing, Params,
     collect_into::ParCollectInto,
     default_fns::{map_clone, map_copy, map_count, reduce_sum, reduce_unit},
     parameters::{ChunkSize, IterationOrder, NumThreads},
-    special_type_sets::Sum,
+    special_type_sets::{Stat, Stats, Sum},
 };
-use core::cmp::Ordering;
+use crate::{ParIterResult, ParThreadPool, RunnerWithPool, Sink};
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{Ordering, Reverse};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::mem::MaybeUninit;
 use orx_concurrent_iter::ConcurrentIter;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// Parallel iterator.
 pub trait ParIter<R = DefaultRunner>: Sized + Send + Sync
@@ -25,6 +44,12 @@ where
     /// Returns a reference to the input concurrent iterator.
     fn con_iter(&self) -> &impl ConcurrentIter;
 
+    /// Decomposes the parallel iterator into the components of an equivalent xap computation;
+    /// i.e., its runner, parameters, concurrent iterator and the transformation applied on its elements.
+    ///
+    /// *xap* is a generalization of one-to-one map, filter-map and flat-map operations.
+    fn into_xap(self) -> impl XapParts<R, Item = Self::Item>;
+
     /// Parameters of the parallel iterator.
     ///
     /// # Examples
@@ -1046,6 +1071,57 @@ where
         self.flat_map(map)
     }
 
+    /// Takes two parallel iterators and creates a new parallel iterator over both in sequence.
+    ///
+    /// `chain` returns a new iterator which will first iterate over values from the original iterator
+    /// and then over values from the `other` iterator.
+    ///
+    /// Both iterators may have completely different computation pipelines, as long as they yield
+    /// elements of the same type. The chained iterator uses the runner and parameters of this iterator,
+    /// while the parameters of the `other` iterator are ignored.
+    ///
+    /// When collected in order, all elements of this iterator come before the elements of the
+    /// `other` iterator.
+    ///
+    /// Note that [`Par`] has an inherent `chain` method which chains its input with another
+    /// input collection. In order to chain a transformed parallel iterator to a [`Par`], this method
+    /// can be called explicitly as `ParIter::chain(a.par(), b.par().map(f))`.
+    ///
+    /// [`Par`]: crate::computational_variants::Par
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![1, 2, 3, 4];
+    /// let b = vec!["5", "6", "x", "8"];
+    ///
+    /// let left = a.par().map(|x| x * 10);
+    /// let right = b.par().filter_map(|x| x.parse::<i32>().ok());
+    /// let chained: Vec<_> = left.chain(right).collect();
+    /// assert_eq!(chained, vec![10, 20, 30, 40, 5, 6, 8]);
+    ///
+    /// let left = a.par();
+    /// let right = b.par().flat_map(|x| x.parse::<i32>().ok()).map(|x| x * 2);
+    /// let sum = ParIter::chain(left.copied(), right).sum();
+    /// assert_eq!(sum, 10 + 38);
+    /// ```
+    fn chain<Q, C>(self, other: C) -> impl ParIter<R, Item = Self::Item>
+    where
+        Q: ParallelRunner,
+        C: ParIter<Q, Item = Self::Item>,
+    {
+        let (orchestrator, params, i, x1) = self.into_xap().into_xap_parts();
+        let (_, _, j, x2) = other.into_xap().into_xap_parts();
+        let iter = ConIterChain::new(i, j);
+        let xap1 = move |x: Either<_, _>| match x {
+            Either::Left(x) => Either::Left(x1(x)),
+            Either::Right(x) => Either::Right(x2(x)),
+        };
+        ParXap::new(orchestrator, params, iter, xap1)
+    }
+
     // collect
 
     /// Collects all the items from an iterator into a collection.
@@ -1054,9 +1130,9 @@ where
     ///
     /// The collection is passed in as owned value, and returned back with the additional elements.
     ///
-    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    /// All collections implementing [`ParCollectTarget`] can be used to collect into.
     ///
-    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// # Examples
     ///
@@ -1073,16 +1149,16 @@ where
     /// ```
     fn collect_into<C>(self, output: C) -> C
     where
-        C: ParCollectInto<Self::Item>;
+        C: ParCollectTarget<Self::Item>;
 
     /// Transforms an iterator into a collection.
     ///
     /// Similar to [`Iterator::collect`], the type annotation on the left-hand-side determines
     /// the type of the result collection; or turbofish annotation can be used.
     ///
-    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    /// All collections implementing [`ParCollectTarget`] can be used to collect into.
     ///
-    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// # Examples
     ///
@@ -1097,100 +1173,140 @@ where
     /// ```
     fn collect<C>(self) -> C
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let output = C::empty(self.con_iter().try_get_len());
         self.collect_into(output)
     }
 
-    // reduce
-
-    /// Reduces the elements to a single one, by repeatedly applying a reducing operation.
+    /// Writes the elements to the `slice`, the `i`-th element being written to the `i`-th position of the slice.
     ///
-    /// If the iterator is empty, returns `None`; otherwise, returns the result of the reduction.
+    /// The number of elements must be equal to the length of the slice. When the length of the
+    /// iterator is known, such as for a mapped slice, vector or range, the threads directly write each
+    /// element to its position in the slice without any intermediate storage. Prior values of the
+    /// slice are dropped as they are replaced.
     ///
-    /// The `reduce` function is a closure with two arguments: an ‘accumulator’, and an element.
+    /// Ordering of the elements is preserved regardless of the set iteration order.
     ///
-    /// # Example
+    /// # Panics
+    ///
+    /// Panics if the number of elements of the iterator is not equal to the length of the `slice`.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// let inputs = 1..10;
-    /// let reduced: usize = inputs.par().reduce(|acc, e| acc + e).unwrap_or(0);
-    /// assert_eq!(reduced, 45);
+    /// let a = vec![1, 2, 3, 4];
+    ///
+    /// let mut buffer = vec![0; 4];
+    /// a.par().map(|x| x * 10).collect_into_slice(&mut buffer);
+    /// assert_eq!(buffer, vec![10, 20, 30, 40]);
+    ///
+    /// let mut buffer = vec![0; 2];
+    /// a.par().copied().filter(|x| x % 2 == 0).collect_into_slice(&mut buffer);
+    /// assert_eq!(buffer, vec![2, 4]);
     /// ```
-    fn reduce<Reduce>(self, reduce: Reduce) -> Option<Self::Item>
+    fn collect_into_slice(self, slice: &mut [Self::Item])
     where
         Self::Item: Send,
-        Reduce: Fn(Self::Item, Self::Item) -> Self::Item + Sync;
+    {
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::collect_slice::x(orchestrator, params, iter, x1, InitSlice::new(slice));
+    }
 
-    /// Tests if every element of the iterator matches a predicate.
+    /// Writes the elements to the uninitialized `slice`, the `i`-th element being written to the `i`-th
+    /// position of the slice; and returns the slice as initialized.
     ///
-    /// `all` takes a `predicate` that returns true or false.
-    /// It applies this closure to each element of the iterator,
-    /// and if they all return true, then so does `all`.
-    /// If any of them returns false, it returns false.
+    /// See [`collect_into_slice`] for details.
     ///
-    /// `all` is short-circuiting; in other words, it will stop processing as soon as it finds a false,
-    /// given that no matter what else happens, the result will also be false.
+    /// [`collect_into_slice`]: crate::ParIter::collect_into_slice
     ///
-    /// An empty iterator returns true.
+    /// # Panics
+    ///
+    /// Panics if the number of elements of the iterator is not equal to the length of the `slice`.
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
+    /// use std::mem::MaybeUninit;
     ///
-    /// let mut a = vec![1, 2, 3];
-    /// assert!(a.par().all(|x| **x > 0));
-    /// assert!(!a.par().all(|x| **x > 2));
+    /// let a = vec![1, 2, 3, 4];
     ///
-    /// a.clear();
-    /// assert!(a.par().all(|x| **x > 2)); // empty iterator
+    /// let mut buffer = [const { MaybeUninit::<String>::uninit() }; 4];
+    /// let values = a.par().map(|x| x.to_string()).collect_into_uninit_slice(&mut buffer);
+    /// assert_eq!(values, ["1", "2", "3", "4"]);
     /// ```
-    fn all<Predicate>(self, predicate: Predicate) -> bool
+    fn collect_into_uninit_slice(self, slice: &mut [MaybeUninit<Self::Item>]) -> &mut [Self::Item]
     where
         Self::Item: Send,
-        Predicate: Fn(&Self::Item) -> bool + Sync,
     {
-        let violates = |x: &Self::Item| !predicate(x);
-        self.find(violates).is_none()
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::collect_slice::x(orchestrator, params, iter, x1, UninitSlice::new(slice));
+        // SAFETY: the computation panics unless each position of the slice is written exactly once
+        unsafe { assume_init(slice) }
     }
 
-    /// Tests if any element of the iterator matches a predicate.
+    /// Consumes the iterator and splits its elements into two collections: the first one contains
+    /// the elements for which the `predicate` returns true, and the second one contains the others.
     ///
-    /// `any` takes a `predicate` that returns true or false.
-    /// It applies this closure to each element of the iterator,
-    /// and if any of the elements returns true, then so does `any`.
-    /// If all of them return false, it returns false.
+    /// Both collections are filled by a single parallel computation; and any two collections
+    /// implementing [`ParCollectTarget`] can be used as the outputs.
     ///
-    /// `any` is short-circuiting; in other words, it will stop processing as soon as it finds a true,
-    /// given that no matter what else happens, the result will also be true.
+    /// Depending on the set iteration order of the parallel iterator, elements of each collection are
     ///
-    /// An empty iterator returns false.
+    /// * in the order of the input if default iteration order `IterationOrder::Ordered` is used,
+    /// * in an arbitrary order if `IterationOrder::Arbitrary` is set.
+    ///
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
+    /// use orx_split_vec::SplitVec;
     ///
-    /// let mut a = vec![1, 2, 3];
-    /// assert!(a.par().any(|x| **x > 0));
-    /// assert!(!a.par().any(|x| **x > 5));
+    /// let a = 0..10;
     ///
-    /// a.clear();
-    /// assert!(!a.par().any(|x| **x > 0)); // empty iterator
+    /// let (even, odd): (Vec<_>, SplitVec<_>) = a.par().map(|x| x * 10).partition(|x| x % 20 == 0);
+    ///
+    /// assert_eq!(even, vec![0, 20, 40, 60, 80]);
+    /// assert_eq!(odd.to_vec(), vec![10, 30, 50, 70, 90]);
     /// ```
-    fn any<Predicate>(self, predicate: Predicate) -> bool
+    fn partition<C1, C2, Predicate>(self, predicate: Predicate) -> (C1, C2)
     where
         Self::Item: Send,
+        C1: ParCollectTarget<Self::Item>,
+        C2: ParCollectTarget<Self::Item>,
         Predicate: Fn(&Self::Item) -> bool + Sync,
     {
-        self.find(predicate).is_some()
+        let split = |vec: Vec<(usize, Self::Item)>| {
+            let (mut left, mut right) = (Vec::new(), Vec::new());
+            for (idx, x) in vec {
+                match predicate(&x) {
+                    true => left.push((idx, x)),
+                    false => right.push((idx, x)),
+                }
+            }
+            (left, right)
+        };
+        collect_split(self, split)
     }
 
-    /// Consumes the iterator, counting the number of iterations and returning it.
+    /// Consumes an iterator of pairs and collects the first and second elements of the pairs
+    /// into two separate collections.
+    ///
+    /// Both collections are filled by a single parallel computation; and any two collections
+    /// implementing [`ParCollectTarget`] can be used as the outputs.
+    ///
+    /// Depending on the set iteration order of the parallel iterator, elements of each collection are
+    ///
+    /// * in the order of the input if default iteration order `IterationOrder::Ordered` is used,
+    /// * in an arbitrary order if `IterationOrder::Arbitrary` is set; however, the i-th elements of
+    ///   both collections are not guaranteed to belong to the same pair in this case.
+    ///
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// # Examples
     ///
@@ -1198,226 +1314,1588 @@ where
     /// use orx_parallel::*;
     ///
     /// let a = vec![1, 2, 3];
-    /// assert_eq!(a.par().filter(|x| **x >= 2).count(), 2);
+    ///
+    /// let (numbers, texts): (Vec<_>, Vec<_>) = a.par().map(|x| (*x, x.to_string())).unzip();
+    ///
+    /// assert_eq!(numbers, vec![1, 2, 3]);
+    /// assert_eq!(texts, vec!["1", "2", "3"]);
     /// ```
-    fn count(self) -> usize {
-        self.map(map_count).reduce(reduce_sum).unwrap_or(0)
+    fn unzip<A, B, C1, C2>(self) -> (C1, C2)
+    where
+        Self: ParIter<R, Item = (A, B)>,
+        A: Send,
+        B: Send,
+        C1: ParCollectTarget<A>,
+        C2: ParCollectTarget<B>,
+    {
+        let split = |vec: Vec<(usize, (A, B))>| {
+            let (mut left, mut right) =
+                (Vec::with_capacity(vec.len()), Vec::with_capacity(vec.len()));
+            for (idx, (a, b)) in vec {
+                left.push((idx, a));
+                right.push((idx, b));
+            }
+            (left, right)
+        };
+        collect_split(self, split)
     }
 
-    /// Calls a closure on each element of an iterator.
+    /// Computes the inclusive prefix scan of the elements with the associative operation `op`,
+    /// and collects the results into a collection.
     ///
-    /// # Examples
+    /// The i-th element of the output is `op(...op(op(x0, x1), x2)..., xi)`.
     ///
-    /// Basic usage:
+    /// The scan is computed in two passes. First, threads compute the scans of the chunks they pull
+    /// independently. Then, after the carries of the chunks are computed sequentially, the chunks
+    /// are fixed up by their carries in parallel. Since elements are combined in a different grouping
+    /// than the sequential scan, `op` is required to be associative.
+    ///
+    /// Iteration order of the computation is ignored; the scan is always computed in the order of the elements.
+    ///
+    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    ///
+    /// [`ParCollectInto`]: crate::ParCollectInto
+    ///
+    /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
-    /// use std::sync::mpsc::channel;
+    /// use orx_split_vec::SplitVec;
     ///
-    /// let (tx, rx) = channel();
-    /// (0..5)
-    ///     .par()
-    ///     .map(|x| x * 2 + 1)
-    ///     .for_each(move |x| tx.send(x).unwrap());
+    /// let vec = vec![3, 1, 4, 1, 5];
     ///
-    /// let mut v: Vec<_> = rx.iter().collect();
-    /// v.sort(); // order can be mixed, since messages will be sent in parallel
-    /// assert_eq!(v, vec![1, 3, 5, 7, 9]);
+    /// let cumulative: Vec<_> = vec.par().copied().scan_inclusive(|a, b| a + b);
+    /// assert_eq!(cumulative, vec![3, 4, 8, 9, 14]);
+    ///
+    /// let running_max: SplitVec<_> = vec.into_par().scan_inclusive(|a, b| *a.max(b));
+    /// assert_eq!(running_max, [3, 3, 4, 4, 5]);
     /// ```
+    fn scan_inclusive<C, Op>(self, op: Op) -> C
+    where
+        Self::Item: Clone + Send + Sync,
+        C: ParCollectInto<Self::Item>,
+        Op: Fn(&Self::Item, &Self::Item) -> Self::Item + Sync,
+    {
+        let len = self.con_iter().try_get_len();
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        let (_, vectors) = prc::scan::inclusive(orchestrator, params, iter, x1, op);
+        C::empty(len).extend_from_vecs(vectors)
+    }
+
+    /// Computes the exclusive prefix scan of the elements starting at `init` with the associative
+    /// operation `op`, and collects the results into a collection.
     ///
-    /// Note that since parallel iterators cannot be used within the `for` loop as regular iterators,
-    /// `for_each` provides a way to perform arbitrary for loops on parallel iterators.
-    /// In the following example, we log every element that satisfies a predicate in parallel.
+    /// The first element of the output is `init`, and the i-th element is the result of applying `op`
+    /// on `init` and all the elements before position i. For instance, the exclusive scan with addition
+    /// computes the offsets of the elements. Length of the output is equal to the number of elements.
+    ///
+    /// The scan is computed in two passes in the same way as [`scan_inclusive`]; therefore, `op`
+    /// is required to be associative.
+    ///
+    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    ///
+    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`scan_inclusive`]: crate::ParIter::scan_inclusive
+    ///
+    /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// (0..5)
-    ///     .par()
-    ///     .flat_map(|x| x * 100..x * 110)
-    ///     .filter(|&x| x % 3 == 0)
-    ///     .for_each(|x| println!("{x}"));
+    /// let lengths = vec![3, 1, 4, 1, 5];
+    ///
+    /// let offsets: Vec<_> = lengths.par().copied().scan_exclusive(0, |a, b| a + b);
+    /// assert_eq!(offsets, vec![0, 3, 4, 8, 9]);
+    ///
+    /// let empty: Vec<usize> = vec![];
+    /// let offsets: Vec<_> = empty.into_par().scan_exclusive(0, |a, b| a + b);
+    /// assert!(offsets.is_empty());
     /// ```
-    fn for_each<Operation>(self, operation: Operation)
+    fn scan_exclusive<C, Op>(self, init: Self::Item, op: Op) -> C
     where
-        Operation: Fn(Self::Item) + Sync,
+        Self::Item: Clone + Send + Sync,
+        C: ParCollectInto<Self::Item>,
+        Op: Fn(&Self::Item, &Self::Item) -> Self::Item + Sync,
     {
-        let map = |x| operation(x);
-        let _ = self.map(map).reduce(reduce_unit);
+        let len = self.con_iter().try_get_len();
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        let (_, vectors) = prc::scan::exclusive(orchestrator, params, iter, x1, init, op);
+        C::empty(len).extend_from_vecs(vectors)
     }
 
-    /// Returns the maximum element of an iterator.
+    /// Collects the elements into a collection sorted in ascending order.
     ///
-    /// If the iterator is empty, None is returned.
+    /// The sort is stable; i.e., the order of equal elements in the iterator is preserved.
+    ///
+    /// Each thread sorts the values it collects; then, the sorted vectors of the threads are merged
+    /// in parallel. Therefore, the elements are not required to be collected before the sort.
+    ///
+    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    ///
+    /// [`ParCollectInto`]: crate::ParCollectInto
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// let a = vec![1, 2, 3];
-    /// let b: Vec<u32> = Vec::new();
+    /// let a = vec![5, 3, 8, 1, 9, 2];
     ///
-    /// assert_eq!(a.par().max(), Some(&3));
-    /// assert_eq!(b.par().max(), None);
+    /// let sorted: Vec<_> = a.par().filter(|x| **x > 2).map(|x| x * 10).sorted();
+    /// assert_eq!(sorted, vec![30, 50, 80, 90]);
     /// ```
-    fn max(self) -> Option<Self::Item>
+    fn sorted<C>(self) -> C
     where
         Self::Item: Ord + Send,
+        C: ParCollectInto<Self::Item>,
     {
-        self.reduce(Ord::max)
+        self.sorted_by(Self::Item::cmp)
     }
 
-    /// Returns the element that gives the maximum value with respect to the specified `compare` function.
+    /// Collects the elements into a collection sorted with the `compare` function.
     ///
-    /// If the iterator is empty, None is returned.
+    /// The sort is stable; i.e., the order of equal elements in the iterator is preserved.
+    ///
+    /// See [`sorted`] for details.
+    ///
+    /// [`sorted`]: crate::ParIter::sorted
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// let a = vec![-3_i32, 0, 1, 5, -10];
-    /// assert_eq!(*a.par().max_by(|x, y| x.cmp(y)).unwrap(), 5);
+    /// let a = vec![5, 3, 8, 1, 9, 2];
+    ///
+    /// let sorted: Vec<_> = a.par().copied().sorted_by(|a, b| b.cmp(a));
+    /// assert_eq!(sorted, vec![9, 8, 5, 3, 2, 1]);
     /// ```
-    fn max_by<Compare>(self, compare: Compare) -> Option<Self::Item>
+    fn sorted_by<C, Compare>(self, compare: Compare) -> C
     where
         Self::Item: Send,
+        C: ParCollectInto<Self::Item>,
         Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
     {
-        let reduce = |x, y| match compare(&x, &y) {
-            Ordering::Greater | Ordering::Equal => x,
-            Ordering::Less => y,
-        };
-        self.reduce(reduce)
+        let output = C::empty(self.con_iter().try_get_len());
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::sort::x(orchestrator, params, iter, x1, compare, output).1
     }
 
-    /// Returns the element that gives the maximum value from the specified function.
+    /// Collects the elements into a collection sorted by the keys extracted by the `key` function.
     ///
-    /// If the iterator is empty, None is returned.
+    /// The sort is stable; i.e., the order of elements with equal keys in the iterator is preserved.
+    ///
+    /// See [`sorted`] for details.
+    ///
+    /// [`sorted`]: crate::ParIter::sorted
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// let a = vec![-3_i32, 0, 1, 5, -10];
-    /// assert_eq!(*a.par().max_by_key(|x| x.abs()).unwrap(), -10);
+    /// let a = vec!["xyz", "a", "bc", "d", "ef"];
+    ///
+    /// let sorted: Vec<_> = a.par().copied().sorted_by_key(|x| x.len());
+    /// assert_eq!(sorted, vec!["a", "d", "bc", "ef", "xyz"]);
     /// ```
-    fn max_by_key<Key, GetKey>(self, key: GetKey) -> Option<Self::Item>
+    fn sorted_by_key<C, Key, GetKey>(self, key: GetKey) -> C
     where
         Self::Item: Send,
+        C: ParCollectInto<Self::Item>,
         Key: Ord,
         GetKey: Fn(&Self::Item) -> Key + Sync,
     {
-        let reduce = |x, y| match key(&x).cmp(&key(&y)) {
-            Ordering::Greater | Ordering::Equal => x,
-            Ordering::Less => y,
-        };
-        self.reduce(reduce)
+        self.sorted_by(|a, b| key(a).cmp(&key(b)))
     }
 
-    /// Returns the minimum element of an iterator.
+    /// Concatenates the string elements into a single string, placing the `separator` between each pair of elements.
     ///
-    /// If the iterator is empty, None is returned.
+    /// Each thread appends the elements it pulls to its own buffers, which are concatenated at the end;
+    /// hence, the elements are not required to be collected before being joined.
+    ///
+    /// Depending on the set iteration order of the parallel iterator, the elements are joined
+    ///
+    /// * in the order of the input if default iteration order `IterationOrder::Ordered` is used,
+    /// * in an arbitrary order if `IterationOrder::Arbitrary` is set.
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// let a = vec![1, 2, 3];
-    /// let b: Vec<u32> = Vec::new();
+    /// let a = vec![1, 2, 3, 4];
     ///
-    /// assert_eq!(a.par().min(), Some(&1));
-    /// assert_eq!(b.par().min(), None);
+    /// let joined = a.par().map(|x| x.to_string()).join(", ");
+    /// assert_eq!(joined, "1, 2, 3, 4");
+    ///
+    /// let joined = a.par().filter(|x| **x > 4).map(|x| x.to_string()).join(", ");
+    /// assert_eq!(joined, "");
     /// ```
-    fn min(self) -> Option<Self::Item>
+    fn join(self, separator: &str) -> String
     where
-        Self::Item: Ord + Send,
+        Self::Item: AsRef<str>,
     {
-        self.reduce(Ord::min)
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::join::x(orchestrator, params, iter, x1, separator).1
     }
 
-    /// Returns the element that gives the minimum value with respect to the specified `compare` function.
+    // reduce
+
+    /// Reduces the elements to a single one, by repeatedly applying a reducing operation.
     ///
-    /// If the iterator is empty, None is returned.
+    /// If the iterator is empty, returns `None`; otherwise, returns the result of the reduction.
     ///
-    /// # Examples
+    /// The `reduce` function is a closure with two arguments: an ‘accumulator’, and an element.
+    ///
+    /// # Example
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// let a = vec![-3_i32, 0, 1, 5, -10];
-    /// assert_eq!(*a.par().min_by(|x, y| x.cmp(y)).unwrap(), -10);
+    /// let inputs = 1..10;
+    /// let reduced: usize = inputs.par().reduce(|acc, e| acc + e).unwrap_or(0);
+    /// assert_eq!(reduced, 45);
     /// ```
-    fn min_by<Compare>(self, compare: Compare) -> Option<Self::Item>
+    fn reduce<Reduce>(self, reduce: Reduce) -> Option<Self::Item>
     where
         Self::Item: Send,
-        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
-    {
-        let reduce = |x, y| match compare(&x, &y) {
-            Ordering::Less | Ordering::Equal => x,
-            Ordering::Greater => y,
-        };
-        self.reduce(reduce)
-    }
+        Reduce: Fn(Self::Item, Self::Item) -> Self::Item + Sync;
 
-    /// Returns the element that gives the minimum value from the specified function.
+    /// Folds the elements into an accumulator of a possibly different type, and combines the
+    /// accumulators into the result.
     ///
-    /// If the iterator is empty, None is returned.
+    /// Each thread creates its own accumulator by calling `identity` once, and folds all the elements
+    /// it pulls into it by repeatedly applying the `fold` operation. Then, accumulators of the threads
+    /// are combined into one by the `combine` operation.
+    ///
+    /// Elements are folded and accumulators are combined in an arbitrary order;
+    /// therefore, the result is deterministic only when the operations are associative and commutative.
+    ///
+    /// Returns `identity()` if the iterator is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// let a = vec![-3_i32, 0, 1, 5, -10];
-    /// assert_eq!(*a.par().min_by_key(|x| x.abs()).unwrap(), 0);
+    /// let words = vec!["a", "bb", "cc", "ddd", "e", "ff"];
+    ///
+    /// let histogram = words.par().fold(
+    ///     || vec![0; 4],
+    ///     |mut hist, x| {
+    ///         hist[x.len()] += 1;
+    ///         hist
+    ///     },
+    ///     |mut a, b| {
+    ///         a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+    ///         a
+    ///     },
+    /// );
+    /// assert_eq!(histogram, vec![0, 2, 3, 1]);
+    ///
+    /// let total_len = (0..1000).par().map(|x| x.to_string()).fold(|| 0, |a, x| a + x.len(), |a, b| a + b);
+    /// assert_eq!(total_len, 10 + 2 * 90 + 3 * 900);
     /// ```
-    fn min_by_key<Key, GetKey>(self, get_key: GetKey) -> Option<Self::Item>
+    fn fold<Acc, Identity, Fold, Combine>(
+        self,
+        identity: Identity,
+        fold: Fold,
+        combine: Combine,
+    ) -> Acc
     where
-        Self::Item: Send,
-        Key: Ord,
-        GetKey: Fn(&Self::Item) -> Key + Sync,
+        Acc: Send,
+        Identity: Fn() -> Acc + Sync,
+        Fold: Fn(Acc, Self::Item) -> Acc + Sync,
+        Combine: Fn(Acc, Acc) -> Acc,
     {
-        let reduce = |x, y| match get_key(&x).cmp(&get_key(&y)) {
-            Ordering::Less | Ordering::Equal => x,
-            Ordering::Greater => y,
-        };
-        self.reduce(reduce)
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::fold::x(orchestrator, params, iter, x1, identity, fold, combine).1
     }
 
-    /// Sums the elements of an iterator.
-    ///
-    /// Takes each element, adds them together, and returns the result.
-    ///
-    /// An empty iterator returns the additive identity (“zero”) of the type, which is 0 for integers and -0.0 for floats.
+    /// Counts the elements of the iterator per key computed by the `key` function.
     ///
-    /// `sum` can be used to sum any type implementing [`Sum<Out>`].
+    /// Each thread counts the elements it processes in its own map; the maps of the threads are then merged.
     ///
-    /// [`Sum<Out>`]: crate::Sum
+    /// Returns a `HashMap` when the "std" feature is enabled; and a `BTreeMap` otherwise.
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_parallel::*;
     ///
-    /// let a = vec![1, 2, 3];
-    /// let sum: i32 = a.par().sum();
+    /// let words = vec!["apple", "bob", "cat", "dog", "emu", "fox", "giraffe"];
     ///
-    /// assert_eq!(sum, 6);
+    /// let counts = words.par().count_by(|x| x.len());
+    ///
+    /// assert_eq!(counts.len(), 3);
+    /// assert_eq!(counts[&3], 5);
+    /// assert_eq!(counts[&5], 1);
+    /// assert_eq!(counts.get(&7), Some(&1));
     /// ```
-    fn sum<Out>(self) -> Out
+    #[cfg(feature = "std")]
+    fn count_by<Key, GetKey>(self, key: GetKey) -> HashMap<Key, usize>
     where
-        Self::Item: Sum<Out>,
-        Out: Send,
+        Key: Hash + Eq + Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
     {
-        self.map(Self::Item::map)
+        let count = |mut counts: HashMap<Key, usize>, x: Self::Item| {
+            *counts.entry(key(&x)).or_insert(0) += 1;
+            counts
+        };
+        let merge = |mut a: HashMap<Key, usize>, mut b: HashMap<Key, usize>| {
+            if a.len() < b.len() {
+                core::mem::swap(&mut a, &mut b);
+            }
+            for (k, count) in b {
+                *a.entry(k).or_insert(0) += count;
+            }
+            a
+        };
+        self.fold(HashMap::new, count, merge)
+    }
+
+    /// Counts the elements of the iterator per key computed by the `key` function.
+    ///
+    /// Each thread counts the elements it processes in its own map; the maps of the threads are then merged.
+    ///
+    /// Returns a `HashMap` when the "std" feature is enabled; and a `BTreeMap` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let words = vec!["apple", "bob", "cat", "dog", "emu", "fox", "giraffe"];
+    ///
+    /// let counts = words.par().count_by(|x| x.len());
+    ///
+    /// assert_eq!(counts.len(), 3);
+    /// assert_eq!(counts[&3], 5);
+    /// assert_eq!(counts[&5], 1);
+    /// assert_eq!(counts.get(&7), Some(&1));
+    /// ```
+    #[cfg(not(feature = "std"))]
+    fn count_by<Key, GetKey>(self, key: GetKey) -> BTreeMap<Key, usize>
+    where
+        Key: Ord + Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let count = |mut counts: BTreeMap<Key, usize>, x: Self::Item| {
+            *counts.entry(key(&x)).or_insert(0) += 1;
+            counts
+        };
+        let merge = |mut a: BTreeMap<Key, usize>, mut b: BTreeMap<Key, usize>| {
+            if a.len() < b.len() {
+                core::mem::swap(&mut a, &mut b);
+            }
+            for (k, count) in b {
+                *a.entry(k).or_insert(0) += count;
+            }
+            a
+        };
+        self.fold(BTreeMap::new, count, merge)
+    }
+
+    /// Computes the histogram of the elements of the iterator with `num_bins` bins,
+    /// where the bin of each element is computed by the `bin` function.
+    ///
+    /// Returns a vector of length `num_bins` where the i-th element is the number of elements in the i-th bin.
+    /// Elements whose bins are not less than `num_bins` are not counted.
+    ///
+    /// Each thread computes the histogram of the elements it processes; the histograms of the threads are then merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let scores = vec![12, 95, 47, 33, 58, 71, 5, 100];
+    ///
+    /// let histogram = scores.par().histogram(4, |x| (**x as usize) / 25);
+    /// assert_eq!(histogram, vec![2, 2, 2, 1]); // 100 falls into bin 4 which does not exist
+    /// ```
+    fn histogram<GetBin>(self, num_bins: usize, bin: GetBin) -> Vec<usize>
+    where
+        GetBin: Fn(&Self::Item) -> usize + Sync,
+    {
+        let count = |mut histogram: Vec<usize>, x: Self::Item| {
+            if let Some(count) = histogram.get_mut(bin(&x)) {
+                *count += 1;
+            }
+            histogram
+        };
+        let merge = |mut a: Vec<usize>, b: Vec<usize>| {
+            a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+            a
+        };
+        self.fold(|| vec![0; num_bins], count, merge)
+    }
+
+    /// Groups the elements of the iterator by the keys computed by the `key` function.
+    ///
+    /// The order of the elements within each group is arbitrary.
+    ///
+    /// Returns a `HashMap` when the "std" feature is enabled; and a `BTreeMap` otherwise.
+    ///
+    /// Each thread groups the elements it processes in its own map, and the maps of the threads are merged
+    /// at the end. See [`group_by_key_sharded`] to merge the maps in parallel as well.
+    ///
+    /// [`group_by_key_sharded`]: crate::ParIter::group_by_key_sharded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let words = vec!["apple", "bob", "cat", "avocado", "banana"];
+    ///
+    /// let mut groups = words.par().copied().group_by_key(|x| x.chars().next());
+    /// groups.values_mut().for_each(|x| x.sort());
+    ///
+    /// assert_eq!(groups.len(), 3);
+    /// assert_eq!(groups[&Some('a')], vec!["apple", "avocado"]);
+    /// assert_eq!(groups[&Some('b')], vec!["banana", "bob"]);
+    /// assert_eq!(groups[&Some('c')], vec!["cat"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn group_by_key<Key, GetKey>(self, key: GetKey) -> HashMap<Key, Vec<Self::Item>>
+    where
+        Self::Item: Send,
+        Key: Hash + Eq + Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let insert = |mut map: HashMap<Key, Vec<Self::Item>>, x: Self::Item| {
+            map.entry(key(&x)).or_default().push(x);
+            map
+        };
+        let merge = |mut a: HashMap<Key, Vec<Self::Item>>, mut b: HashMap<Key, Vec<Self::Item>>| {
+            if a.len() < b.len() {
+                core::mem::swap(&mut a, &mut b);
+            }
+            for (k, mut x) in b {
+                let group = a.entry(k).or_default();
+                if group.len() < x.len() {
+                    core::mem::swap(group, &mut x);
+                }
+                group.append(&mut x);
+            }
+            a
+        };
+        self.fold(HashMap::new, insert, merge)
+    }
+
+    /// Groups the elements of the iterator by the keys computed by the `key` function into shards,
+    /// which are maps with disjoint keys.
+    ///
+    /// The order of the elements within each group is arbitrary.
+    ///
+    /// This is the sharded counterpart of [`group_by_key`]: each thread groups the elements it processes in
+    /// its own maps, one for each shard, where the keys are assigned to shards by their hashes. Then, the maps
+    /// of the threads are merged in parallel, shard by shard; hence, the final combine step is parallel as well.
+    /// The shards are returned without being combined into a single map, which would require rehashing all keys.
+    ///
+    /// The number of shards is equal to the number of threads of the computation.
+    ///
+    /// [`group_by_key`]: crate::ParIter::group_by_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let words = vec!["apple", "bob", "cat", "avocado", "banana"];
+    ///
+    /// let shards = words.par().copied().group_by_key_sharded(|x| x.chars().next());
+    ///
+    /// let num_groups: usize = shards.iter().map(|x| x.len()).sum();
+    /// assert_eq!(num_groups, 3);
+    ///
+    /// let a_shard = shards.iter().find(|x| x.contains_key(&Some('a'))).unwrap();
+    /// let mut a_group = a_shard[&Some('a')].clone();
+    /// a_group.sort();
+    /// assert_eq!(a_group, vec!["apple", "avocado"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn group_by_key_sharded<Key, GetKey>(self, key: GetKey) -> Vec<HashMap<Key, Vec<Self::Item>>>
+    where
+        Self::Item: Send,
+        Key: Hash + Eq + Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let insert = |map: &mut HashMap<Key, Vec<Self::Item>>, k: Key, x: Self::Item| {
+            map.entry(k).or_default().push(x);
+        };
+        let merge = |map: &mut HashMap<Key, Vec<Self::Item>>, k: Key, mut x: Vec<Self::Item>| {
+            let group = map.entry(k).or_default();
+            if group.len() < x.len() {
+                core::mem::swap(group, &mut x);
+            }
+            group.append(&mut x);
+        };
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::by_key::x(orchestrator, params, iter, x1, key, insert, merge).1
+    }
+
+    /// Groups the elements of the iterator by the keys computed by the `key` function.
+    ///
+    /// The order of the elements within each group is arbitrary.
+    ///
+    /// Returns a `HashMap` when the "std" feature is enabled; and a `BTreeMap` otherwise.
+    ///
+    /// Each thread groups the elements it processes in its own map, and the maps of the threads are merged
+    /// at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let words = vec!["apple", "bob", "cat", "avocado", "banana"];
+    ///
+    /// let mut groups = words.par().copied().group_by_key(|x| x.chars().next());
+    /// groups.values_mut().for_each(|x| x.sort());
+    ///
+    /// assert_eq!(groups.len(), 3);
+    /// assert_eq!(groups[&Some('a')], vec!["apple", "avocado"]);
+    /// assert_eq!(groups[&Some('b')], vec!["banana", "bob"]);
+    /// assert_eq!(groups[&Some('c')], vec!["cat"]);
+    /// ```
+    #[cfg(not(feature = "std"))]
+    fn group_by_key<Key, GetKey>(self, key: GetKey) -> BTreeMap<Key, Vec<Self::Item>>
+    where
+        Self::Item: Send,
+        Key: Ord + Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let insert = |mut map: BTreeMap<Key, Vec<Self::Item>>, x: Self::Item| {
+            map.entry(key(&x)).or_default().push(x);
+            map
+        };
+        let merge = |mut a: BTreeMap<Key, Vec<Self::Item>>,
+                     mut b: BTreeMap<Key, Vec<Self::Item>>| {
+            if a.len() < b.len() {
+                core::mem::swap(&mut a, &mut b);
+            }
+            for (k, mut x) in b {
+                let group = a.entry(k).or_default();
+                if group.len() < x.len() {
+                    core::mem::swap(group, &mut x);
+                }
+                group.append(&mut x);
+            }
+            a
+        };
+        self.fold(BTreeMap::new, insert, merge)
+    }
+
+    /// Reduces the values of the elements sharing the same key, where keys and values of the elements
+    /// are computed by the `key` and `value` functions, respectively.
+    ///
+    /// Values are reduced in an arbitrary order; hence, `reduce` is expected to be associative and commutative.
+    ///
+    /// Returns a `HashMap` when the "std" feature is enabled; and a `BTreeMap` otherwise.
+    ///
+    /// See [`group_by_key`] for details of the parallel aggregation.
+    ///
+    /// [`group_by_key`]: crate::ParIter::group_by_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let sales = vec![("apple", 3), ("bob", 1), ("apple", 4), ("cat", 2), ("bob", 5)];
+    ///
+    /// let totals = sales.par().reduce_by_key(|x| x.0, |x| x.1, |a, b| a + b);
+    ///
+    /// assert_eq!(totals.len(), 3);
+    /// assert_eq!(totals[&"apple"], 7);
+    /// assert_eq!(totals[&"bob"], 6);
+    /// assert_eq!(totals[&"cat"], 2);
+    /// ```
+    #[cfg(feature = "std")]
+    fn reduce_by_key<Key, Value, GetKey, GetValue, Reduce>(
+        self,
+        key: GetKey,
+        value: GetValue,
+        reduce: Reduce,
+    ) -> HashMap<Key, Value>
+    where
+        Key: Hash + Eq + Send,
+        Value: Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+        GetValue: Fn(Self::Item) -> Value + Sync,
+        Reduce: Fn(Value, Value) -> Value + Sync,
+    {
+        let insert = |mut map: HashMap<Key, Value>, x: Self::Item| {
+            prc::by_key::reduce_into_hash_map(&mut map, key(&x), value(x), &reduce);
+            map
+        };
+        let combine = |mut a: HashMap<Key, Value>, mut b: HashMap<Key, Value>| {
+            if a.len() < b.len() {
+                core::mem::swap(&mut a, &mut b);
+            }
+            for (k, v) in b {
+                prc::by_key::reduce_into_hash_map(&mut a, k, v, &reduce);
+            }
+            a
+        };
+        self.fold(HashMap::new, insert, combine)
+    }
+
+    /// Reduces the values of the elements sharing the same key into shards, which are maps with disjoint keys;
+    /// keys and values of the elements are computed by the `key` and `value` functions, respectively.
+    ///
+    /// Values are reduced in an arbitrary order; hence, `reduce` is expected to be associative and commutative.
+    ///
+    /// This is the sharded counterpart of [`reduce_by_key`]; see [`group_by_key_sharded`] for details
+    /// of the sharded parallel aggregation.
+    ///
+    /// [`reduce_by_key`]: crate::ParIter::reduce_by_key
+    /// [`group_by_key_sharded`]: crate::ParIter::group_by_key_sharded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let sales = vec![("apple", 3), ("bob", 1), ("apple", 4), ("cat", 2), ("bob", 5)];
+    ///
+    /// let shards = sales.par().reduce_by_key_sharded(|x| x.0, |x| x.1, |a, b| a + b);
+    ///
+    /// let total = |k| shards.iter().find_map(|x| x.get(k).copied());
+    /// assert_eq!(total(&"apple"), Some(7));
+    /// assert_eq!(total(&"bob"), Some(6));
+    /// assert_eq!(total(&"cat"), Some(2));
+    /// assert_eq!(total(&"dog"), None);
+    /// ```
+    #[cfg(feature = "std")]
+    fn reduce_by_key_sharded<Key, Value, GetKey, GetValue, Reduce>(
+        self,
+        key: GetKey,
+        value: GetValue,
+        reduce: Reduce,
+    ) -> Vec<HashMap<Key, Value>>
+    where
+        Key: Hash + Eq + Send,
+        Value: Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+        GetValue: Fn(Self::Item) -> Value + Sync,
+        Reduce: Fn(Value, Value) -> Value + Sync,
+    {
+        let merge = |map: &mut HashMap<Key, Value>, k: Key, v: Value| {
+            prc::by_key::reduce_into_hash_map(map, k, v, &reduce);
+        };
+        let insert = |map: &mut HashMap<Key, Value>, k: Key, x: Self::Item| merge(map, k, value(x));
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::by_key::x(orchestrator, params, iter, x1, key, insert, merge).1
+    }
+
+    /// Reduces the values of the elements sharing the same key, where keys and values of the elements
+    /// are computed by the `key` and `value` functions, respectively.
+    ///
+    /// Values are reduced in an arbitrary order; hence, `reduce` is expected to be associative and commutative.
+    ///
+    /// Returns a `HashMap` when the "std" feature is enabled; and a `BTreeMap` otherwise.
+    ///
+    /// See [`group_by_key`] for details of the parallel aggregation.
+    ///
+    /// [`group_by_key`]: crate::ParIter::group_by_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let sales = vec![("apple", 3), ("bob", 1), ("apple", 4), ("cat", 2), ("bob", 5)];
+    ///
+    /// let totals = sales.par().reduce_by_key(|x| x.0, |x| x.1, |a, b| a + b);
+    ///
+    /// assert_eq!(totals.len(), 3);
+    /// assert_eq!(totals[&"apple"], 7);
+    /// assert_eq!(totals[&"bob"], 6);
+    /// assert_eq!(totals[&"cat"], 2);
+    /// ```
+    #[cfg(not(feature = "std"))]
+    fn reduce_by_key<Key, Value, GetKey, GetValue, Reduce>(
+        self,
+        key: GetKey,
+        value: GetValue,
+        reduce: Reduce,
+    ) -> BTreeMap<Key, Value>
+    where
+        Key: Ord + Send,
+        Value: Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+        GetValue: Fn(Self::Item) -> Value + Sync,
+        Reduce: Fn(Value, Value) -> Value + Sync,
+    {
+        let insert = |mut map: BTreeMap<Key, Value>, x: Self::Item| {
+            prc::by_key::reduce_into_btree_map(&mut map, key(&x), value(x), &reduce);
+            map
+        };
+        let combine = |mut a: BTreeMap<Key, Value>, mut b: BTreeMap<Key, Value>| {
+            if a.len() < b.len() {
+                core::mem::swap(&mut a, &mut b);
+            }
+            for (k, v) in b {
+                prc::by_key::reduce_into_btree_map(&mut a, k, v, &reduce);
+            }
+            a
+        };
+        self.fold(BTreeMap::new, insert, combine)
+    }
+
+    /// Tests if every element of the iterator matches a predicate.
+    ///
+    /// `all` takes a `predicate` that returns true or false.
+    /// It applies this closure to each element of the iterator,
+    /// and if they all return true, then so does `all`.
+    /// If any of them returns false, it returns false.
+    ///
+    /// `all` is short-circuiting; in other words, it will stop processing as soon as it finds a false,
+    /// given that no matter what else happens, the result will also be false.
+    ///
+    /// An empty iterator returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut a = vec![1, 2, 3];
+    /// assert!(a.par().all(|x| **x > 0));
+    /// assert!(!a.par().all(|x| **x > 2));
+    ///
+    /// a.clear();
+    /// assert!(a.par().all(|x| **x > 2)); // empty iterator
+    /// ```
+    fn all<Predicate>(self, predicate: Predicate) -> bool
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let violates = |x: &Self::Item| !predicate(x);
+        self.find(violates).is_none()
+    }
+
+    /// Tests if any element of the iterator matches a predicate.
+    ///
+    /// `any` takes a `predicate` that returns true or false.
+    /// It applies this closure to each element of the iterator,
+    /// and if any of the elements returns true, then so does `any`.
+    /// If all of them return false, it returns false.
+    ///
+    /// `any` is short-circuiting; in other words, it will stop processing as soon as it finds a true,
+    /// given that no matter what else happens, the result will also be true.
+    ///
+    /// An empty iterator returns false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut a = vec![1, 2, 3];
+    /// assert!(a.par().any(|x| **x > 0));
+    /// assert!(!a.par().any(|x| **x > 5));
+    ///
+    /// a.clear();
+    /// assert!(!a.par().any(|x| **x > 0)); // empty iterator
+    /// ```
+    fn any<Predicate>(self, predicate: Predicate) -> bool
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        self.find(predicate).is_some()
+    }
+
+    /// Consumes the iterator, counting the number of iterations and returning it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![1, 2, 3];
+    /// assert_eq!(a.par().filter(|x| **x >= 2).count(), 2);
+    /// ```
+    fn count(self) -> usize {
+        self.map(map_count).reduce(reduce_sum).unwrap_or(0)
+    }
+
+    /// Calls a closure on each element of an iterator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    /// use std::sync::mpsc::channel;
+    ///
+    /// let (tx, rx) = channel();
+    /// (0..5)
+    ///     .par()
+    ///     .map(|x| x * 2 + 1)
+    ///     .for_each(move |x| tx.send(x).unwrap());
+    ///
+    /// let mut v: Vec<_> = rx.iter().collect();
+    /// v.sort(); // order can be mixed, since messages will be sent in parallel
+    /// assert_eq!(v, vec![1, 3, 5, 7, 9]);
+    /// ```
+    ///
+    /// Note that since parallel iterators cannot be used within the `for` loop as regular iterators,
+    /// `for_each` provides a way to perform arbitrary for loops on parallel iterators.
+    /// In the following example, we log every element that satisfies a predicate in parallel.
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// (0..5)
+    ///     .par()
+    ///     .flat_map(|x| x * 100..x * 110)
+    ///     .filter(|&x| x % 3 == 0)
+    ///     .for_each(|x| println!("{x}"));
+    /// ```
+    fn for_each<Operation>(self, operation: Operation)
+    where
+        Operation: Fn(Self::Item) + Sync,
+    {
+        let map = |x| operation(x);
+        let _ = self.map(map).reduce(reduce_unit);
+    }
+
+    /// Calls the `operation` sequentially on each element in the order of the input,
+    /// while the elements are computed in parallel.
+    ///
+    /// Unlike [`for_each`], the `operation` is a `FnMut` which is never called concurrently; hence, it can be
+    /// used to write the results to a sink in order without collecting them first.
+    /// Threads compute at most [`DEFAULT_REORDER_WINDOW`] positions ahead of the last position sent to the `operation`;
+    /// see [`for_each_ordered_with_window`] to set the length of this reorder window.
+    ///
+    /// The elements are always processed in the order of the input regardless of the set iteration order.
+    ///
+    /// [`for_each`]: crate::ParIter::for_each
+    /// [`DEFAULT_REORDER_WINDOW`]: crate::DEFAULT_REORDER_WINDOW
+    /// [`for_each_ordered_with_window`]: crate::ParIter::for_each_ordered_with_window
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut output = vec![];
+    /// (0..100)
+    ///     .par()
+    ///     .filter(|x| x % 3 == 0)
+    ///     .map(|x| x * 2)
+    ///     .for_each_ordered(|x| output.push(x));
+    ///
+    /// assert_eq!(output, (0..100).filter(|x| x % 3 == 0).map(|x| x * 2).collect::<Vec<_>>());
+    /// ```
+    #[cfg(feature = "std")]
+    fn for_each_ordered<Operation>(self, operation: Operation)
+    where
+        Self::Item: Send,
+        Operation: FnMut(Self::Item) + Send,
+    {
+        self.for_each_ordered_with_window(prc::for_each_ordered::DEFAULT_REORDER_WINDOW, operation)
+    }
+
+    /// Calls the `operation` sequentially on each element in the order of the input,
+    /// while the elements are computed in parallel by threads which are allowed to be at most
+    /// `reorder_window` positions ahead of the last position sent to the `operation`.
+    ///
+    /// The reorder window bounds the number of input positions whose results are buffered at any time,
+    /// and hence, the memory used by the computation.
+    ///
+    /// See [`for_each_ordered`] for details.
+    ///
+    /// [`for_each_ordered`]: crate::ParIter::for_each_ordered
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reorder_window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut lines = String::new();
+    /// (0..4)
+    ///     .par()
+    ///     .map(|x| format!("line-{x}\n"))
+    ///     .for_each_ordered_with_window(2, |x| lines.push_str(&x));
+    ///
+    /// assert_eq!(lines, "line-0\nline-1\nline-2\nline-3\n");
+    /// ```
+    #[cfg(feature = "std")]
+    fn for_each_ordered_with_window<Operation>(
+        self,
+        reorder_window: usize,
+        mut operation: Operation,
+    ) where
+        Self::Item: Send,
+        Operation: FnMut(Self::Item) + Send,
+    {
+        let sink = |x| {
+            operation(x);
+            true
+        };
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        let (_, result) =
+            prc::for_each_ordered::x(orchestrator, params, iter, x1, reorder_window, sink);
+        let Ok(_) = result;
+    }
+
+    /// Writes the elements to the `writer` sequentially in the order of the input,
+    /// while the elements are mapped to their byte representations by the `format` function in parallel.
+    ///
+    /// Threads compute at most [`DEFAULT_REORDER_WINDOW`] positions ahead of the last position written;
+    /// hence, the results are not required to be collected before being written.
+    ///
+    /// The computation stops early and the error is returned as soon as a write to the `writer` fails.
+    ///
+    /// [`DEFAULT_REORDER_WINDOW`]: crate::DEFAULT_REORDER_WINDOW
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut output: Vec<u8> = vec![];
+    /// (0..5)
+    ///     .par()
+    ///     .map(|x| x * 10)
+    ///     .write_ordered(&mut output, |x| format!("{x};"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(output).unwrap(), "0;10;20;30;40;");
+    /// ```
+    #[cfg(feature = "std")]
+    fn write_ordered<Writer, Bytes, Format>(
+        self,
+        writer: &mut Writer,
+        format: Format,
+    ) -> std::io::Result<()>
+    where
+        Writer: std::io::Write + Send,
+        Bytes: AsRef<[u8]> + Send,
+        Format: Fn(Self::Item) -> Bytes + Sync,
+    {
+        let mut error = None;
+        let sink = |bytes: Bytes| match writer.write_all(bytes.as_ref()) {
+            Ok(()) => true,
+            Err(e) => {
+                error = Some(e);
+                false
+            }
+        };
+        let map = |x| format(x);
+        let (orchestrator, params, iter, x1) = self.map(map).into_xap().into_xap_parts();
+        let window = prc::for_each_ordered::DEFAULT_REORDER_WINDOW;
+        let (_, result) = prc::for_each_ordered::x(orchestrator, params, iter, x1, window, sink);
+        let Ok(_) = result;
+        error.map_or(Ok(()), Err)
+    }
+
+    /// Sends all elements to the `sink`, such as the sending half of a channel, as they are computed.
+    ///
+    /// Each thread collects the elements computed from a pulled chunk into a batch and sends the batch
+    /// at once to reduce contention on the sink. Elements are sent in an arbitrary order regardless of
+    /// the iteration order of the parallel iterator.
+    ///
+    /// The computation stops early once the sink is disconnected, such as when the receiving half of
+    /// the channel is dropped.
+    /// Returns true if all elements are sent; false if the computation is stopped early due to a disconnected sink.
+    ///
+    /// The plain channel senders, `std::sync::mpsc::Sender` and `std::sync::mpsc::SyncSender`, send the elements
+    /// of each batch one by one. In order to send each batch as a single message, the senders of a channel of
+    /// vectors can be wrapped in a [`BatchSender`] or a [`SyncBatchSender`].
+    /// See [`Sink`] for the sinks which can be used and to implement custom sinks.
+    ///
+    /// [`Sink`]: crate::Sink
+    /// [`BatchSender`]: crate::BatchSender
+    /// [`SyncBatchSender`]: crate::SyncBatchSender
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    /// use std::sync::mpsc::channel;
+    ///
+    /// let (tx, rx) = channel();
+    /// let consumer = std::thread::spawn(move || rx.iter().collect::<Vec<_>>());
+    ///
+    /// let all_sent = (0..100).par().map(|x| x * 2).for_each_send(tx);
+    /// assert!(all_sent);
+    ///
+    /// let mut values = consumer.join().unwrap();
+    /// values.sort();
+    /// assert_eq!(values, (0..100).map(|x| x * 2).collect::<Vec<_>>());
+    ///
+    /// // computation stops early when the receiver is dropped
+    /// let (tx, rx) = channel();
+    /// drop(rx);
+    /// let all_sent = (0..100).par().for_each_send(tx);
+    /// assert!(!all_sent);
+    /// ```
+    fn for_each_send<S>(self, sink: S) -> bool
+    where
+        S: Sink<Self::Item>,
+    {
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        let (_, result) = prc::send::x(orchestrator, params, iter, x1, sink);
+        let Ok(all_sent) = result;
+        all_sent
+    }
+
+    /// Starts the parallel computation in the background and returns a sequential iterator yielding its results
+    /// as soon as they are computed, without waiting for the entire computation to complete.
+    ///
+    /// The computation is driven by a background thread and run on the thread pool of the runner.
+    /// Results are passed to the iterator through a bounded buffer of length `buffer_len`;
+    /// the threads of the computation pause while the buffer is full, until the consumer catches up.
+    /// Dropping the iterator stops the computation.
+    ///
+    /// Depending on the set iteration order of the parallel iterator, the elements are yielded
+    ///
+    /// * in the order of the input if default iteration order `IterationOrder::Ordered` is used,
+    ///   in which case threads compute at most `buffer_len` positions ahead of the last position sent to the buffer,
+    /// * in an arbitrary order if `IterationOrder::Arbitrary` is set.
+    ///
+    /// If the computation panics, the panic is propagated to the consumer once the iterator reaches the end.
+    ///
+    /// # Owned inputs only
+    ///
+    /// Since the returned iterator may outlive the current scope, the computation must not borrow anything,
+    /// which is required by the `Self: 'static` bound.
+    /// Therefore, parallel iterators over borrowed data such as `vec.par()` cannot use this method;
+    /// [`with_ordered_seq_iter`] provides the same sequential iterator within a scope and accepts borrowing
+    /// parallel iterators.
+    ///
+    /// [`with_ordered_seq_iter`]: crate::ParIter::with_ordered_seq_iter
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer_len` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let input: Vec<_> = (0..1000).collect();
+    ///
+    /// let mut iter = input.into_par().map(|x| x * 2).into_ordered_seq_iter(16);
+    /// assert_eq!(iter.next(), Some(0));
+    /// assert_eq!(iter.next(), Some(2));
+    ///
+    /// let sum: usize = iter.take(3).sum();
+    /// assert_eq!(sum, 4 + 6 + 8);
+    ///
+    /// let mut output: Vec<_> = (0..1000)
+    ///     .par()
+    ///     .iteration_order(IterationOrder::Arbitrary)
+    ///     .filter(|x| x % 2 == 0)
+    ///     .into_ordered_seq_iter(16)
+    ///     .collect();
+    /// output.sort();
+    /// assert_eq!(output, (0..1000).step_by(2).collect::<Vec<_>>());
+    /// ```
+    #[cfg(feature = "std")]
+    fn into_ordered_seq_iter(self, buffer_len: usize) -> impl Iterator<Item = Self::Item>
+    where
+        Self: 'static,
+        Self::Item: Send + 'static,
+    {
+        prc::seq_iter::x(self, buffer_len)
+    }
+
+    /// Starts the parallel computation in the background and calls `f` with a sequential iterator yielding its
+    /// results as soon as they are computed; returns the result of `f`.
+    ///
+    /// This is the scoped counterpart of [`into_ordered_seq_iter`]: the computation is stopped and joined
+    /// before this method returns, and hence, the parallel iterator is allowed to borrow, such as `vec.par()`.
+    ///
+    /// The results are passed to the iterator through a bounded buffer of length `buffer_len`, and they are
+    /// yielded in the order of the input unless `IterationOrder::Arbitrary` is set.
+    /// Returning from `f` before consuming the iterator stops the computation.
+    ///
+    /// [`into_ordered_seq_iter`]: crate::ParIter::into_ordered_seq_iter
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer_len` is zero.
+    /// If the computation panics, the panic is propagated once the iterator reaches the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let input: Vec<_> = (0..1000).collect();
+    ///
+    /// let first_three: Vec<_> = input
+    ///     .par()
+    ///     .map(|x| x * 2)
+    ///     .with_ordered_seq_iter(16, |iter| iter.take(3).collect());
+    /// assert_eq!(first_three, [0, 2, 4]);
+    ///
+    /// let sum = input.par().with_ordered_seq_iter(16, |iter| iter.sum::<usize>());
+    /// assert_eq!(sum, 999 * 1000 / 2);
+    /// ```
+    #[cfg(feature = "std")]
+    fn with_ordered_seq_iter<F, T>(self, buffer_len: usize, f: F) -> T
+    where
+        Self::Item: Send,
+        F: FnOnce(&mut dyn Iterator<Item = Self::Item>) -> T,
+    {
+        prc::seq_iter::scoped(self, buffer_len, f)
+    }
+
+    /// Returns the maximum element of an iterator.
+    ///
+    /// If the iterator is empty, None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![1, 2, 3];
+    /// let b: Vec<u32> = Vec::new();
+    ///
+    /// assert_eq!(a.par().max(), Some(&3));
+    /// assert_eq!(b.par().max(), None);
+    /// ```
+    fn max(self) -> Option<Self::Item>
+    where
+        Self::Item: Ord + Send,
+    {
+        self.reduce(Ord::max)
+    }
+
+    /// Returns the element that gives the maximum value with respect to the specified `compare` function.
+    ///
+    /// If the iterator is empty, None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(*a.par().max_by(|x, y| x.cmp(y)).unwrap(), 5);
+    /// ```
+    fn max_by<Compare>(self, compare: Compare) -> Option<Self::Item>
+    where
+        Self::Item: Send,
+        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
+    {
+        let reduce = |x, y| match compare(&x, &y) {
+            Ordering::Greater | Ordering::Equal => x,
+            Ordering::Less => y,
+        };
+        self.reduce(reduce)
+    }
+
+    /// Returns the element that gives the maximum value from the specified function.
+    ///
+    /// If the iterator is empty, None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(*a.par().max_by_key(|x| x.abs()).unwrap(), -10);
+    /// ```
+    fn max_by_key<Key, GetKey>(self, key: GetKey) -> Option<Self::Item>
+    where
+        Self::Item: Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let reduce = |x, y| match key(&x).cmp(&key(&y)) {
+            Ordering::Greater | Ordering::Equal => x,
+            Ordering::Less => y,
+        };
+        self.reduce(reduce)
+    }
+
+    /// Returns the minimum element of an iterator.
+    ///
+    /// If the iterator is empty, None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![1, 2, 3];
+    /// let b: Vec<u32> = Vec::new();
+    ///
+    /// assert_eq!(a.par().min(), Some(&1));
+    /// assert_eq!(b.par().min(), None);
+    /// ```
+    fn min(self) -> Option<Self::Item>
+    where
+        Self::Item: Ord + Send,
+    {
+        self.reduce(Ord::min)
+    }
+
+    /// Returns the element that gives the minimum value with respect to the specified `compare` function.
+    ///
+    /// If the iterator is empty, None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(*a.par().min_by(|x, y| x.cmp(y)).unwrap(), -10);
+    /// ```
+    fn min_by<Compare>(self, compare: Compare) -> Option<Self::Item>
+    where
+        Self::Item: Send,
+        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
+    {
+        let reduce = |x, y| match compare(&x, &y) {
+            Ordering::Less | Ordering::Equal => x,
+            Ordering::Greater => y,
+        };
+        self.reduce(reduce)
+    }
+
+    /// Returns the element that gives the minimum value from the specified function.
+    ///
+    /// If the iterator is empty, None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(*a.par().min_by_key(|x| x.abs()).unwrap(), 0);
+    /// ```
+    fn min_by_key<Key, GetKey>(self, get_key: GetKey) -> Option<Self::Item>
+    where
+        Self::Item: Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let reduce = |x, y| match get_key(&x).cmp(&get_key(&y)) {
+            Ordering::Less | Ordering::Equal => x,
+            Ordering::Greater => y,
+        };
+        self.reduce(reduce)
+    }
+
+    /// Returns the minimum and maximum elements of the iterator, computed in a single pass.
+    ///
+    /// If the iterator is empty, None is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    ///
+    /// The extremes are tracked without cloning the elements; the only clone happens when the iterator
+    /// has exactly one element which is then both the minimum and the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![3, 1, 4, 1, 5, 9, 2, 6];
+    /// let b: Vec<u32> = Vec::new();
+    ///
+    /// assert_eq!(a.par().min_max(), Some((&1, &9)));
+    /// assert_eq!(b.par().min_max(), None);
+    /// ```
+    fn min_max(self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Ord + Clone + Send,
+    {
+        self.min_max_by(Self::Item::cmp)
+    }
+
+    /// Returns the elements that give the minimum and maximum values with respect to the specified
+    /// `compare` function, computed in a single pass.
+    ///
+    /// If the iterator is empty, None is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.par().copied().min_max_by(|x, y| x.cmp(y)), Some((-10, 5)));
+    /// ```
+    fn min_max_by<Compare>(self, compare: Compare) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Clone + Send,
+        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
+    {
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        let x1 = |(idx, i)| x1(i).map(move |x| min_max_unit((idx, x)));
+        let compare = |a: &_, b: &_| compare_with_idx(&compare, a, b);
+        let reduce = |a, b| min_max_reduce(&compare, a, b);
+        let (_, Ok(acc)) = prc::reduce::x(orchestrator, params, iter.enumerate(), x1, reduce);
+        acc.map(min_max_into_pair_without_idx)
+    }
+
+    /// Returns the elements that give the minimum and maximum values from the specified function,
+    /// computed in a single pass.
+    ///
+    /// If the iterator is empty, None is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.par().copied().min_max_by_key(|x| x.abs()), Some((0, -10)));
+    /// ```
+    fn min_max_by_key<Key, GetKey>(self, get_key: GetKey) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Clone + Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        self.min_max_by(|x, y| get_key(x).cmp(&get_key(y)))
+    }
+
+    /// Returns the element that gives the minimum value from the specified function,
+    /// together with its position in the source iterator.
+    ///
+    /// If the iterator is empty, None is returned.
+    ///
+    /// When multiple elements give the minimum value, the one at the smallest position wins,
+    /// regardless of the number of threads or how the elements are distributed to them.
+    ///
+    /// Note that the position is that of the source element which produced the value.
+    /// For instance, values created by a `flat_map` from the same source element share the same position,
+    /// and positions of the elements passing a `filter` are not contiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![4_i32, -1, 3, 1, -7, 1];
+    ///
+    /// assert_eq!(a.par().copied().argmin_by_key(|x| x.abs()), Some((1, -1)));
+    /// assert_eq!(a.par().filter(|x| **x > 2).argmin_by_key(|x| **x), Some((2, &3)));
+    /// assert_eq!(a.par().copied().take_while(|x| *x > 0).argmin_by_key(|x| *x), Some((0, 4)));
+    /// ```
+    fn argmin_by_key<Key, GetKey>(self, get_key: GetKey) -> Option<(usize, Self::Item)>
+    where
+        Self::Item: Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let is_better = |x: &Self::Item, y: &Self::Item| get_key(x) < get_key(y);
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::arg_best::x(orchestrator, params, iter, x1, is_better).1
+    }
+
+    /// Returns the element that gives the maximum value from the specified function,
+    /// together with its position in the source iterator.
+    ///
+    /// If the iterator is empty, None is returned.
+    ///
+    /// When multiple elements give the maximum value, the one at the smallest position wins,
+    /// regardless of the number of threads or how the elements are distributed to them.
+    ///
+    /// See [`argmin_by_key`] for details on the positions.
+    ///
+    /// [`argmin_by_key`]: crate::ParIter::argmin_by_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![4_i32, -1, 3, 7, -7, 1];
+    ///
+    /// assert_eq!(a.par().copied().argmax_by_key(|x| x.abs()), Some((3, 7)));
+    ///
+    /// let flat = a.par().copied().flat_map(|x| [x, 2 * x]).argmax_by_key(|x| *x);
+    /// assert_eq!(flat, Some((3, 14)));
+    /// ```
+    fn argmax_by_key<Key, GetKey>(self, get_key: GetKey) -> Option<(usize, Self::Item)>
+    where
+        Self::Item: Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let is_better = |x: &Self::Item, y: &Self::Item| get_key(x) > get_key(y);
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::arg_best::x(orchestrator, params, iter, x1, is_better).1
+    }
+
+    /// Returns the `k` greatest elements of the iterator in descending order.
+    ///
+    /// If the iterator has fewer than `k` elements, all elements are returned.
+    ///
+    /// Each thread keeps the best `k` elements it observes in a bounded heap; the heaps of the threads are
+    /// then merged. Therefore, memory requirement is independent of the length of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![5, 3, 8, 1, 9, 2];
+    ///
+    /// assert_eq!(a.par().copied().top_k(3), vec![9, 8, 5]);
+    /// assert_eq!(a.par().copied().top_k(10), vec![9, 8, 5, 3, 2, 1]);
+    /// assert_eq!(a.par().copied().top_k(0), vec![]);
+    /// ```
+    fn top_k(self, k: usize) -> Vec<Self::Item>
+    where
+        Self::Item: Ord + Clone + Send,
+    {
+        self.top_k_by_key(k, |x| x.clone())
+    }
+
+    /// Returns the `k` elements with the greatest keys computed by the `key` function,
+    /// in descending order of their keys.
+    ///
+    /// If the iterator has fewer than `k` elements, all elements are returned.
+    /// When multiple elements have equal keys, it is not specified which of them are returned.
+    ///
+    /// See [`top_k`] for details.
+    ///
+    /// [`top_k`]: crate::ParIter::top_k
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let points = vec![(3, 4), (0, 1), (6, 8), (1, 1), (5, 12)];
+    ///
+    /// let farthest = points.par().copied().top_k_by_key(2, |(x, y)| x * x + y * y);
+    /// assert_eq!(farthest, vec![(5, 12), (6, 8)]);
+    /// ```
+    fn top_k_by_key<Key, GetKey>(self, k: usize, key: GetKey) -> Vec<Self::Item>
+    where
+        Self::Item: Send,
+        Key: Ord + Clone + Send,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        prc::top_k::x(orchestrator, params, iter, x1, k, key).1
+    }
+
+    /// Returns the `k` smallest elements of the iterator in ascending order.
+    ///
+    /// If the iterator has fewer than `k` elements, all elements are returned.
+    ///
+    /// See [`top_k`] for details.
+    ///
+    /// [`top_k`]: crate::ParIter::top_k
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![5, 3, 8, 1, 9, 2];
+    ///
+    /// assert_eq!(a.par().copied().bottom_k(3), vec![1, 2, 3]);
+    /// assert_eq!(a.par().map(|x| x.to_string()).bottom_k(2), vec!["1", "2"]);
+    /// ```
+    fn bottom_k(self, k: usize) -> Vec<Self::Item>
+    where
+        Self::Item: Ord + Clone + Send,
+    {
+        self.top_k_by_key(k, |x| Reverse(x.clone()))
+    }
+
+    /// Sums the elements of an iterator.
+    ///
+    /// Takes each element, adds them together, and returns the result.
+    ///
+    /// An empty iterator returns the additive identity (“zero”) of the type, which is 0 for integers and -0.0 for floats.
+    ///
+    /// `sum` can be used to sum any type implementing [`Sum<Out>`].
+    ///
+    /// [`Sum<Out>`]: crate::Sum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = vec![1, 2, 3];
+    /// let sum: i32 = a.par().sum();
+    ///
+    /// assert_eq!(sum, 6);
+    /// ```
+    fn sum<Out>(self) -> Out
+    where
+        Self::Item: Sum<Out>,
+        Out: Send,
+    {
+        self.map(Self::Item::map)
             .reduce(Self::Item::reduce)
             .unwrap_or(Self::Item::zero())
     }
 
+    /// Computes the descriptive statistics of the numbers in a single pass;
+    /// i.e., count, sum, mean, variance, min and max.
+    ///
+    /// Each thread keeps its own partial statistics which are merged at the end.
+    /// See [`Stats`] for details.
+    ///
+    /// `stats` can be computed over any type implementing [`Stat`].
+    ///
+    /// [`Stats`]: crate::Stats
+    /// [`Stat`]: crate::Stat
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let stats = (1..101).into_par().map(|x| x * 2).stats();
+    ///
+    /// assert_eq!(stats.count(), 100);
+    /// assert_eq!(stats.sum(), 10100.0);
+    /// assert!((stats.mean().unwrap() - 101.0).abs() < 1e-12);
+    /// assert_eq!(stats.min(), Some(2.0));
+    /// assert_eq!(stats.max(), Some(200.0));
+    ///
+    /// let empty: Vec<f32> = vec![];
+    /// let stats = empty.par().stats();
+    /// assert_eq!(stats.count(), 0);
+    /// assert_eq!(stats.mean(), None);
+    /// ```
+    fn stats(self) -> Stats
+    where
+        Self::Item: Stat,
+    {
+        self.map(Self::Item::map)
+            .reduce(Stats::merge)
+            .unwrap_or_default()
+    }
+
     // early exit
 
     /// Returns the first (or any) element of the iterator; returns None if it is empty.
@@ -1517,4 +2995,175 @@ where
     {
         self.filter(&predicate).first()
     }
+
+    /// Returns the last (or any) element of the iterator; returns None if it is empty.
+    ///
+    /// * last element is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * any element is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Each chunk pulled by a thread is scanned from its back; and threads skip the elements which are
+    /// known to be before an already found element. The only exception is when the iterator contains
+    /// a while condition, such as `take_while`, in which case all elements up to the stop are evaluated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<usize> = vec![];
+    /// assert_eq!(a.par().copied().last(), None);
+    ///
+    /// let a = vec![1, 2, 3];
+    /// assert_eq!(a.par().map(|x| x * 10).last(), Some(30));
+    ///
+    /// let a = 1..10_000;
+    /// assert_eq!(a.par().filter(|x| x % 3421 == 0).last(), Some(2 * 3421));
+    /// assert_eq!(a.par().filter(|x| x % 12345 == 0).last(), None);
+    /// ```
+    fn last(self) -> Option<Self::Item>
+    where
+        Self::Item: Send;
+
+    /// Searches for the last element of an iterator that satisfies a `predicate`.
+    ///
+    /// Depending on the set iteration order of the parallel iterator, returns
+    ///
+    /// * last element satisfying the `predicate` if default iteration order `IterationOrder::Ordered` is used,
+    /// * any element satisfying the `predicate` if `IterationOrder::Arbitrary` is set.
+    ///
+    /// `par_iter.find_last(predicate)` can also be considered as a shorthand for `par_iter.filter(predicate).last()`.
+    ///
+    /// Each chunk pulled by a thread is scanned from its back; and threads skip the elements which are
+    /// known to be before an already found match. The only exception is when the iterator contains
+    /// a while condition, such as `take_while`, in which case all elements up to the stop are evaluated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a = 1..10_000;
+    /// assert_eq!(a.par().find_last(|x| x % 12345 == 0), None);
+    /// assert_eq!(a.par().find_last(|x| x % 3421 == 0), Some(2 * 3421));
+    ///
+    /// let b = vec!["x", "1", "y", "2", "z"];
+    /// let last = b.par().map(|x| x.parse::<u32>()).find_last(|x| x.is_ok());
+    /// assert_eq!(last, Some(Ok(2)));
+    /// ```
+    fn find_last<Predicate>(self, predicate: Predicate) -> Option<Self::Item>
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        self.filter(&predicate).last()
+    }
+
+    /// Searches for an element satisfying the `predicate` and returns its index.
+    ///
+    /// The index is the position of the element among the elements yielded by the iterator,
+    /// as in [`Iterator::position`].
+    ///
+    /// Depending on the set iteration order of the parallel iterator, returns
+    ///
+    /// * index of the first element satisfying the `predicate` if default iteration order `IterationOrder::Ordered` is used,
+    /// * index of any element satisfying the `predicate` if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Similar to [`find`], `position` is short-circuiting: elements positioned after an already
+    /// found match are not evaluated.
+    ///
+    /// [`find`]: crate::ParIter::find
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let vec = vec![3, 7, 12, 5, 18];
+    ///
+    /// assert_eq!(vec.par().position(|x| **x > 10), Some(2));
+    /// assert_eq!(vec.par().position(|x| **x > 100), None);
+    /// assert_eq!(vec.par().skip(1).position(|x| *x % 2 == 0), Some(1));
+    ///
+    /// // index among the yielded elements
+    /// let odd = vec.par().filter(|x| *x % 2 == 1);
+    /// assert_eq!(odd.position(|x| **x == 5), Some(2));
+    ///
+    /// let repeated = vec.par().flat_map(|x| [*x, *x]);
+    /// assert_eq!(repeated.position(|x| *x == 12), Some(4));
+    /// ```
+    fn position<Predicate>(self, predicate: Predicate) -> Option<usize>
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        let x1 = |i| x1(i).map(|x| PositionSegment::new(predicate(&x)));
+        let (_, Ok(position)) = prc::position::x(orchestrator, params, iter, x1);
+        position
+    }
+
+    /// Searches for an element satisfying the `predicate` from the back and returns its index.
+    ///
+    /// The index is the position of the element among the elements yielded by the iterator,
+    /// counted from the front as in [`Iterator::rposition`].
+    ///
+    /// Depending on the set iteration order of the parallel iterator, returns
+    ///
+    /// * index of the last element satisfying the `predicate` if default iteration order `IterationOrder::Ordered` is used,
+    /// * index of any element satisfying the `predicate` if `IterationOrder::Arbitrary` is set.
+    ///
+    /// When the transformations are one-to-one, such as `map`, the index is known without counting
+    /// the elements; hence, similar to [`find_last`], elements positioned before an already found
+    /// match are not evaluated. Otherwise, all elements are evaluated in order to count the
+    /// elements yielded before the match.
+    ///
+    /// [`find_last`]: crate::ParIter::find_last
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let vec = vec![3, 7, 12, 5, 18];
+    ///
+    /// assert_eq!(vec.par().rposition(|x| **x < 10), Some(3));
+    /// assert_eq!(vec.par().rposition(|x| **x > 100), None);
+    /// assert_eq!(vec.par().skip(1).rposition(|x| **x == 7), Some(0));
+    ///
+    /// // index among the yielded elements
+    /// let even = vec.par().filter(|x| *x % 2 == 0);
+    /// assert_eq!(even.rposition(|x| **x > 10), Some(1));
+    /// ```
+    fn rposition<Predicate>(self, predicate: Predicate) -> Option<usize>
+    where
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let (orchestrator, params, iter, x1) = self.into_xap().into_xap_parts();
+        let x1 = |i| x1(i).map(|x| PositionSegment::new(predicate(&x)));
+        let (_, Ok(position)) = prc::position::x_rev(orchestrator, params, iter, x1);
+        position
+    }
+}
+
+/// Collects elements of the parallel iterator into two collections with a single computation,
+/// where `split` distributes the values collected by each thread to the two outputs.
+fn collect_split<R, P, A, B, C1, C2, S>(par: P, split: S) -> (C1, C2)
+where
+    R: ParallelRunner,
+    P: ParIter<R>,
+    P::Item: Send,
+    A: Send,
+    B: Send,
+    C1: ParCollectTarget<A>,
+    C2: ParCollectTarget<B>,
+    S: Fn(Vec<(usize, P::Item)>) -> (Vec<(usize, A)>, Vec<(usize, B)>) + Sync,
+{
+    let (orchestrator, params, iter, x1) = par.into_xap().into_xap_parts();
+    let order = params.iteration_order;
+    let (_, Ok((left, right, stopped_idx))) =
+        prc::collect_unzip::x(orchestrator, params, iter, x1, split);
+    let left = C1::empty(None).extend_with_idx(left, stopped_idx, order);
+    let right = C2::empty(None).extend_with_idx(right, stopped_idx, order);
+    (left, right)
 }
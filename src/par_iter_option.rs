@@ -1,7 +1,8 @@
 use crate::default_fns::{map_count, reduce_sum, reduce_unit};
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::{
-    ChunkSize, IterationOrder, NumThreads, ParCollectInto, ParThreadPool, RunnerWithPool, Sum,
+    ChunkSize, IterationOrder, NumThreads, ParCollectTarget, ParIterResult, ParThreadPool,
+    RunnerWithPool, Sum,
 };
 use core::cmp::Ordering;
 
@@ -410,6 +411,48 @@ where
         Operation: Fn(&Self::Item) + Sync + Clone,
         Self::Item: Send;
 
+    /// Takes two fallible parallel iterators and creates a new fallible parallel iterator over both in sequence.
+    ///
+    /// Both iterators may have different computation pipelines, as long as they have the same `Item` type.
+    /// The chained iterator uses the runner and parameters of this iterator.
+    ///
+    /// Transformation is only for the success path where all elements are of the `Some` variant.
+    /// Any observation of a `None` case, on either of the iterators, short-circuits the computation and
+    /// immediately returns None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// // all succeeds
+    /// let a = vec!["1", "2"];
+    /// let b = vec![3, 4, 5];
+    /// let left = a.par().map(|x| x.parse::<u32>().ok()).into_fallible_option();
+    /// let right = b.par().map(|x| Some(x * 10)).into_fallible_option();
+    ///
+    /// let c: Option<Vec<_>> = left.chain(right).collect();
+    /// assert_eq!(c, Some(vec![1, 2, 30, 40, 50]));
+    ///
+    /// // at least one fails
+    /// let a = vec!["1", "x"];
+    /// let left = a.par().map(|x| x.parse::<u32>().ok()).into_fallible_option();
+    /// let right = b.par().map(|x| Some(x * 10)).into_fallible_option();
+    ///
+    /// let c: Option<Vec<_>> = left.chain(right).collect();
+    /// assert_eq!(c, None);
+    /// ```
+    fn chain<Q, C>(self, other: C) -> impl ParIterOption<R, Item = Self::Item>
+    where
+        Self: Sized,
+        Q: ParallelRunner,
+        C: ParIterOption<Q, Item = Self::Item>,
+        Self::Item: Send;
+
+    /// Converts this fallible iterator into a fallible result iterator where the unit type `()`
+    /// represents the `None` case as the error.
+    fn into_fallible_unit_result(self) -> impl ParIterResult<R, Item = Self::Item, Err = ()>;
+
     // collect
 
     /// Collects all the items from an iterator into a collection iff all elements are of Some variant.
@@ -419,9 +462,9 @@ where
     ///
     /// The collection is passed in as owned value, and returned back with the additional elements.
     ///
-    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    /// All collections implementing [`ParCollectTarget`] can be used to collect into.
     ///
-    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// # Examples
     ///
@@ -454,7 +497,7 @@ where
     fn collect_into<C>(self, output: C) -> Option<C>
     where
         Self::Item: Send,
-        C: ParCollectInto<Self::Item>;
+        C: ParCollectTarget<Self::Item>;
 
     /// Transforms an iterator into a collection iff all elements are of Ok variant.
     /// Early exits and returns the error if any of the elements is an Err.
@@ -462,9 +505,9 @@ where
     /// Similar to [`Iterator::collect`], the type annotation on the left-hand-side determines
     /// the type of the result collection; or turbofish annotation can be used.
     ///
-    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    /// All collections implementing [`ParCollectTarget`] can be used to collect into.
     ///
-    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// # Examples
     ///
@@ -496,7 +539,7 @@ where
     fn collect<C>(self) -> Option<C>
     where
         Self::Item: Send,
-        C: ParCollectInto<Self::Item>;
+        C: ParCollectTarget<Self::Item>;
 
     // reduce
 
@@ -994,6 +1037,101 @@ where
         self.reduce(reduce)
     }
 
+    /// Returns Some of the minimum and maximum elements of the iterator, computed in a single pass,
+    /// if all elements are Some.
+    /// If the iterator is empty, `Some(None)` is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    /// Early exits and returns None if any of the elements is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(2), Some(1), Some(3)];
+    /// assert_eq!(a.par().copied().into_fallible_option().min_max(), Some(Some((1, 3))));
+    ///
+    /// let b: Vec<Option<i32>> = vec![];
+    /// assert_eq!(b.par().copied().into_fallible_option().min_max(), Some(None));
+    ///
+    /// let c: Vec<Option<i32>> = vec![Some(1), Some(2), None];
+    /// assert_eq!(c.par().copied().into_fallible_option().min_max(), None);
+    /// ```
+    fn min_max(self) -> Option<Option<(Self::Item, Self::Item)>>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone + Send,
+    {
+        self.min_max_by(Self::Item::cmp)
+    }
+
+    /// Returns the elements that give the minimum and maximum values with respect to the specified
+    /// `compare` function, computed in a single pass.
+    /// If the iterator is empty, `Some(None)` is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    /// Early exits and returns None if any of the elements is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(2), Some(1), Some(3)];
+    /// assert_eq!(
+    ///     a.par()
+    ///         .copied()
+    ///         .into_fallible_option()
+    ///         .min_max_by(|a, b| b.cmp(a)),
+    ///     Some(Some((3, 1)))
+    /// );
+    /// ```
+    fn min_max_by<Compare>(self, compare: Compare) -> Option<Option<(Self::Item, Self::Item)>>
+    where
+        Self: Sized,
+        Self::Item: Clone + Send,
+        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
+    {
+        self.into_fallible_unit_result()
+            .min_max_by(compare)
+            .into_option()
+    }
+
+    /// Returns the elements that give the minimum and maximum values from the specified function,
+    /// computed in a single pass.
+    /// If the iterator is empty, `Some(None)` is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    /// Early exits and returns None if any of the elements is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(-1), Some(2), Some(-3)];
+    /// assert_eq!(
+    ///     a.par()
+    ///         .copied()
+    ///         .into_fallible_option()
+    ///         .min_max_by_key(|x| x.abs()),
+    ///     Some(Some((-1, -3)))
+    /// );
+    /// ```
+    fn min_max_by_key<Key, GetKey>(
+        self,
+        get_key: GetKey,
+    ) -> Option<Option<(Self::Item, Self::Item)>>
+    where
+        Self: Sized,
+        Self::Item: Clone + Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        self.min_max_by(|x, y| get_key(x).cmp(&get_key(y)))
+    }
+
     /// Sums the elements of an iterator.
     /// Early exits and returns None if any of the elements is None.
     ///
@@ -1120,6 +1258,161 @@ where
     {
         self.filter(&predicate).first()
     }
+
+    /// Returns the last (or any) element of the iterator.
+    /// If the iterator is empty, `Some(None)` is returned.
+    /// Early exits and returns None if a None element is observed first.
+    ///
+    /// * last element is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * any element is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Chunks pulled by the threads are scanned from their back, and the elements positioned before
+    /// an already found element are not evaluated.
+    /// Therefore, in case the fallible iterator contains both a None and a Some element,
+    /// the result is **not deterministic**:
+    /// * it might be the `None` if it is observed first;
+    /// * or `Some(element)` if a Some element after it is observed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Option<i32>> = vec![];
+    /// assert_eq!(a.par().copied().into_fallible_option().last(), Some(None));
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(1), Some(2), Some(3)];
+    /// assert_eq!(
+    ///     a.par().copied().into_fallible_option().last(),
+    ///     Some(Some(3))
+    /// );
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(1), None, Some(3)];
+    /// let result = a.par().copied().into_fallible_option().last();
+    /// // depends on whichever is observed first in parallel execution
+    /// assert!(result == Some(Some(3)) || result == None);
+    /// ```
+    fn last(self) -> Option<Option<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Send,
+    {
+        self.into_fallible_unit_result().last().into_option()
+    }
+
+    /// Returns the last (or any) element of the iterator that satisfies the `predicate`.
+    /// If the iterator is empty, `Some(None)` is returned.
+    /// Early exits and returns None if a None element is observed first.
+    ///
+    /// * last element is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * any element is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Note that `find_last` itself is short-circuiting in addition to fallible computation.
+    /// Therefore, in case the fallible iterator contains both a None and a Some element,
+    /// the result is **not deterministic**:
+    /// * it might be the `None` if it is observed first;
+    /// * or `Some(element)` if a Some element satisfying the predicate after it is observed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(1), Some(2), Some(3)];
+    /// assert_eq!(
+    ///     a.par().copied().into_fallible_option().find_last(|x| *x < 3),
+    ///     Some(Some(2))
+    /// );
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(1), None, Some(3)];
+    /// let result = a.par().copied().into_fallible_option().find_last(|x| *x > 2);
+    /// // depends on whichever is observed first in parallel execution
+    /// assert!(result == Some(Some(3)) || result == None);
+    /// ```
+    fn find_last<Predicate>(self, predicate: Predicate) -> Option<Option<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        self.filter(&predicate).last()
+    }
+
+    /// Searches for an element satisfying the `predicate` and returns its index among the
+    /// elements yielded by the iterator.
+    /// If there is no such element, `Some(None)` is returned.
+    /// Early exits and returns None if a None element is observed first.
+    ///
+    /// * index of the first element satisfying the `predicate` is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * index of any element satisfying the `predicate` is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Note that `position` itself is short-circuiting in addition to fallible computation.
+    /// Therefore, in case the fallible iterator contains both a None and a Some element,
+    /// the result is **not deterministic**:
+    /// * it might be the `None` if it is observed first;
+    /// * or `Some(index)` if the Some element satisfying the predicate is observed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(1), Some(2), Some(3)];
+    /// assert_eq!(
+    ///     a.par().copied().into_fallible_option().position(|x| *x > 1),
+    ///     Some(Some(1))
+    /// );
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(1), None, Some(3)];
+    /// let result = a.par().copied().into_fallible_option().position(|x| *x < 2);
+    /// // depends on whichever is observed first in parallel execution
+    /// assert!(result == Some(Some(0)) || result == None);
+    /// ```
+    fn position<Predicate>(self, predicate: Predicate) -> Option<Option<usize>>
+    where
+        Self: Sized,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        self.into_fallible_unit_result()
+            .position(predicate)
+            .into_option()
+    }
+
+    /// Searches for an element satisfying the `predicate` from the back and returns its index
+    /// among the elements yielded by the iterator, counted from the front.
+    /// If there is no such element, `Some(None)` is returned.
+    /// Early exits and returns None if a None element is observed.
+    ///
+    /// * index of the last element satisfying the `predicate` is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * index of any element satisfying the `predicate` is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Since the index is counted from the front, all elements are evaluated unless a None element
+    /// is observed; hence, the result is None whenever the iterator contains a None element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(1), Some(2), Some(3)];
+    /// assert_eq!(
+    ///     a.par().copied().into_fallible_option().rposition(|x| *x < 3),
+    ///     Some(Some(1))
+    /// );
+    ///
+    /// let a: Vec<Option<i32>> = vec![Some(1), None, Some(3)];
+    /// let result = a.par().copied().into_fallible_option().rposition(|x| *x > 2);
+    /// assert_eq!(result, None);
+    /// ```
+    fn rposition<Predicate>(self, predicate: Predicate) -> Option<Option<usize>>
+    where
+        Self: Sized,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        self.into_fallible_unit_result()
+            .rposition(predicate)
+            .into_option()
+    }
 }
 
 pub trait IntoOption<T> {
@@ -1,8 +1,16 @@
+use crate::computational_variants::XapParts;
 use crate::default_fns::{map_count, reduce_sum, reduce_unit};
+use crate::executor::parallel_compute as prc;
+use crate::generic_values::TransformableValues;
+use crate::generic_values::runner_results::PositionSegment;
+use crate::min_max::{
+    ResultMinMax, compare_with_idx, min_max_into_pair_without_idx, min_max_reduce, min_max_unit,
+};
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::{ChunkSize, IterationOrder, NumThreads, ParThreadPool, RunnerWithPool, Sum};
-use crate::{ParCollectInto, ParIter, generic_values::fallible_iterators::ResultOfIter};
+use crate::{ParCollectTarget, ParIter, generic_values::fallible_iterators::ResultOfIter};
 use core::cmp::Ordering;
+use orx_concurrent_iter::ConcurrentIter;
 
 /// A parallel iterator for which the computation either completely succeeds,
 /// or fails and **early exits** with an error.
@@ -500,6 +508,50 @@ where
         self.map(map)
     }
 
+    /// Takes two fallible parallel iterators and creates a new fallible parallel iterator over both in sequence.
+    ///
+    /// Both iterators may have different computation pipelines, as long as they have the same `Item` and `Err`
+    /// types. The chained iterator uses the runner and parameters of this iterator.
+    ///
+    /// Transformation is only for the success path where all elements are of the `Ok` variant.
+    /// Any observation of an `Err` case, on either of the iterators, short-circuits the computation and
+    /// immediately returns the observed error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// // all succeeds
+    /// let a = vec!["1", "2"];
+    /// let b = vec![3, 4, 5];
+    /// let left = a.par().map(|x| x.parse::<u32>()).into_fallible_result();
+    /// let right = b.par().map(|x| Ok(x * 10)).into_fallible_result();
+    ///
+    /// let c: Result<Vec<_>, _> = left.chain(right).collect();
+    /// assert_eq!(c, Ok(vec![1, 2, 30, 40, 50]));
+    ///
+    /// // at least one fails
+    /// let a = vec!["1", "x"];
+    /// let left = a.par().map(|x| x.parse::<u32>()).into_fallible_result();
+    /// let right = b.par().map(|x| Ok(x * 10)).into_fallible_result();
+    ///
+    /// let c: Result<Vec<_>, _> = left.chain(right).collect();
+    /// assert!(c.is_err());
+    /// ```
+    fn chain<Q, C>(self, other: C) -> impl ParIterResult<R, Item = Self::Item, Err = Self::Err>
+    where
+        Self: Sized,
+        Q: ParallelRunner,
+        C: ParIterResult<Q, Item = Self::Item, Err = Self::Err>,
+        Self::Item: Send,
+        Self::Err: Send,
+    {
+        let left = self.into_regular_par().map(IntoResult::into_result);
+        let right = other.into_regular_par().map(IntoResult::into_result);
+        left.chain(right).into_fallible_result()
+    }
+
     // collect
 
     /// Collects all the items from an iterator into a collection iff all elements are of Ok variant.
@@ -509,9 +561,9 @@ where
     ///
     /// The collection is passed in as owned value, and returned back with the additional elements.
     ///
-    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    /// All collections implementing [`ParCollectTarget`] can be used to collect into.
     ///
-    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// # Examples
     ///
@@ -544,7 +596,7 @@ where
     /// ```
     fn collect_into<C>(self, output: C) -> Result<C, Self::Err>
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
         Self::Item: Send,
         Self::Err: Send;
 
@@ -554,9 +606,9 @@ where
     /// Similar to [`Iterator::collect`], the type annotation on the left-hand-side determines
     /// the type of the result collection; or turbofish annotation can be used.
     ///
-    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    /// All collections implementing [`ParCollectTarget`] can be used to collect into.
     ///
-    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// # Examples
     ///
@@ -590,7 +642,7 @@ where
         Self: Sized,
         Self::Item: Send,
         Self::Err: Send,
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let output = C::empty(self.con_iter_len());
         self.collect_into(output)
@@ -1110,6 +1162,107 @@ where
         self.reduce(reduce)
     }
 
+    /// Returns Ok of the minimum and maximum elements of the iterator, computed in a single pass,
+    /// if all elements succeed.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    /// Early exits and returns the error if any of the elements is an Err.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(2), Ok(1), Ok(3)];
+    /// assert_eq!(a.par().copied().into_fallible_result().min_max(), Ok(Some((1, 3))));
+    ///
+    /// let b: Vec<Result<i32, char>> = vec![];
+    /// assert_eq!(b.par().copied().into_fallible_result().min_max(), Ok(None));
+    ///
+    /// let c: Vec<Result<i32, char>> = vec![Ok(1), Ok(2), Err('x')];
+    /// assert_eq!(c.par().copied().into_fallible_result().min_max(), Err('x'));
+    /// ```
+    fn min_max(self) -> ResultMinMax<Self::Item, Self::Err>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone + Send,
+        Self::Err: Send,
+    {
+        self.min_max_by(Self::Item::cmp)
+    }
+
+    /// Returns the elements that give the minimum and maximum values with respect to the specified
+    /// `compare` function, computed in a single pass.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    /// Early exits and returns the error if any of the elements is an Err.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(2), Ok(1), Ok(3)];
+    /// assert_eq!(
+    ///     a.par()
+    ///         .copied()
+    ///         .into_fallible_result()
+    ///         .min_max_by(|a, b| b.cmp(a)),
+    ///     Ok(Some((3, 1)))
+    /// );
+    /// ```
+    fn min_max_by<Compare>(self, compare: Compare) -> ResultMinMax<Self::Item, Self::Err>
+    where
+        Self: Sized,
+        Self::Item: Clone + Send,
+        Self::Err: Send,
+        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
+    {
+        let par = self.into_regular_par().into_xap();
+        let (orchestrator, params, iter, x1) = par.into_xap_parts();
+        let x1 = |(idx, i)| {
+            x1(i).map_while_ok(move |x| IntoResult::into_result(x).map(|x| min_max_unit((idx, x))))
+        };
+        let compare = |a: &_, b: &_| compare_with_idx(&compare, a, b);
+        let reduce = |a, b| min_max_reduce(&compare, a, b);
+        let (_, result) = prc::reduce::x(orchestrator, params, iter.enumerate(), x1, reduce);
+        result.map(|x| x.map(min_max_into_pair_without_idx))
+    }
+
+    /// Returns the elements that give the minimum and maximum values from the specified function,
+    /// computed in a single pass.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    /// Among the equal elements, the first one is returned as the minimum and the last one as the maximum,
+    /// as in [`Iterator::min_by`] and [`Iterator::max_by`], regardless of the number of threads.
+    /// Early exits and returns the error if any of the elements is an Err.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(-1), Ok(2), Ok(-3)];
+    /// assert_eq!(
+    ///     a.par()
+    ///         .copied()
+    ///         .into_fallible_result()
+    ///         .min_max_by_key(|x| x.abs()),
+    ///     Ok(Some((-1, -3)))
+    /// );
+    /// ```
+    fn min_max_by_key<Key, GetKey>(self, get_key: GetKey) -> ResultMinMax<Self::Item, Self::Err>
+    where
+        Self: Sized,
+        Self::Item: Clone + Send,
+        Self::Err: Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        self.min_max_by(|x, y| get_key(x).cmp(&get_key(y)))
+    }
+
     /// Sums the elements of an iterator.
     /// Early exits and returns the error if any of the elements is an Err.
     ///
@@ -1243,6 +1396,184 @@ where
     {
         self.filter(&predicate).first()
     }
+
+    /// Returns the last (or any) element of the iterator.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    /// Early exits and returns the error if an Err element is observed first.
+    ///
+    /// * last element is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * any element is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Chunks pulled by the threads are scanned from their back, and the elements positioned before
+    /// an already found element are not evaluated.
+    /// Therefore, in case the fallible iterator contains both an Err and an Ok element,
+    /// the result is **not deterministic**:
+    /// * it might be the `Err` if it is observed first;
+    /// * or `Ok(element)` if an Ok element after it is observed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![];
+    /// assert_eq!(a.par().copied().into_fallible_result().last(), Ok(None));
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(1), Ok(2), Ok(3)];
+    /// assert_eq!(a.par().copied().into_fallible_result().last(), Ok(Some(3)));
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(1), Err('x'), Ok(3)];
+    /// let result = a.par().copied().into_fallible_result().last();
+    /// // depends on whichever is observed first in parallel execution
+    /// assert!(result == Ok(Some(3)) || result == Err('x'));
+    /// ```
+    fn last(self) -> Result<Option<Self::Item>, Self::Err>
+    where
+        Self: Sized,
+        Self::Item: Send,
+        Self::Err: Send,
+    {
+        let par = self.into_regular_par().into_xap();
+        let (orchestrator, params, iter, x1) = par.into_xap_parts();
+        let x1 = |i| x1(i).map_while_ok(IntoResult::into_result);
+        match params.iteration_order {
+            IterationOrder::Ordered => {
+                let (_, result) = prc::last::x(orchestrator, params, iter, x1);
+                result.map(|x| x.map(|y| y.1))
+            }
+            IterationOrder::Arbitrary => {
+                let (_, result) = prc::next_any::x(orchestrator, params, iter, x1);
+                result
+            }
+        }
+    }
+
+    /// Returns the last (or any) element of the iterator that satisfies the `predicate`.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    /// Early exits and returns the error if an Err element is observed first.
+    ///
+    /// * last element is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * any element is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Note that `find_last` itself is short-circuiting in addition to fallible computation.
+    /// Therefore, in case the fallible iterator contains both an Err and an Ok element,
+    /// the result is **not deterministic**:
+    /// * it might be the `Err` if it is observed first;
+    /// * or `Ok(element)` if an Ok element satisfying the predicate after it is observed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(1), Ok(2), Ok(3)];
+    /// assert_eq!(
+    ///     a.par().copied().into_fallible_result().find_last(|x| *x < 3),
+    ///     Ok(Some(2))
+    /// );
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(1), Err('x'), Ok(3)];
+    /// let result = a.par().copied().into_fallible_result().find_last(|x| *x > 2);
+    /// // depends on whichever is observed first in parallel execution
+    /// assert!(result == Ok(Some(3)) || result == Err('x'));
+    /// ```
+    fn find_last<Predicate>(self, predicate: Predicate) -> Result<Option<Self::Item>, Self::Err>
+    where
+        Self: Sized,
+        Self::Item: Send,
+        Self::Err: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        self.filter(&predicate).last()
+    }
+
+    /// Searches for an element satisfying the `predicate` and returns its index among the
+    /// elements yielded by the iterator.
+    /// If there is no such element, `Ok(None)` is returned.
+    /// Early exits and returns the error if an Err element is observed first.
+    ///
+    /// * index of the first element satisfying the `predicate` is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * index of any element satisfying the `predicate` is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Note that `position` itself is short-circuiting in addition to fallible computation.
+    /// Therefore, in case the fallible iterator contains both an Err and an Ok element,
+    /// the result is **not deterministic**:
+    /// * it might be the `Err` if it is observed first;
+    /// * or `Ok(index)` if the Ok element satisfying the predicate is observed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(1), Ok(2), Ok(3)];
+    /// assert_eq!(
+    ///     a.par().copied().into_fallible_result().position(|x| *x > 1),
+    ///     Ok(Some(1))
+    /// );
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(1), Err('x'), Ok(3)];
+    /// let result = a.par().copied().into_fallible_result().position(|x| *x < 2);
+    /// // depends on whichever is observed first in parallel execution
+    /// assert!(result == Ok(Some(0)) || result == Err('x'));
+    /// ```
+    fn position<Predicate>(self, predicate: Predicate) -> Result<Option<usize>, Self::Err>
+    where
+        Self: Sized,
+        Self::Err: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let par = self.into_regular_par().into_xap();
+        let (orchestrator, params, iter, x1) = par.into_xap_parts();
+        let segment = |x: Self::Item| PositionSegment::new(predicate(&x));
+        let x1 = |i| {
+            let values = x1(i).map(|x: Self::RegularItem| x.into_result().map(segment));
+            values.map_while_ok(|x| x)
+        };
+        prc::position::x(orchestrator, params, iter, x1).1
+    }
+
+    /// Searches for an element satisfying the `predicate` from the back and returns its index
+    /// among the elements yielded by the iterator, counted from the front.
+    /// If there is no such element, `Ok(None)` is returned.
+    /// Early exits and returns the error if an Err element is observed.
+    ///
+    /// * index of the last element satisfying the `predicate` is returned if default iteration order `IterationOrder::Ordered` is used,
+    /// * index of any element satisfying the `predicate` is returned if `IterationOrder::Arbitrary` is set.
+    ///
+    /// Since the index is counted from the front, all elements are evaluated unless an Err element
+    /// is observed; hence, the result is the error whenever the iterator contains an Err element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(1), Ok(2), Ok(3)];
+    /// assert_eq!(
+    ///     a.par().copied().into_fallible_result().rposition(|x| *x < 3),
+    ///     Ok(Some(1))
+    /// );
+    ///
+    /// let a: Vec<Result<i32, char>> = vec![Ok(1), Err('x'), Ok(3)];
+    /// let result = a.par().copied().into_fallible_result().rposition(|x| *x > 2);
+    /// assert_eq!(result, Err('x'));
+    /// ```
+    fn rposition<Predicate>(self, predicate: Predicate) -> Result<Option<usize>, Self::Err>
+    where
+        Self: Sized,
+        Self::Err: Send,
+        Predicate: Fn(&Self::Item) -> bool + Sync,
+    {
+        let par = self.into_regular_par().into_xap();
+        let (orchestrator, params, iter, x1) = par.into_xap_parts();
+        let segment = |x: Self::Item| PositionSegment::new(predicate(&x));
+        let x1 = |i| {
+            let values = x1(i).map(|x: Self::RegularItem| x.into_result().map(segment));
+            values.map_while_ok(|x| x)
+        };
+        prc::position::x_rev(orchestrator, params, iter, x1).1
+    }
 }
 
 pub trait IntoResult<T, E> {
@@ -0,0 +1,194 @@
+use crate::runner::ParallelRunner;
+use crate::{NumThreads, ParIter, ParallelizableCollection};
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Raw pointer to the elements of a slice or a buffer shared among the threads of a sort.
+///
+/// Threads only access disjoint ranges of the elements through the pointer.
+pub struct SyncPtr<T>(pub *mut T);
+
+unsafe impl<T: Send> Sync for SyncPtr<T> {}
+
+/// Merge of two adjacent sorted ranges of a slice into the same range of a buffer.
+///
+/// A merge of two runs is split into multiple merge jobs such that they can be completed by different
+/// threads; `dst` is the position of the first merged element in the buffer.
+pub struct Merge {
+    pub left: Range<usize>,
+    pub right: Range<usize>,
+    pub dst: usize,
+}
+
+impl Merge {
+    fn len(&self) -> usize {
+        self.left.len() + self.right.len()
+    }
+
+    /// Stably merges the `left` and `right` ranges of the `src` into the `dst` by bitwise copies.
+    ///
+    /// # SAFETY
+    ///
+    /// * `src` must be valid for reads of both ranges, and `dst` must be valid for writes
+    ///   of `self.len()` elements starting at `self.dst`.
+    /// * No other thread can write to these ranges during the merge.
+    ///
+    /// The elements of `src` are not modified; hence, the `src` is still the owner of the elements
+    /// if the merge panics due to `is_less`.
+    pub unsafe fn merge<T, F>(&self, src: &SyncPtr<T>, dst: &SyncPtr<T>, is_less: &F)
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        // SAFETY: ranges are within the bounds of the src and not written by others
+        let left =
+            unsafe { core::slice::from_raw_parts(src.0.add(self.left.start), self.left.len()) };
+        let right =
+            unsafe { core::slice::from_raw_parts(src.0.add(self.right.start), self.right.len()) };
+        let dst = unsafe { dst.0.add(self.dst) };
+
+        let (mut i, mut j) = (0, 0);
+        while i < left.len() && j < right.len() {
+            // takes from the left unless the right one is strictly less, which makes the merge stable
+            let x = match is_less(&right[j], &left[i]) {
+                true => {
+                    j += 1;
+                    &right[j - 1]
+                }
+                false => {
+                    i += 1;
+                    &left[i - 1]
+                }
+            };
+            // SAFETY: i + j - 1 < self.len() positions are written so far
+            unsafe { core::ptr::copy_nonoverlapping(x, dst.add(i + j - 1), 1) };
+        }
+
+        // SAFETY: remaining elements of only one of the ranges are copied to the end
+        unsafe {
+            let rest = dst.add(i + j);
+            core::ptr::copy_nonoverlapping(left.as_ptr().add(i), rest, left.len() - i);
+            let rest = rest.add(left.len() - i);
+            core::ptr::copy_nonoverlapping(right.as_ptr().add(j), rest, right.len() - j);
+        }
+    }
+
+    /// Moves the merged elements from the `buffer` back to the same range of the `slice`.
+    ///
+    /// # SAFETY
+    ///
+    /// The merge must have been completed; i.e., the range of the buffer holds a permutation
+    /// of the elements of the same range of the slice.
+    pub unsafe fn copy_back<T>(&self, buffer: &SyncPtr<T>, slice: &SyncPtr<T>) {
+        // SAFETY: buffer and slice are different allocations and the range is within both
+        unsafe {
+            let src = buffer.0.add(self.dst);
+            core::ptr::copy_nonoverlapping(src, slice.0.add(self.dst), self.len());
+        }
+    }
+}
+
+/// Creates the merge jobs to merge each pair of adjacent sorted runs of the `slice`, where `runs`
+/// are the boundaries of the runs starting at 0 and ending at the length of the slice.
+///
+/// Each pair is split into jobs of approximately `job_len` elements. The split positions are
+/// determined by pivots picked from the left run, and elements of the right run which are strictly
+/// less than the pivot are merged before it, so that the merge remains stable.
+///
+/// Returns the merge jobs together with the boundaries of the runs after the merges.
+fn merge_jobs<T, F>(
+    slice: &[T],
+    runs: &[usize],
+    job_len: usize,
+    is_less: &F,
+) -> (Vec<Merge>, Vec<usize>)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let num_runs = runs.len() - 1;
+    let mut jobs = Vec::new();
+    let mut merged_runs = Vec::with_capacity(num_runs / 2 + 2);
+
+    for r in (0..num_runs).step_by(2) {
+        let begin = runs[r];
+        merged_runs.push(begin);
+        let (mid, end) = match runs.get(r + 2) {
+            Some(&end) => (runs[r + 1], end),
+            None => continue, // last run without a pair
+        };
+
+        let num_jobs = (end - begin).div_ceil(job_len).max(1);
+        let (mut a, mut b) = (begin, mid);
+        for p in 1..=num_jobs {
+            let (next_a, next_b) = match p == num_jobs {
+                true => (mid, end),
+                false => {
+                    let next_a = begin + p * (mid - begin) / num_jobs;
+                    let pivot = &slice[next_a];
+                    let next_b = mid + slice[mid..end].partition_point(|x| is_less(x, pivot));
+                    (next_a, next_b.max(b))
+                }
+            };
+            jobs.push(Merge {
+                left: a..next_a,
+                right: b..next_b,
+                dst: a + (b - mid),
+            });
+            (a, b) = (next_a, next_b);
+        }
+    }
+    merged_runs.push(runs[num_runs]);
+
+    (jobs, merged_runs)
+}
+
+/// Merges the adjacent sorted runs of the `slice` in rounds until the entire slice is sorted.
+///
+/// `runs` are the boundaries of the sorted runs starting at 0 and ending at the length of the slice.
+/// Merges of each round are split into jobs of approximately `job_len` elements which are distributed
+/// to the threads one at a time.
+pub fn merge_runs<T, R, F>(
+    slice: &mut [T],
+    mut runs: Vec<usize>,
+    job_len: usize,
+    runner: &mut R,
+    num_threads: NumThreads,
+    is_less: &F,
+) where
+    T: Send,
+    R: ParallelRunner,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    let mut buffer = Vec::<T>::with_capacity(slice.len());
+
+    while runs.len() > 2 {
+        let (jobs, merged_runs) = merge_jobs(slice, &runs, job_len, is_less);
+        let src = SyncPtr(slice.as_mut_ptr());
+        let dst = SyncPtr(buffer.as_mut_ptr());
+
+        let num_merged = AtomicUsize::new(0);
+        jobs.par()
+            .with_runner(&mut *runner)
+            .num_threads(num_threads)
+            .chunk_size(1)
+            .for_each(|job| {
+                // SAFETY: jobs read disjoint ranges of the slice and write to disjoint ranges of the buffer
+                unsafe { job.merge(&src, &dst, is_less) };
+                _ = num_merged.fetch_add(1, Ordering::Relaxed);
+            });
+
+        // the slice remains the owner of the elements unless all merges are completed
+        if num_merged.into_inner() < jobs.len() {
+            return;
+        }
+
+        jobs.par()
+            .with_runner(&mut *runner)
+            .num_threads(num_threads)
+            .chunk_size(1)
+            // SAFETY: all merges are completed, and jobs write to disjoint ranges of the slice
+            .for_each(|job| unsafe { job.copy_back(&dst, &src) });
+
+        runs = merged_runs;
+    }
+}
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests;
+
+mod merge;
+mod par_sorter;
+mod parallel_sort;
+
+pub use par_sorter::ParSorter;
+
+pub(crate) use merge::merge_runs;
+pub use parallel_sort::ParallelSort;
@@ -0,0 +1,268 @@
+use super::merge::merge_runs;
+use crate::runner::{DefaultRunner, ParallelRunner};
+use crate::{ChunkSize, IntoParIter, NumThreads, ParIter, ParThreadPool, Params, RunnerWithPool};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Runs shorter than this length are not sorted in parallel unless the chunk size is set explicitly.
+const MIN_RUN_LEN: usize = 1 << 10;
+
+/// A parallel sort of a slice which can be configured by the number of threads, chunk size,
+/// runner or thread pool in the same way as parallel iterators.
+///
+/// It is created by the [`par_sorter`] method of the [`ParallelSort`] extension trait.
+///
+/// The sort is a parallel merge sort:
+///
+/// * The slice is split into runs which are sorted by the threads using the sequential sort of the standard library.
+/// * Then, in rounds, each pair of adjacent sorted runs is merged. Each merge is split into parts
+///   of approximately the run length so that it can be shared by multiple threads.
+///
+/// Length of the runs is determined by the chunk size:
+///
+/// * `ChunkSize::Auto` splits the slice into as many runs as the maximum number of threads
+///   that can be used for the computation, while each run has at least 1024 elements,
+/// * `ChunkSize::Exact(c)` splits the slice into runs of length `c`,
+/// * `ChunkSize::Min(c)` makes sure that the runs determined by `ChunkSize::Auto` have at least `c` elements.
+///
+/// The slice is sorted sequentially if the computation is allowed to use only one thread, such as when
+/// `num_threads` is set to 1 or when the [`SequentialPool`] is used; or if the slice fits into a single run.
+///
+/// The merges require a buffer with the same length as the slice.
+///
+/// [`par_sorter`]: crate::ParallelSort::par_sorter
+/// [`ParallelSort`]: crate::ParallelSort
+/// [`SequentialPool`]: crate::SequentialPool
+pub struct ParSorter<'a, T, R = DefaultRunner>
+where
+    T: Send,
+    R: ParallelRunner,
+{
+    slice: &'a mut [T],
+    runner: R,
+    params: Params,
+}
+
+impl<'a, T: Send> ParSorter<'a, T> {
+    pub(crate) fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            slice,
+            runner: DefaultRunner::default(),
+            params: Params::default(),
+        }
+    }
+}
+
+impl<'a, T, R> ParSorter<'a, T, R>
+where
+    T: Send,
+    R: ParallelRunner,
+{
+    /// Sets the number of threads to be used in the sort.
+    ///
+    /// See [`ParIter::num_threads`] for details.
+    pub fn num_threads(mut self, num_threads: impl Into<NumThreads>) -> Self {
+        self.params = self.params.with_num_threads(num_threads);
+        self
+    }
+
+    /// Sets the chunk size which determines the length of the runs sorted by the threads.
+    ///
+    /// See [`ParSorter`] for details.
+    pub fn chunk_size(mut self, chunk_size: impl Into<ChunkSize>) -> Self {
+        self.params = self.params.with_chunk_size(chunk_size);
+        self
+    }
+
+    /// Sets the parallel runner to be used in the sort.
+    ///
+    /// See [`ParIter::with_runner`] for details.
+    pub fn with_runner<Q: ParallelRunner>(self, runner: Q) -> ParSorter<'a, T, Q> {
+        ParSorter {
+            slice: self.slice,
+            runner,
+            params: self.params,
+        }
+    }
+
+    /// Sets the thread pool to be used in the sort.
+    ///
+    /// See [`ParIter::with_pool`] for details.
+    pub fn with_pool<P: ParThreadPool>(
+        self,
+        pool: P,
+    ) -> ParSorter<'a, T, RunnerWithPool<P, R::Executor>> {
+        let runner = RunnerWithPool::from(pool).with_executor::<R::Executor>();
+        self.with_runner(runner)
+    }
+
+    /// Sorts the slice, preserving the initial order of equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut vec = vec![5, 4, 1, 3, 2];
+    /// vec.par_sorter().sort();
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort(self)
+    where
+        T: Ord,
+    {
+        self.sort_with(&T::cmp, true)
+    }
+
+    /// Sorts the slice with the `compare` function, preserving the initial order of equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut vec = vec![5, 4, 1, 3, 2];
+    /// vec.par_sorter().sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, vec![5, 4, 3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        self.sort_with(&compare, true)
+    }
+
+    /// Sorts the slice with the key extraction function, preserving the initial order of equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut vec = vec![-5i32, 4, 1, -3, 2];
+    /// vec.par_sorter().sort_by_key(|x| x.abs());
+    /// assert_eq!(vec, vec![1, 2, -3, 4, -5]);
+    /// ```
+    pub fn sort_by_key<K, F>(self, key: F)
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Sync,
+    {
+        self.sort_with(&|a, b| key(a).cmp(&key(b)), true)
+    }
+
+    /// Sorts the slice, without preserving the initial order of equal elements.
+    ///
+    /// Runs are sorted by the unstable sort of the standard library, which is typically faster.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut vec = vec![5, 4, 1, 3, 2];
+    /// vec.par_sorter().sort_unstable();
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort_unstable(self)
+    where
+        T: Ord,
+    {
+        self.sort_with(&T::cmp, false)
+    }
+
+    /// Sorts the slice with the `compare` function, without preserving the initial order of equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut vec = vec![5, 4, 1, 3, 2];
+    /// vec.par_sorter().sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, vec![5, 4, 3, 2, 1]);
+    /// ```
+    pub fn sort_unstable_by<F>(self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        self.sort_with(&compare, false)
+    }
+
+    /// Sorts the slice with the key extraction function, without preserving the initial order of equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut vec = vec![-5i32, 4, 1, -3, 2];
+    /// vec.par_sorter().sort_unstable_by_key(|x| x.abs());
+    /// assert_eq!(vec, vec![1, 2, -3, 4, -5]);
+    /// ```
+    pub fn sort_unstable_by_key<K, F>(self, key: F)
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Sync,
+    {
+        self.sort_with(&|a, b| key(a).cmp(&key(b)), false)
+    }
+
+    // helpers
+
+    fn run_len(&self, max_num_threads: usize) -> usize {
+        let len = self.slice.len();
+        let auto = len.div_ceil(max_num_threads).max(MIN_RUN_LEN);
+        match self.params.chunk_size {
+            ChunkSize::Auto => auto,
+            ChunkSize::Exact(c) => c.get(),
+            ChunkSize::Min(c) => auto.max(c.get()),
+        }
+    }
+
+    fn sort_with<F>(self, compare: &F, stable: bool)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let sort_seq = |run: &mut [T]| match stable {
+            true => run.sort_by(compare),
+            false => run.sort_unstable_by(compare),
+        };
+        let is_less = &|a: &T, b: &T| compare(a, b) == Ordering::Less;
+
+        let len = self.slice.len();
+        let max_num_threads = self
+            .runner
+            .max_num_threads_for_computation(self.params, Some(len))
+            .get();
+        let run_len = self.run_len(max_num_threads);
+
+        let Self {
+            slice,
+            mut runner,
+            params,
+        } = self;
+
+        if max_num_threads == 1 || run_len >= len {
+            sort_seq(slice);
+            return;
+        }
+
+        let runs: Vec<_> = slice.chunks_mut(run_len).collect();
+        runs.into_par()
+            .with_runner(&mut runner)
+            .num_threads(params.num_threads)
+            .chunk_size(1)
+            .for_each(sort_seq);
+
+        let runs = (0..len).step_by(run_len).chain([len]).collect();
+        merge_runs(
+            slice,
+            runs,
+            run_len,
+            &mut runner,
+            params.num_threads,
+            is_less,
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use super::par_sorter::ParSorter;
+use core::cmp::Ordering;
+
+/// Extension trait to sort slices, and hence vectors, in parallel.
+///
+/// The `par_sort` methods use the default runner and parameters. In order to configure the computation,
+/// [`par_sorter`] can be used to create a [`ParSorter`], which can be configured by the number of threads,
+/// chunk size, runner or thread pool in the same way as parallel iterators.
+///
+/// [`par_sorter`]: crate::ParallelSort::par_sorter
+///
+/// # Examples
+///
+/// ```
+/// use orx_parallel::*;
+///
+/// let mut vec: Vec<_> = (0..10_000).map(|x| (x * 7919) % 10_000).collect();
+/// vec.par_sort();
+/// assert!(vec.iter().enumerate().all(|(i, x)| i == *x));
+///
+/// let mut vec: Vec<_> = (0..10_000).map(|x| (x * 7919) % 10_000).collect();
+/// vec.par_sorter().num_threads(2).sort_by_key(|x| core::cmp::Reverse(*x));
+/// assert!(vec.iter().rev().enumerate().all(|(i, x)| i == *x));
+/// ```
+pub trait ParallelSort<T: Send> {
+    /// Creates a [`ParSorter`] to sort the elements in parallel, which can be configured
+    /// before calling one of its sort methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    ///
+    /// let mut vec = vec![3, 1, 2];
+    /// vec.par_sorter()
+    ///     .num_threads(4)
+    ///     .chunk_size(1024)
+    ///     .with_runner(DefaultRunner::default())
+    ///     .sort();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    fn par_sorter(&mut self) -> ParSorter<'_, T>;
+
+    /// Sorts the slice in parallel, preserving the initial order of equal elements.
+    ///
+    /// See [`ParSorter::sort`] for details.
+    fn par_sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.par_sorter().sort()
+    }
+
+    /// Sorts the slice in parallel with the `compare` function, preserving the initial order of equal elements.
+    ///
+    /// See [`ParSorter::sort_by`] for details.
+    fn par_sort_by<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        self.par_sorter().sort_by(compare)
+    }
+
+    /// Sorts the slice in parallel with the key extraction function, preserving the initial order of equal elements.
+    ///
+    /// See [`ParSorter::sort_by_key`] for details.
+    fn par_sort_by_key<K, F>(&mut self, key: F)
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Sync,
+    {
+        self.par_sorter().sort_by_key(key)
+    }
+
+    /// Sorts the slice in parallel, without preserving the initial order of equal elements.
+    ///
+    /// See [`ParSorter::sort_unstable`] for details.
+    fn par_sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.par_sorter().sort_unstable()
+    }
+
+    /// Sorts the slice in parallel with the `compare` function, without preserving the initial order of equal elements.
+    ///
+    /// See [`ParSorter::sort_unstable_by`] for details.
+    fn par_sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        self.par_sorter().sort_unstable_by(compare)
+    }
+
+    /// Sorts the slice in parallel with the key extraction function, without preserving the initial order of equal elements.
+    ///
+    /// See [`ParSorter::sort_unstable_by_key`] for details.
+    fn par_sort_unstable_by_key<K, F>(&mut self, key: F)
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Sync,
+    {
+        self.par_sorter().sort_unstable_by_key(key)
+    }
+}
+
+impl<T: Send> ParallelSort<T> for [T] {
+    fn par_sorter(&mut self) -> ParSorter<'_, T> {
+        ParSorter::new(self)
+    }
+}
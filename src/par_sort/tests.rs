@@ -0,0 +1,139 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use test_case::test_matrix;
+
+fn input(n: usize) -> Vec<usize> {
+    (0..n).map(|x| (x * 7919 + 13) % (n / 3 + 1)).collect()
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_sort_stable(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let mut expected = input(n);
+        expected.sort();
+
+        let mut vec = input(n);
+        vec.par_sorter().num_threads(nt).chunk_size(chunk).sort();
+        assert_eq!(vec, expected);
+
+        // pairs of (key, position) reveal whether the order of equal keys is preserved
+        let pairs: Vec<_> = input(n)
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| (x, i))
+            .collect();
+        let mut expected = pairs.clone();
+        expected.sort_by_key(|x| Reverse(x.0));
+
+        let mut vec = pairs.clone();
+        vec.par_sorter()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .sort_by_key(|x| Reverse(x.0));
+        assert_eq!(vec, expected);
+
+        let mut vec = pairs;
+        vec.par_sorter()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .sort_by(|a, b| b.0.cmp(&a.0));
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_sort_unstable(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let mut expected = input(n);
+        expected.sort_unstable();
+
+        let mut vec = input(n);
+        vec.par_sorter()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .sort_unstable();
+        assert_eq!(vec, expected);
+
+        let mut vec = input(n);
+        vec.par_sorter()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .sort_unstable_by(|a, b| b.cmp(a));
+        expected.reverse();
+        assert_eq!(vec, expected);
+
+        let mut vec = input(n);
+        vec.par_sorter()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .sort_unstable_by_key(|x| Reverse(*x));
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn par_sort_owned(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let strings = || -> Vec<String> { input(n).into_iter().map(|x| x.to_string()).collect() };
+        let mut expected = strings();
+        expected.sort();
+
+        let mut vec = strings();
+        vec.par_sorter().num_threads(nt).chunk_size(chunk).sort();
+        assert_eq!(vec, expected);
+
+        let mut vec = strings();
+        vec.as_mut_slice()
+            .par_sorter()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .sort_unstable();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test]
+fn par_sort_defaults() {
+    let n = 100_000;
+    let mut expected = input(n);
+    expected.sort();
+
+    let mut vec = input(n);
+    vec.par_sort();
+    assert_eq!(vec, expected);
+
+    let mut vec = input(n);
+    vec.par_sort_unstable();
+    assert_eq!(vec, expected);
+
+    let mut vec = input(n);
+    vec.par_sort_by(|a, b| a.cmp(b));
+    assert_eq!(vec, expected);
+
+    let mut vec = input(n);
+    vec.par_sort_by_key(|x| *x);
+    assert_eq!(vec, expected);
+
+    let mut vec = input(n);
+    vec.par_sort_unstable_by(|a, b| a.cmp(b));
+    assert_eq!(vec, expected);
+
+    let mut vec = input(n);
+    vec.par_sort_unstable_by_key(|x| *x);
+    assert_eq!(vec, expected);
+
+    let mut vec = input(n);
+    vec.par_sorter().with_pool(SequentialPool).sort();
+    assert_eq!(vec, expected);
+
+    let mut vec = input(n);
+    vec[1000..2000].par_sorter().chunk_size(7).sort_unstable();
+    expected = input(n);
+    expected[1000..2000].sort();
+    assert_eq!(vec, expected);
+}
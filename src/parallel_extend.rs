@@ -0,0 +1,77 @@
+use crate::{ParCollectTarget, ParIter, ParallelRunner};
+use orx_concurrent_iter::ConcurrentIter;
+
+/// A collection which can be extended with the elements of a parallel iterator.
+///
+/// Unlike [`collect_into`] which takes the collection by value, `par_extend` appends the elements
+/// to a mutably borrowed collection which can be used afterwards.
+///
+/// It is implemented for all collections implementing [`ParCollectTarget`], such as `Vec`, `SplitVec`,
+/// `FixedVec`, `String`, `VecDeque`, `LinkedList`, `BinaryHeap`, `HashMap` and `BTreeSet`.
+/// When the length of the input is known, capacity for that many elements is reserved on the collection
+/// before the computation, and the elements are then collected directly into the collection.
+///
+/// Note that the collection is taken out of the mutable reference during the computation; hence, it is left
+/// empty if the parallel computation panics.
+///
+/// Depending on the set iteration order of the parallel iterator, the elements are appended
+///
+/// * in the order of the input if default iteration order `IterationOrder::Ordered` is used,
+/// * in an arbitrary order if `IterationOrder::Arbitrary` is set.
+///
+/// [`collect_into`]: crate::ParIter::collect_into
+/// [`ParCollectTarget`]: crate::ParCollectTarget
+///
+/// # Examples
+///
+/// ```
+/// use orx_parallel::*;
+/// use std::collections::VecDeque;
+///
+/// let mut vec = vec![0, 1];
+/// vec.par_extend((2..5).par());
+/// vec.par_extend((5..10).par().filter(|x| x % 2 == 1));
+/// assert_eq!(vec, vec![0, 1, 2, 3, 4, 5, 7, 9]);
+///
+/// let mut deque = VecDeque::from([3, 4]);
+/// deque.push_front(2);
+/// deque.par_extend(vec![5, 6, 7].into_par().map(|x| x * 10));
+/// assert_eq!(deque, [2, 3, 4, 50, 60, 70]);
+/// ```
+pub trait ParallelExtend<T> {
+    /// Appends all elements of the parallel iterator `par_iter` to this collection.
+    ///
+    /// See [`ParallelExtend`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::from([(0, 'x')]);
+    /// map.par_extend(vec!['a', 'b', 'c'].into_par().enumerate().map(|(i, c)| (i + 1, c)));
+    /// assert_eq!(map, HashMap::from([(0, 'x'), (1, 'a'), (2, 'b'), (3, 'c')]));
+    /// ```
+    fn par_extend<R, I>(&mut self, par_iter: I)
+    where
+        R: ParallelRunner,
+        I: ParIter<R, Item = T>;
+}
+
+impl<T, C> ParallelExtend<T> for C
+where
+    C: ParCollectTarget<T>,
+{
+    fn par_extend<R, I>(&mut self, par_iter: I)
+    where
+        R: ParallelRunner,
+        I: ParIter<R, Item = T>,
+    {
+        if let Some(len) = par_iter.con_iter().try_get_len() {
+            self.reserve(len);
+        }
+        let collection = core::mem::replace(self, C::empty(None));
+        *self = par_iter.collect_into(collection);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{ParIter, ParallelRunner};
+use orx_concurrent_bag::ConcurrentBag;
+#[cfg(feature = "orx-concurrent-vec")]
+use orx_concurrent_vec::{ConcurrentElement, ConcurrentVec};
+use orx_pinned_vec::IntoConcurrentPinnedVec;
+
+/// A concurrent collection which can be extended with the elements of parallel iterators through a
+/// shared reference.
+///
+/// Since the collection is not required to be borrowed mutably, several parallel computations,
+/// which might run successively or at the same time, can push their results to the same collection.
+///
+/// Elements are pushed in an arbitrary order as they are computed, regardless of the iteration order
+/// of the parallel iterator.
+///
+/// The trait is implemented for [`ConcurrentBag`], and for `ConcurrentVec` when the `orx-concurrent-vec`
+/// feature, which is enabled by default, is enabled.
+///
+/// # Examples
+///
+/// ```
+/// use orx_parallel::*;
+/// use orx_concurrent_bag::ConcurrentBag;
+///
+/// let bag = ConcurrentBag::new();
+///
+/// std::thread::scope(|s| {
+///     s.spawn(|| bag.par_extend((0..100).par().map(|x| x * 2)));
+///     s.spawn(|| bag.par_extend((0..100).par().map(|x| x * 2 + 1)));
+/// });
+/// bag.par_extend((200..250).par());
+///
+/// let mut values = bag.into_inner().to_vec();
+/// values.sort();
+/// assert_eq!(values, (0..250).collect::<Vec<_>>());
+/// ```
+pub trait ParallelExtendConcurrent<T> {
+    /// Pushes all elements of the parallel iterator `par_iter` to this concurrent collection.
+    ///
+    /// Elements are pushed in an arbitrary order as they are computed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_parallel::*;
+    /// use orx_concurrent_bag::ConcurrentBag;
+    ///
+    /// let bag = ConcurrentBag::new();
+    ///
+    /// bag.par_extend(vec![1, 2, 3].into_par());
+    /// bag.par_extend((0..10).par().filter(|x| x % 3 == 0).map(|x| x * 10));
+    ///
+    /// let mut values = bag.into_inner().to_vec();
+    /// values.sort();
+    /// assert_eq!(values, vec![0, 1, 2, 3, 30, 60, 90]);
+    /// ```
+    fn par_extend<R, I>(&self, par_iter: I)
+    where
+        R: ParallelRunner,
+        I: ParIter<R, Item = T>;
+}
+
+impl<T, P> ParallelExtendConcurrent<T> for ConcurrentBag<T, P>
+where
+    T: Send,
+    P: IntoConcurrentPinnedVec<T>,
+{
+    fn par_extend<R, I>(&self, par_iter: I)
+    where
+        R: ParallelRunner,
+        I: ParIter<R, Item = T>,
+    {
+        par_iter.for_each(|x| _ = self.push(x));
+    }
+}
+
+#[cfg(feature = "orx-concurrent-vec")]
+impl<T, P> ParallelExtendConcurrent<T> for ConcurrentVec<T, P>
+where
+    T: Send + Sync,
+    P: IntoConcurrentPinnedVec<ConcurrentElement<T>>,
+{
+    fn par_extend<R, I>(&self, par_iter: I)
+    where
+        R: ParallelRunner,
+        I: ParIter<R, Item = T>,
+    {
+        par_iter.for_each(|x| _ = self.push(x));
+    }
+}
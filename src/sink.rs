@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+
+/// A destination, such as the sending half of a channel, which the elements of a parallel iterator
+/// can be sent to concurrently by multiple threads.
+///
+/// It is implemented for `std::sync::mpsc::Sender` and `std::sync::mpsc::SyncSender`, which send the elements
+/// of each batch one by one; and for [`BatchSender`] and [`SyncBatchSender`], which send each batch as a single
+/// message through a channel of vectors. It can be implemented for other channels or destinations.
+///
+/// See [`ParIter::for_each_send`] for details.
+///
+/// [`ParIter::for_each_send`]: crate::ParIter::for_each_send
+///
+/// # Examples
+///
+/// ```
+/// use orx_parallel::*;
+/// use std::sync::Mutex;
+///
+/// /// Collects the elements in batches as they are sent by the threads.
+/// struct Batches(Mutex<Vec<Vec<usize>>>);
+///
+/// impl Sink<usize> for Batches {
+///     fn send_batch(&self, batch: &mut Vec<usize>) -> bool {
+///         self.0.lock().unwrap().push(core::mem::take(batch));
+///         true
+///     }
+/// }
+///
+/// let batches = Batches(Mutex::new(vec![]));
+/// (0..1000).par().chunk_size(100).for_each_send(&batches);
+///
+/// // one batch for each pulled chunk
+/// let batches = batches.0.into_inner().unwrap();
+/// assert_eq!(batches.len(), 10);
+///
+/// let mut values: Vec<_> = batches.into_iter().flatten().collect();
+/// values.sort();
+/// assert_eq!(values, (0..1000).collect::<Vec<_>>());
+/// ```
+pub trait Sink<T>: Sync {
+    /// Sends all elements of the `batch` to the sink, leaving the batch empty.
+    ///
+    /// Returns false if the sink is disconnected and does not accept any more elements; true otherwise.
+    fn send_batch(&self, batch: &mut Vec<T>) -> bool;
+}
+
+impl<T, S> Sink<T> for &S
+where
+    S: Sink<T>,
+{
+    fn send_batch(&self, batch: &mut Vec<T>) -> bool {
+        S::send_batch(self, batch)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Send> Sink<T> for std::sync::mpsc::Sender<T> {
+    fn send_batch(&self, batch: &mut Vec<T>) -> bool {
+        batch.drain(..).all(|x| self.send(x).is_ok())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Send> Sink<T> for std::sync::mpsc::SyncSender<T> {
+    fn send_batch(&self, batch: &mut Vec<T>) -> bool {
+        batch.drain(..).all(|x| self.send(x).is_ok())
+    }
+}
+
+/// A sink sending each batch as a single message through the sending half of a channel of vectors.
+///
+/// Unlike the [`Sink`] implementation of `std::sync::mpsc::Sender` which sends the elements one by one,
+/// each thread sends all elements computed from a pulled chunk at once, reducing the contention on the channel.
+///
+/// # Examples
+///
+/// ```
+/// use orx_parallel::*;
+/// use std::sync::mpsc::channel;
+///
+/// let (tx, rx) = channel();
+/// let all_sent = (0..1000).par().chunk_size(100).for_each_send(BatchSender::new(tx));
+/// assert!(all_sent);
+///
+/// let batches: Vec<Vec<usize>> = rx.iter().collect();
+/// assert_eq!(batches.len(), 10);
+///
+/// let mut values: Vec<_> = batches.into_iter().flatten().collect();
+/// values.sort();
+/// assert_eq!(values, (0..1000).collect::<Vec<_>>());
+/// ```
+#[cfg(feature = "std")]
+pub struct BatchSender<T>(std::sync::mpsc::Sender<Vec<T>>);
+
+#[cfg(feature = "std")]
+impl<T> BatchSender<T> {
+    /// Creates a sink sending the batches through the `sender`.
+    pub fn new(sender: std::sync::mpsc::Sender<Vec<T>>) -> Self {
+        Self(sender)
+    }
+
+    /// Returns the wrapped sender.
+    pub fn into_inner(self) -> std::sync::mpsc::Sender<Vec<T>> {
+        self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<std::sync::mpsc::Sender<Vec<T>>> for BatchSender<T> {
+    fn from(sender: std::sync::mpsc::Sender<Vec<T>>) -> Self {
+        Self(sender)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Send> Sink<T> for BatchSender<T> {
+    fn send_batch(&self, batch: &mut Vec<T>) -> bool {
+        self.0.send(core::mem::take(batch)).is_ok()
+    }
+}
+
+/// A sink sending each batch as a single message through the sending half of a bounded channel of vectors.
+///
+/// Unlike the [`Sink`] implementation of `std::sync::mpsc::SyncSender` which sends the elements one by one,
+/// each thread sends all elements computed from a pulled chunk at once, reducing the contention on the channel.
+/// Note that the bound of the channel then limits the number of pending batches rather than elements.
+///
+/// # Examples
+///
+/// ```
+/// use orx_parallel::*;
+/// use std::sync::mpsc::sync_channel;
+///
+/// let (tx, rx) = sync_channel(4);
+/// let consumer = std::thread::spawn(move || rx.iter().collect::<Vec<Vec<usize>>>());
+///
+/// let all_sent = (0..1000).par().chunk_size(100).for_each_send(SyncBatchSender::new(tx));
+/// assert!(all_sent);
+///
+/// let batches = consumer.join().unwrap();
+/// assert_eq!(batches.len(), 10);
+/// ```
+#[cfg(feature = "std")]
+pub struct SyncBatchSender<T>(std::sync::mpsc::SyncSender<Vec<T>>);
+
+#[cfg(feature = "std")]
+impl<T> SyncBatchSender<T> {
+    /// Creates a sink sending the batches through the `sender`.
+    pub fn new(sender: std::sync::mpsc::SyncSender<Vec<T>>) -> Self {
+        Self(sender)
+    }
+
+    /// Returns the wrapped sender.
+    pub fn into_inner(self) -> std::sync::mpsc::SyncSender<Vec<T>> {
+        self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<std::sync::mpsc::SyncSender<Vec<T>>> for SyncBatchSender<T> {
+    fn from(sender: std::sync::mpsc::SyncSender<Vec<T>>) -> Self {
+        Self(sender)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Send> Sink<T> for SyncBatchSender<T> {
+    fn send_batch(&self, batch: &mut Vec<T>) -> bool {
+        self.0.send(core::mem::take(batch)).is_ok()
+    }
+}
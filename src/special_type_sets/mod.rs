@@ -1,3 +1,5 @@
+mod stats;
 mod sum;
 
+pub use stats::{Stat, Stats};
 pub use sum::Sum;
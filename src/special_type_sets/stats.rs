@@ -0,0 +1,152 @@
+/// Number whose descriptive statistics can be computed by [`ParIter::stats`].
+///
+/// [`ParIter::stats`]: crate::ParIter::stats
+pub trait Stat {
+    /// Maps the number to the statistics of a single observation.
+    fn map(a: Self) -> Stats;
+}
+
+macro_rules! impl_stat {
+    ($($t:ty),*) => {
+        $(
+            impl Stat for $t {
+                #[inline(always)]
+                fn map(a: Self) -> Stats {
+                    Stats::single(a as f64)
+                }
+            }
+
+            impl Stat for &$t {
+                #[inline(always)]
+                fn map(a: Self) -> Stats {
+                    Stats::single(*a as f64)
+                }
+            }
+
+            impl Stat for &mut $t {
+                #[inline(always)]
+                fn map(a: Self) -> Stats {
+                    Stats::single(*a as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_stat!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Descriptive statistics of a sequence of numbers computed in a single pass.
+///
+/// The mean and variance are computed with Welford's online algorithm within each thread,
+/// and the partial states of the threads are merged with Chan's parallel algorithm.
+/// This avoids the numerical instability of computing the variance from the sum of squares.
+///
+/// # Examples
+///
+/// ```
+/// use orx_parallel::*;
+///
+/// let a = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// let stats = a.par().stats();
+///
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.sum(), 40.0);
+/// assert!((stats.mean().unwrap() - 5.0).abs() < 1e-12);
+/// assert!((stats.variance().unwrap() - 4.0).abs() < 1e-12);
+/// assert_eq!(stats.min(), Some(2.0));
+/// assert_eq!(stats.max(), Some(9.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    count: usize,
+    sum: f64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl Stats {
+    fn single(x: f64) -> Self {
+        Self {
+            count: 1,
+            sum: x,
+            mean: x,
+            m2: 0.0,
+            min: x,
+            max: x,
+        }
+    }
+
+    /// Merges statistics of two disjoint sequences into the statistics of their union.
+    pub fn merge(self, other: Self) -> Self {
+        match (self.count, other.count) {
+            (0, _) => other,
+            (_, 0) => self,
+            (n_a, n_b) => {
+                let count = n_a + n_b;
+                let (n_a, n_b, n) = (n_a as f64, n_b as f64, count as f64);
+                let delta = other.mean - self.mean;
+                Self {
+                    count,
+                    sum: self.sum + other.sum,
+                    mean: self.mean + delta * n_b / n,
+                    m2: self.m2 + other.m2 + delta * delta * n_a * n_b / n,
+                    min: self.min.min(other.min),
+                    max: self.max.max(other.max),
+                }
+            }
+        }
+    }
+
+    /// Number of observations.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Sum of the observations; zero if there is no observation.
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Mean of the observations; None if there is no observation.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Population variance of the observations; None if there is no observation.
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Sample variance of the observations with Bessel's correction;
+    /// None if there are fewer than two observations.
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Minimum of the observations; None if there is no observation.
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Maximum of the observations; None if there is no observation.
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+}
@@ -18,6 +18,11 @@ pub const NT: &[usize] = &[1, 2];
 #[cfg(miri)]
 pub const CHUNK: &[usize] = &[4];
 
+/// Pseudo-random but deterministic values with many duplicates.
+pub fn value(i: usize) -> usize {
+    (i * 7919 + 13) % 1013
+}
+
 /// Test all combinations of the settings with the `test` method.
 pub fn test_n_nt_chunk<T>(n: &[usize], nt: &[usize], chunk: &[usize], test: T)
 where
@@ -0,0 +1,94 @@
+use crate::Params;
+use crate::collect_into::ParCollectIntoCore;
+use crate::collect_into::extendable::ExtendableCollection;
+use crate::generic_values::runner_results::{Fallibility, Infallible};
+use crate::generic_values::{TransformableValues, Values};
+use crate::runner::ParallelRunner;
+use crate::using::Using;
+use crate::using::collect_into::u_par_collect_into::UParCollectIntoCore;
+use alloc::collections::{BinaryHeap, LinkedList, VecDeque};
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+use orx_split_vec::SplitVec;
+
+macro_rules! impl_u_par_collect_into_core {
+    ($collection:ty) => {
+        impl<O> UParCollectIntoCore<O> for $collection
+        where
+            $collection: ExtendableCollection<O>,
+            O: Send + Sync,
+        {
+            fn u_m_collect_into<'using, U, R, I, M1>(
+                mut self,
+                using: U,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                map1: M1,
+            ) -> Self
+            where
+                U: Using<'using>,
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                M1: Fn(&mut U::Item, I::Item) -> O + Sync,
+            {
+                let iter_len = iter.try_get_len();
+                if let Some(len) = iter_len {
+                    ExtendableCollection::reserve(&mut self, len);
+                }
+                let vec = Vec::empty(iter_len);
+                self.extend(vec.u_m_collect_into(using, orchestrator, params, iter, map1));
+                self
+            }
+
+            fn u_x_collect_into<'using, U, R, I, Vo, X1>(
+                mut self,
+                using: U,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                xap1: X1,
+            ) -> Self
+            where
+                U: Using<'using>,
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                Vo: TransformableValues<Item = O, Fallibility = Infallible>,
+                X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+            {
+                let split_vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+                self.extend(split_vec.u_x_collect_into(using, orchestrator, params, iter, xap1));
+                self
+            }
+
+            fn u_x_try_collect_into<'using, U, R, I, Vo, X1>(
+                mut self,
+                using: U,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                xap1: X1,
+            ) -> Result<Self, <Vo::Fallibility as Fallibility>::Error>
+            where
+                U: Using<'using>,
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+                Vo: Values<Item = O>,
+                Self: Sized,
+            {
+                let split_vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+                let result =
+                    split_vec.u_x_try_collect_into(using, orchestrator, params, iter, xap1);
+                result.map(|split_vec| {
+                    self.extend(split_vec);
+                    self
+                })
+            }
+        }
+    };
+}
+
+impl_u_par_collect_into_core!(VecDeque<O>);
+impl_u_par_collect_into_core!(LinkedList<O>);
+impl_u_par_collect_into_core!(BinaryHeap<O>);
@@ -0,0 +1,130 @@
+use crate::IterationOrder;
+use crate::Params;
+use crate::collect_into::mergeable::{MergeableCollection, extend_collection, merge_partials};
+use crate::generic_values::runner_results::{Fallibility, Infallible};
+use crate::generic_values::{TransformableValues, Values};
+use crate::runner::ParallelRunner;
+use crate::using::collect_into::u_par_collect_into::UParCollectIntoCore;
+use crate::using::executor::parallel_compute as prc;
+use crate::using::using_variants::Using;
+use alloc::collections::{BTreeMap, BTreeSet};
+use orx_concurrent_iter::ConcurrentIter;
+use orx_split_vec::SplitVec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Collects into the `collection` either through partial collections of threads when
+/// the order is arbitrary or sequential, or through an ordered intermediate vector otherwise.
+fn u_x_try_collect_into<'using, C, U, R, I, Vo, X1>(
+    collection: C,
+    using: U,
+    orchestrator: R,
+    params: Params,
+    iter: I,
+    xap1: X1,
+) -> Result<C, <Vo::Fallibility as Fallibility>::Error>
+where
+    C: MergeableCollection<Vo::Item>,
+    U: Using<'using>,
+    R: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values,
+    Vo::Item: Send + Sync,
+    X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+{
+    match (params.is_sequential(), params.iteration_order) {
+        (false, IterationOrder::Ordered) => {
+            let split_vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+            let result = split_vec.u_x_try_collect_into(using, orchestrator, params, iter, xap1);
+            result.map(|split_vec| extend_collection(collection, split_vec))
+        }
+        _ => {
+            let (_, result) =
+                prc::collect_partial::x(using, orchestrator, params, iter, xap1, C::default);
+            result.map(|partials| match collection.len() {
+                0 => merge_partials(partials),
+                _ => extend_collection(collection, merge_partials(partials)),
+            })
+        }
+    }
+}
+
+macro_rules! impl_u_par_collect_into_core {
+    ([$($g:ident),*] $collection:ty, $item:ty) => {
+        impl<$($g),*> UParCollectIntoCore<$item> for $collection
+        where
+            $collection: MergeableCollection<$item>,
+            $item: Send + Sync,
+        {
+            fn u_m_collect_into<'using, U, R, I, M1>(
+                self,
+                using: U,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                map1: M1,
+            ) -> Self
+            where
+                U: Using<'using>,
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                M1: Fn(&mut U::Item, I::Item) -> $item + Sync,
+            {
+                let xap1 = |u: *mut U::Item, x| {
+                    // SAFETY: u points to the using value of the thread executing this closure
+                    let u = unsafe { &mut *u };
+                    Some(map1(u, x))
+                };
+                let Ok(collection) =
+                    u_x_try_collect_into(self, using, orchestrator, params, iter, xap1);
+                collection
+            }
+
+            fn u_x_collect_into<'using, U, R, I, Vo, X1>(
+                self,
+                using: U,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                xap1: X1,
+            ) -> Self
+            where
+                U: Using<'using>,
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                Vo: TransformableValues<Item = $item, Fallibility = Infallible>,
+                X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+            {
+                let Ok(collection) =
+                    u_x_try_collect_into(self, using, orchestrator, params, iter, xap1);
+                collection
+            }
+
+            fn u_x_try_collect_into<'using, U, R, I, Vo, X1>(
+                self,
+                using: U,
+                orchestrator: R,
+                params: Params,
+                iter: I,
+                xap1: X1,
+            ) -> Result<Self, <Vo::Fallibility as Fallibility>::Error>
+            where
+                U: Using<'using>,
+                R: ParallelRunner,
+                I: ConcurrentIter,
+                X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+                Vo: Values<Item = $item>,
+                Self: Sized,
+            {
+                u_x_try_collect_into(self, using, orchestrator, params, iter, xap1)
+            }
+        }
+    };
+}
+
+impl_u_par_collect_into_core!([K, V] BTreeMap<K, V>, (K, V));
+impl_u_par_collect_into_core!([T] BTreeSet<T>, T);
+#[cfg(feature = "std")]
+impl_u_par_collect_into_core!([K, V, S] HashMap<K, V, S>, (K, V));
+#[cfg(feature = "std")]
+impl_u_par_collect_into_core!([T, S] HashSet<T, S>, T);
@@ -1,6 +1,9 @@
 pub(crate) mod collect;
+mod extendable;
 mod fixed_vec;
+mod mergeable;
 mod split_vec;
+mod string;
 mod u_par_collect_into;
 mod vec;
 
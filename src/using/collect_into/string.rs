@@ -0,0 +1,83 @@
+use crate::collect_into::string::concat_buffers;
+use crate::generic_values::runner_results::{Fallibility, Infallible};
+use crate::generic_values::{TransformableValues, Values};
+use crate::runner::ParallelRunner;
+use crate::using::collect_into::u_par_collect_into::UParCollectIntoCore;
+use crate::using::executor::parallel_compute as prc;
+use crate::using::using_variants::Using;
+use crate::{IterationOrder, Params};
+use alloc::string::String;
+use orx_concurrent_iter::ConcurrentIter;
+
+impl<O> UParCollectIntoCore<O> for String
+where
+    String: Extend<O>,
+    O: Send + Sync,
+{
+    fn u_m_collect_into<'using, U, R, I, M1>(
+        self,
+        using: U,
+        orchestrator: R,
+        params: Params,
+        iter: I,
+        map1: M1,
+    ) -> Self
+    where
+        U: Using<'using>,
+        R: ParallelRunner,
+        I: ConcurrentIter,
+        M1: Fn(&mut U::Item, I::Item) -> O + Sync,
+    {
+        let xap1 = |u: *mut U::Item, x| {
+            // SAFETY: u points to the using value of the thread executing this closure
+            let u = unsafe { &mut *u };
+            Some(map1(u, x))
+        };
+        let Ok(string) = self.u_x_try_collect_into(using, orchestrator, params, iter, xap1);
+        string
+    }
+
+    fn u_x_collect_into<'using, U, R, I, Vo, X1>(
+        self,
+        using: U,
+        orchestrator: R,
+        params: Params,
+        iter: I,
+        xap1: X1,
+    ) -> Self
+    where
+        U: Using<'using>,
+        R: ParallelRunner,
+        I: ConcurrentIter,
+        Vo: TransformableValues<Item = O, Fallibility = Infallible>,
+        X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+    {
+        let Ok(string) = self.u_x_try_collect_into(using, orchestrator, params, iter, xap1);
+        string
+    }
+
+    fn u_x_try_collect_into<'using, U, R, I, Vo, X1>(
+        self,
+        using: U,
+        orchestrator: R,
+        params: Params,
+        iter: I,
+        xap1: X1,
+    ) -> Result<Self, <Vo::Fallibility as Fallibility>::Error>
+    where
+        U: Using<'using>,
+        R: ParallelRunner,
+        I: ConcurrentIter,
+        X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+        Vo: Values<Item = O>,
+        Self: Sized,
+    {
+        let (_, result) = match (params.is_sequential(), params.iteration_order) {
+            (false, IterationOrder::Ordered) => {
+                prc::collect_runs::x(using, orchestrator, params, iter, xap1, String::new)
+            }
+            _ => prc::collect_partial::x(using, orchestrator, params, iter, xap1, String::new),
+        };
+        result.map(|buffers| concat_buffers(self, buffers))
+    }
+}
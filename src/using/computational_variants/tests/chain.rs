@@ -0,0 +1,58 @@
+use crate::{test_utils::*, *};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn u_chain_map_collect(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+        let b: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+        let left = a.par().num_threads(nt).chunk_size(chunk).using_clone(1);
+        let left = left.map(|u, x| x * *u);
+        let right = b.par().filter_map(|x| x.parse::<usize>().ok());
+        let vec: Vec<_> = left.chain(right).map(|u, x| (x * *u).to_string()).collect();
+
+        let expected: Vec<String> = (0..n).chain(0..n).map(|x| x.to_string()).collect();
+        assert_eq!(vec, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn u_chain_reduce(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+
+        let left = a.par().num_threads(nt).chunk_size(chunk).copied();
+        let left = left.using_clone(0).filter(|_, x| x % 2 == 0);
+        let right = (0..n).into_par().flat_map(|x| [x, x]);
+        let sum = left.chain(right).sum();
+
+        let expected: usize =
+            (0..n).filter(|x| x % 2 == 0).sum::<usize>() + 2 * (0..n).sum::<usize>();
+        assert_eq!(sum, expected);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn u_chain_fallible_result(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let a: Vec<_> = (0..n).collect();
+
+        let left = a.par().num_threads(nt).chunk_size(chunk).using_clone(0);
+        let left = left.map(|_, x| Ok::<_, String>(*x)).into_fallible_result();
+        let right = (0..n).into_par().map(|x| match x == n / 2 {
+            true => Err(x.to_string()),
+            false => Ok(x),
+        });
+        let vec: Result<Vec<_>, _> = left.chain(right.into_fallible_result()).collect();
+        match n {
+            0 => assert_eq!(vec, Ok(Vec::new())),
+            _ => assert_eq!(vec, Err((n / 2).to_string())),
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
@@ -0,0 +1,99 @@
+use crate::using::computational_variants::tests::utils::{make_u_filter, make_u_map};
+use crate::{test_utils::*, *};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use test_case::test_matrix;
+
+const ORDER: [IterationOrder; 2] = [IterationOrder::Ordered, IterationOrder::Arbitrary];
+
+#[test_matrix(N, NT, CHUNK)]
+fn u_collect_btree_map(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| (i, value(i).to_string());
+        let expected: BTreeMap<_, _> = input.iter().map(|i| map(*i)).collect();
+
+        for order in ORDER {
+            let output: BTreeMap<_, _> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .using_clone("XyZw".to_string())
+                .map(make_u_map(|i: &usize| map(*i)))
+                .collect();
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn u_collect_btree_set_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let flat_map = |i: usize| [value(i), value(i + n)];
+        let filter = |x: &usize| !x.is_multiple_of(5);
+        let expected: BTreeSet<_> = input
+            .iter()
+            .copied()
+            .flat_map(flat_map)
+            .filter(filter)
+            .collect();
+
+        for order in ORDER {
+            let output: BTreeSet<_> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .using_clone("XyZw".to_string())
+                .flat_map(make_u_map(|i: &usize| flat_map(*i)))
+                .filter(make_u_filter(&filter))
+                .collect();
+            assert_eq!(output, expected);
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[cfg(feature = "std")]
+#[test_matrix(N, NT, CHUNK)]
+fn u_collect_hash_map_fallible(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| (i, value(i));
+        let expected: HashMap<_, _> = input.iter().map(|i| map(*i)).collect();
+
+        for order in ORDER {
+            let output: Result<HashMap<_, _>, String> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .using_clone("XyZw".to_string())
+                .map(make_u_map(|i: &usize| Ok(map(*i))))
+                .into_fallible_result()
+                .collect();
+            assert_eq!(output, Ok(expected.clone()));
+
+            let output: Result<HashMap<_, _>, String> = input
+                .par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .iteration_order(order)
+                .using_clone("XyZw".to_string())
+                .map(make_u_map(|i: &usize| match *i == n / 2 {
+                    true => Err(i.to_string()),
+                    false => Ok(map(*i)),
+                }))
+                .into_fallible_result()
+                .collect();
+            assert_eq!(output, Err((n / 2).to_string()));
+        }
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
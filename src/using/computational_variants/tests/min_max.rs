@@ -254,3 +254,82 @@ fn min_max_xap_filter_xap(n: &[usize], nt: &[usize], chunk: &[usize]) {
     };
     test_n_nt_chunk(n, nt, chunk, test);
 }
+
+#[test_matrix(N, NT, CHUNK)]
+fn min_max_single_pass(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input = || input::<Vec<_>>(n);
+        let map = |x: String| x.parse::<usize>().expect("is a number");
+        let seq = || input().into_iter().map(&map);
+
+        let par = || {
+            input()
+                .into_par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .using_clone("XyZw".to_string())
+                .map(make_u_map(map))
+        };
+
+        assert_eq!(par().min_max(), seq().min().zip(seq().max()));
+        assert_eq!(
+            par().min_max_by(cmp),
+            seq().min_by(cmp).zip(seq().max_by(cmp))
+        );
+        assert_eq!(
+            par().min_max_by_key(key),
+            seq().min_by_key(key).zip(seq().max_by_key(key))
+        );
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn min_max_fallible(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input = || input::<Vec<_>>(n);
+        let map = |x: String| x.parse::<usize>().expect("is a number");
+        let seq = || input().into_iter().map(&map);
+        let expected = seq().min().zip(seq().max());
+
+        let par = || {
+            input()
+                .into_par()
+                .num_threads(nt)
+                .chunk_size(chunk)
+                .using_clone("XyZw".to_string())
+        };
+
+        let output = par()
+            .map(make_u_map(|x: String| x.parse::<usize>()))
+            .into_fallible_result();
+        assert_eq!(output.min_max_by(cmp), Ok(expected));
+
+        let output = par()
+            .map(make_u_map(|x: String| match x == "50" {
+                true => Err(x),
+                false => Ok(map(x)),
+            }))
+            .into_fallible_result();
+        let expected_err = match n > 40 {
+            true => Err("50".to_string()),
+            false => Ok(expected),
+        };
+        assert_eq!(output.min_max(), expected_err);
+
+        let output = par()
+            .map(make_u_map(|x: String| Some(map(x))))
+            .into_fallible_option();
+        assert_eq!(output.min_max_by_key(key), Some(expected));
+
+        let output = par()
+            .map(make_u_map(|x: String| (x != "50").then(|| map(x))))
+            .into_fallible_option();
+        let expected_none = match n > 40 {
+            true => None,
+            false => Some(expected),
+        };
+        assert_eq!(output.min_max(), expected_none);
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
@@ -1,5 +1,7 @@
 mod utils;
 
+mod chain;
+mod collect_map_set;
 mod copied;
 mod count;
 mod fallible_option;
@@ -13,6 +15,7 @@ mod map;
 mod min_max;
 mod range;
 mod slice;
+mod string;
 mod sum;
 mod vectors;
 mod xap;
@@ -0,0 +1,60 @@
+use crate::using::computational_variants::tests::utils::{make_u_filter, make_u_map};
+use crate::{test_utils::*, *};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+#[test_matrix(N, NT, CHUNK)]
+fn u_collect_string(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: &usize| format!("{};", value(*i));
+        let filter = |x: &String| !x.starts_with('3');
+        let expected: String = input.iter().map(map).filter(filter).collect();
+
+        let output: String = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .using_clone("XyZw".to_string())
+            .map(make_u_map(map))
+            .filter(make_u_filter(&filter))
+            .collect();
+        assert_eq!(output, expected);
+
+        let output: String = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .iteration_order(IterationOrder::Arbitrary)
+            .using_clone("XyZw".to_string())
+            .map(make_u_map(map))
+            .filter(make_u_filter(&filter))
+            .collect();
+        assert_eq!(output.len(), expected.len());
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
+
+#[test_matrix(N, NT, CHUNK)]
+fn u_collect_string_fallible(n: &[usize], nt: &[usize], chunk: &[usize]) {
+    let test = |n, nt, chunk| {
+        let input: Vec<_> = (0..n).collect();
+        let map = |i: usize| format!("{};", value(i));
+
+        let output: Result<String, usize> = input
+            .par()
+            .num_threads(nt)
+            .chunk_size(chunk)
+            .using_clone("XyZw".to_string())
+            .map(make_u_map(|i: &usize| match *i == n / 2 {
+                true => Err(*i),
+                false => Ok(map(*i)),
+            }))
+            .into_fallible_result()
+            .collect();
+        assert_eq!(output, Err(n / 2));
+    };
+    test_n_nt_chunk(n, nt, chunk, test);
+}
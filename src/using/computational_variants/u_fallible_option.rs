@@ -1,5 +1,5 @@
 use crate::{
-    ChunkSize, IterationOrder, NumThreads, ParCollectInto,
+    ChunkSize, IterationOrder, NumThreads, ParCollectTarget, ParIterOption,
     par_iter_option::ResultIntoOption,
     runner::{DefaultRunner, ParallelRunner},
     using::{ParIterOptionUsing, ParIterResultUsing, Using},
@@ -120,12 +120,22 @@ where
         UParOption::<U, _, _, _>::new(self.par.inspect(operation))
     }
 
+    fn chain<Q, C>(self, other: C) -> impl ParIterOptionUsing<'using, U, R, Item = Self::Item>
+    where
+        Self: Sized,
+        Q: ParallelRunner,
+        C: ParIterOption<Q, Item = Self::Item>,
+        Self::Item: Send,
+    {
+        UParOption::<U, _, _, _>::new(self.par.chain(other.into_fallible_unit_result()))
+    }
+
     // collect
 
     fn collect_into<C>(self, output: C) -> Option<C>
     where
         Self::Item: Send,
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         self.par.collect_into(output).into_option()
     }
@@ -133,7 +143,7 @@ where
     fn collect<C>(self) -> Option<C>
     where
         Self::Item: Send,
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         self.par.collect().into_option()
     }
@@ -2,7 +2,7 @@ use crate::par_iter_result::IntoResult;
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::using::executor::parallel_compute as prc;
 use crate::using::{ParIterResultUsing, UParMap, Using};
-use crate::{IterationOrder, ParCollectInto, ParIterUsing};
+use crate::{IterationOrder, ParCollectTarget, ParIterUsing};
 use core::marker::PhantomData;
 use orx_concurrent_iter::ConcurrentIter;
 
@@ -85,7 +85,7 @@ where
 
     fn collect_into<C>(self, output: C) -> Result<C, Self::Err>
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
         Self::Item: Send,
         Self::Err: Send,
     {
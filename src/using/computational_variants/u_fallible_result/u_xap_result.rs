@@ -4,7 +4,7 @@ use crate::par_iter_result::IntoResult;
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::using::executor::parallel_compute as prc;
 use crate::using::{ParIterResultUsing, UParXap, Using};
-use crate::{IterationOrder, ParCollectInto, Params};
+use crate::{IterationOrder, ParCollectTarget, Params};
 use core::marker::PhantomData;
 use orx_concurrent_iter::ConcurrentIter;
 
@@ -104,7 +104,7 @@ where
 
     fn collect_into<C>(self, output: C) -> Result<C, Self::Err>
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
         Self::Item: Send,
         Self::Err: Send,
     {
@@ -1,5 +1,10 @@
 use core::marker::PhantomData;
 
+use crate::ParIter;
+use crate::computational_variants::XapParts;
+use crate::generic_values::Either;
+use crate::iter::ConIterChain;
+
 use crate::ParIterUsing;
 use crate::generic_values::Vector;
 use crate::par_iter_result::IntoResult;
@@ -9,7 +14,7 @@ use crate::using::computational_variants::u_fallible_result::UParMapResult;
 use crate::using::computational_variants::u_xap::UParXap;
 use crate::using::executor::parallel_compute as prc;
 use crate::using::using_variants::Using;
-use crate::{ChunkSize, IterationOrder, NumThreads, ParCollectInto, Params};
+use crate::{ChunkSize, IterationOrder, NumThreads, ParCollectTarget, Params};
 use orx_concurrent_iter::ConcurrentIter;
 
 /// A parallel iterator that maps inputs.
@@ -177,6 +182,25 @@ where
         UParXap::new(using, orchestrator, params, iter, x1)
     }
 
+    fn chain<Q, C>(self, other: C) -> impl ParIterUsing<'using, U, R, Item = Self::Item>
+    where
+        Q: ParallelRunner,
+        C: ParIter<Q, Item = Self::Item>,
+    {
+        let (using, orchestrator, params, i, m1) = self.destruct();
+        let (_, _, j, x2) = other.into_xap().into_xap_parts();
+        let iter = ConIterChain::new(i, j);
+        let x1 = move |u: *mut U::Item, x: Either<_, _>| match x {
+            Either::Left(x) => {
+                // SAFETY: TODO-USING
+                let u = unsafe { &mut *u };
+                Either::Left(Some(m1(u, x)))
+            }
+            Either::Right(x) => Either::Right(x2(x)),
+        };
+        UParXap::new(using, orchestrator, params, iter, x1)
+    }
+
     fn into_fallible_result<Out, Err>(
         self,
     ) -> impl ParIterResultUsing<'using, U, R, Item = Out, Err = Err>
@@ -188,7 +212,7 @@ where
 
     fn collect_into<C>(self, output: C) -> C
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let (using, orchestrator, params, iter, m1) = self.destruct();
         output.u_m_collect_into(using, orchestrator, params, iter, m1)
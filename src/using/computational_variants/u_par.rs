@@ -1,5 +1,10 @@
 use core::marker::PhantomData;
 
+use crate::ParIter;
+use crate::computational_variants::XapParts;
+use crate::generic_values::Either;
+use crate::iter::ConIterChain;
+
 use crate::ParIterUsing;
 use crate::default_fns::u_map_self;
 use crate::generic_values::Vector;
@@ -11,7 +16,7 @@ use crate::using::computational_variants::u_map::UParMap;
 use crate::using::computational_variants::u_xap::UParXap;
 use crate::using::executor::parallel_compute as prc;
 use crate::using::using_variants::Using;
-use crate::{ChunkSize, IterationOrder, NumThreads, ParCollectInto, Params};
+use crate::{ChunkSize, IterationOrder, NumThreads, ParCollectTarget, Params};
 use orx_concurrent_iter::ConcurrentIter;
 
 /// A parallel iterator.
@@ -158,6 +163,21 @@ where
         UParXap::new(using, orchestrator, params, iter, x1)
     }
 
+    fn chain<Q, C>(self, other: C) -> impl ParIterUsing<'using, U, R, Item = Self::Item>
+    where
+        Q: ParallelRunner,
+        C: ParIter<Q, Item = Self::Item>,
+    {
+        let (using, orchestrator, params, i) = self.destruct();
+        let (_, _, j, x2) = other.into_xap().into_xap_parts();
+        let iter = ConIterChain::new(i, j);
+        let x1 = move |_: *mut U::Item, x: Either<_, _>| match x {
+            Either::Left(x) => Either::Left(Some(x)),
+            Either::Right(x) => Either::Right(x2(x)),
+        };
+        UParXap::new(using, orchestrator, params, iter, x1)
+    }
+
     fn into_fallible_result<Out, Err>(
         self,
     ) -> impl ParIterResultUsing<'using, U, R, Item = Out, Err = Err>
@@ -169,7 +189,7 @@ where
 
     fn collect_into<C>(self, output: C) -> C
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let (using, orchestrator, params, iter) = self.destruct();
         output.u_m_collect_into(using, orchestrator, params, iter, u_map_self)
@@ -1,11 +1,16 @@
 use core::marker::PhantomData;
 
+use crate::ParIter;
+use crate::computational_variants::XapParts;
+use crate::generic_values::Either;
+use crate::iter::ConIterChain;
+
 use crate::par_iter_result::IntoResult;
 use crate::using::ParIterResultUsing;
 use crate::using::computational_variants::u_fallible_result::UParXapResult;
 use crate::using::executor::parallel_compute as prc;
 use crate::{
-    ChunkSize, IterationOrder, NumThreads, ParCollectInto, ParIterUsing, Params,
+    ChunkSize, IterationOrder, NumThreads, ParCollectTarget, ParIterUsing, Params,
     generic_values::{TransformableValues, runner_results::Infallible},
     runner::{DefaultRunner, ParallelRunner},
     using::using_variants::Using,
@@ -204,6 +209,21 @@ where
         UParXap::new(using, orchestrator, params, iter, x1)
     }
 
+    fn chain<Q, C>(self, other: C) -> impl ParIterUsing<'using, U, R, Item = Self::Item>
+    where
+        Q: ParallelRunner,
+        C: ParIter<Q, Item = Self::Item>,
+    {
+        let (using, orchestrator, params, i, x1) = self.destruct();
+        let (_, _, j, x2) = other.into_xap().into_xap_parts();
+        let iter = ConIterChain::new(i, j);
+        let x1 = move |u: *mut U::Item, x: Either<_, _>| match x {
+            Either::Left(x) => Either::Left(x1(u, x)),
+            Either::Right(x) => Either::Right(x2(x)),
+        };
+        UParXap::new(using, orchestrator, params, iter, x1)
+    }
+
     fn into_fallible_result<Out, Err>(
         self,
     ) -> impl ParIterResultUsing<'using, U, R, Item = Out, Err = Err>
@@ -216,7 +236,7 @@ where
 
     fn collect_into<C>(self, output: C) -> C
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let (using, orchestrator, params, iter, x1) = self.destruct();
         output.u_x_collect_into(using, orchestrator, params, iter, x1)
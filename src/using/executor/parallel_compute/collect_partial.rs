@@ -0,0 +1,44 @@
+use crate::Params;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::Fallibility;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use crate::using::executor::thread_compute as th;
+use crate::using::using_variants::Using;
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+
+pub fn x<'using, U, C, I, Vo, X1, P, Empty>(
+    using: U,
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    empty: Empty,
+) -> (
+    NumSpawned,
+    Result<Vec<P>, <Vo::Fallibility as Fallibility>::Error>,
+)
+where
+    U: Using<'using>,
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values,
+    X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+    P: Extend<Vo::Item> + Send,
+    Empty: Fn() -> P + Sync,
+{
+    let thread_map =
+        |nt: NumSpawned, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+            let u = using.create(nt.into_inner());
+            let mut partial = empty();
+            th::collect_partial::x(u, thread_runner, iter, state, &xap1, &mut partial)
+                .into_result()
+                .map(|_| partial)
+        };
+    orchestrator.map_all::<Vo::Fallibility, _, _, _>(
+        params,
+        iter,
+        ComputationKind::Collect,
+        thread_map,
+    )
+}
@@ -0,0 +1,62 @@
+use crate::Params;
+use crate::generic_values::Values;
+use crate::generic_values::runner_results::Fallibility;
+use crate::runner::{ComputationKind, NumSpawned, ParallelRunner, SharedStateOf, ThreadRunnerOf};
+use crate::using::executor::thread_compute as th;
+use crate::using::using_variants::Using;
+use alloc::vec::Vec;
+use orx_concurrent_iter::ConcurrentIter;
+
+/// Collects the values into runs of contiguous positions built by the threads, and returns the runs
+/// sorted by their positions so that concatenating them yields the values in order.
+pub fn x<'using, U, C, I, Vo, X1, P, E>(
+    using: U,
+    mut orchestrator: C,
+    params: Params,
+    iter: I,
+    xap1: X1,
+    empty: E,
+) -> (
+    NumSpawned,
+    Result<Vec<P>, <Vo::Fallibility as Fallibility>::Error>,
+)
+where
+    U: Using<'using>,
+    C: ParallelRunner,
+    I: ConcurrentIter,
+    Vo: Values,
+    X1: Fn(*mut U::Item, I::Item) -> Vo + Sync,
+    P: Extend<Vo::Item> + Send,
+    E: Fn() -> P + Sync,
+{
+    let thread_map =
+        |nt: NumSpawned, iter: &I, state: &SharedStateOf<C>, thread_runner: ThreadRunnerOf<C>| {
+            let u = using.create(nt.into_inner());
+            th::collect_runs::x(u, thread_runner, iter, state, &xap1, &empty)
+        };
+    let (num_spawned, result) = orchestrator.map_all::<Vo::Fallibility, _, _, _>(
+        params,
+        iter,
+        ComputationKind::Collect,
+        thread_map,
+    );
+
+    let result = result.map(|results| {
+        let idx_bound = results
+            .iter()
+            .filter_map(|x| x.1)
+            .min()
+            .unwrap_or(usize::MAX);
+
+        // runs are disjoint ranges of positions and the earliest stop is within the run
+        // that begins at or before it, hence, runs beginning after the stop are dropped
+        let mut runs: Vec<_> = results
+            .into_iter()
+            .flat_map(|x| x.0)
+            .filter(|(begin, _)| *begin <= idx_bound)
+            .collect();
+        runs.sort_by_key(|(begin, _)| *begin);
+        runs.into_iter().map(|(_, run)| run).collect()
+    });
+    (num_spawned, result)
+}
@@ -1,5 +1,7 @@
 pub(crate) mod collect_arbitrary;
 pub(crate) mod collect_ordered;
+pub(crate) mod collect_partial;
+pub(crate) mod collect_runs;
 pub(crate) mod next;
 pub(crate) mod next_any;
 pub(crate) mod reduce;
@@ -1,5 +1,7 @@
 pub(super) mod collect_arbitrary;
 pub(super) mod collect_ordered;
+pub(super) mod collect_partial;
+pub(super) mod collect_runs;
 pub(super) mod next;
 pub(super) mod next_any;
 pub(super) mod reduce;
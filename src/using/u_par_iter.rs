@@ -1,5 +1,6 @@
+use crate::min_max::{min_max_into_pair, min_max_reduce, min_max_unit};
 use crate::{
-    ChunkSize, IterationOrder, NumThreads, ParCollectInto, Params, RunnerWithPool, Sum,
+    ChunkSize, IterationOrder, NumThreads, ParCollectTarget, Params, RunnerWithPool, Sum,
     par_iter_option::IntoOption,
     par_iter_result::IntoResult,
     runner::{DefaultRunner, ParallelRunner},
@@ -8,7 +9,7 @@ use crate::{
         computational_variants::u_fallible_option::UParOption, using_variants::Using,
     },
 };
-use crate::{ParThreadPool, default_fns::*};
+use crate::{ParIter, ParThreadPool, default_fns::*};
 use core::cmp::Ordering;
 use orx_concurrent_iter::ConcurrentIter;
 
@@ -265,6 +266,21 @@ where
         self.flat_map(map)
     }
 
+    /// Takes this parallel iterator and a regular parallel iterator and creates a new parallel
+    /// iterator over both in sequence.
+    ///
+    /// Unlike [crate::ParIter::chain], transformations applied after chaining have access to mutable
+    /// reference of the used variable; while the transformations of the `other` iterator, which are
+    /// defined before chaining, do not.
+    ///
+    /// Please see [`crate::ParIter::using`] transformation for details and examples.
+    ///
+    /// Further documentation can be found here: [`using.md`](https://github.com/orxfun/orx-parallel/blob/main/docs/using.md).
+    fn chain<Q, C>(self, other: C) -> impl ParIterUsing<'using, U, R, Item = Self::Item>
+    where
+        Q: ParallelRunner,
+        C: ParIter<Q, Item = Self::Item>;
+
     // collect
 
     /// Collects all the items from an iterator into a collection.
@@ -276,7 +292,7 @@ where
     /// Further documentation can be found here: [`using.md`](https://github.com/orxfun/orx-parallel/blob/main/docs/using.md).
     fn collect_into<C>(self, output: C) -> C
     where
-        C: ParCollectInto<Self::Item>;
+        C: ParCollectTarget<Self::Item>;
 
     /// Transforms an iterator into a collection.
     ///
@@ -287,7 +303,7 @@ where
     /// Further documentation can be found here: [`using.md`](https://github.com/orxfun/orx-parallel/blob/main/docs/using.md).
     fn collect<C>(self) -> C
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let output = C::empty(self.con_iter().try_get_len());
         self.collect_into(output)
@@ -438,6 +454,43 @@ where
         self.reduce(reduce)
     }
 
+    /// Returns the minimum and maximum elements of the iterator, computed in a single pass.
+    ///
+    /// See the details here: [crate::ParIter::min_max].
+    fn min_max(self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Ord + Clone + Send,
+    {
+        self.min_max_by(Self::Item::cmp)
+    }
+
+    /// Returns the elements that give the minimum and maximum values with respect to the specified
+    /// `compare` function, computed in a single pass.
+    ///
+    /// See the details here: [crate::ParIter::min_max_by].
+    fn min_max_by<Compare>(self, compare: Compare) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Clone + Send,
+        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
+    {
+        self.map(|_, x| min_max_unit(x))
+            .reduce(|_, a, b| min_max_reduce(&compare, a, b))
+            .map(min_max_into_pair)
+    }
+
+    /// Returns the elements that give the minimum and maximum values from the specified function,
+    /// computed in a single pass.
+    ///
+    /// See the details here: [crate::ParIter::min_max_by_key].
+    fn min_max_by_key<Key, GetKey>(self, get_key: GetKey) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Clone + Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        self.min_max_by(|x, y| get_key(x).cmp(&get_key(y)))
+    }
+
     /// Sums the elements of an iterator.
     ///
     /// See the details here: [crate::ParIter::sum].
@@ -1,8 +1,10 @@
 use crate::default_fns::{u_map_count, u_reduce_sum, u_reduce_unit};
+use crate::min_max::{min_max_into_pair, min_max_reduce, min_max_unit};
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::using::Using;
 use crate::{
-    ChunkSize, IterationOrder, NumThreads, ParCollectInto, ParThreadPool, RunnerWithPool, Sum,
+    ChunkSize, IterationOrder, NumThreads, ParCollectTarget, ParIterOption, ParThreadPool,
+    RunnerWithPool, Sum,
 };
 use core::cmp::Ordering;
 
@@ -173,6 +175,23 @@ where
         Operation: Fn(&mut U::Item, &Self::Item) + Sync + Clone,
         Self::Item: Send;
 
+    /// Takes this fallible parallel iterator and a regular fallible parallel iterator and creates a new
+    /// fallible parallel iterator over both in sequence, provided that all elements are of Some variant;
+    /// short-circuits and returns None otherwise.
+    ///
+    /// Unlike [crate::ParIterOption::chain], transformations applied after chaining have access to mutable
+    /// reference of the used variable.
+    ///
+    /// Please see [`crate::ParIter::using`] transformation for details and examples.
+    ///
+    /// Further documentation can be found here: [`using.md`](https://github.com/orxfun/orx-parallel/blob/main/docs/using.md).
+    fn chain<Q, C>(self, other: C) -> impl ParIterOptionUsing<'using, U, R, Item = Self::Item>
+    where
+        Self: Sized,
+        Q: ParallelRunner,
+        C: ParIterOption<Q, Item = Self::Item>,
+        Self::Item: Send;
+
     // collect
 
     /// Collects all the items from an iterator into a collection iff all elements are of Some variant.
@@ -186,7 +205,7 @@ where
     fn collect_into<C>(self, output: C) -> Option<C>
     where
         Self::Item: Send,
-        C: ParCollectInto<Self::Item>;
+        C: ParCollectTarget<Self::Item>;
 
     /// Transforms an iterator into a collection iff all elements are of Ok variant.
     /// Early exits and returns the error if any of the elements is an Err.
@@ -194,9 +213,9 @@ where
     /// Similar to [`Iterator::collect`], the type annotation on the left-hand-side determines
     /// the type of the result collection; or turbofish annotation can be used.
     ///
-    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    /// All collections implementing [`ParCollectTarget`] can be used to collect into.
     ///
-    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// Unlike [crate::ParIterOption::collect], the closure allows access to mutable reference of the used variable.
     ///
@@ -206,7 +225,7 @@ where
     fn collect<C>(self) -> Option<C>
     where
         Self::Item: Send,
-        C: ParCollectInto<Self::Item>;
+        C: ParCollectTarget<Self::Item>;
 
     // reduce
 
@@ -390,6 +409,56 @@ where
         self.reduce(reduce)
     }
 
+    /// Returns Some of the minimum and maximum elements of the iterator, computed in a single pass,
+    /// if all elements are Some.
+    /// If the iterator is empty, `Some(None)` is returned.
+    /// Early exits and returns None if any of the elements is None.
+    ///
+    /// See the details here: [crate::ParIterOption::min_max].
+    fn min_max(self) -> Option<Option<(Self::Item, Self::Item)>>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone + Send,
+    {
+        self.min_max_by(Self::Item::cmp)
+    }
+
+    /// Returns the elements that give the minimum and maximum values with respect to the specified
+    /// `compare` function, computed in a single pass.
+    /// If the iterator is empty, `Some(None)` is returned.
+    /// Early exits and returns None if any of the elements is None.
+    ///
+    /// See the details here: [crate::ParIterOption::min_max_by].
+    fn min_max_by<Compare>(self, compare: Compare) -> Option<Option<(Self::Item, Self::Item)>>
+    where
+        Self: Sized,
+        Self::Item: Clone + Send,
+        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
+    {
+        self.map(|_, x| min_max_unit(x))
+            .reduce(|_, a, b| min_max_reduce(&compare, a, b))
+            .map(|x| x.map(min_max_into_pair))
+    }
+
+    /// Returns the elements that give the minimum and maximum values from the specified function,
+    /// computed in a single pass.
+    /// If the iterator is empty, `Some(None)` is returned.
+    /// Early exits and returns None if any of the elements is None.
+    ///
+    /// See the details here: [crate::ParIterOption::min_max_by_key].
+    fn min_max_by_key<Key, GetKey>(
+        self,
+        get_key: GetKey,
+    ) -> Option<Option<(Self::Item, Self::Item)>>
+    where
+        Self: Sized,
+        Self::Item: Clone + Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        self.min_max_by(|x, y| get_key(x).cmp(&get_key(y)))
+    }
+
     /// Sums the elements of an iterator.
     /// Early exits and returns None if any of the elements is None.
     ///
@@ -1,11 +1,13 @@
 use crate::default_fns::{u_map_count, u_reduce_sum, u_reduce_unit};
+use crate::min_max::{ResultMinMax, min_max_into_pair, min_max_reduce, min_max_unit};
 use crate::par_iter_result::IntoResult;
 use crate::runner::{DefaultRunner, ParallelRunner};
 use crate::using::Using;
 use crate::{
-    ChunkSize, IterationOrder, NumThreads, ParIterUsing, ParThreadPool, RunnerWithPool, Sum,
+    ChunkSize, IterationOrder, NumThreads, ParIter, ParIterResult, ParIterUsing, ParThreadPool,
+    RunnerWithPool, Sum,
 };
-use crate::{ParCollectInto, generic_values::fallible_iterators::ResultOfIter};
+use crate::{ParCollectTarget, generic_values::fallible_iterators::ResultOfIter};
 use core::cmp::Ordering;
 
 /// A parallel iterator for which the computation either completely succeeds,
@@ -257,6 +259,34 @@ where
         self.map(map)
     }
 
+    /// Takes this fallible parallel iterator and a regular fallible parallel iterator and creates a new
+    /// fallible parallel iterator over both in sequence, provided that all elements are of Ok variant;
+    /// short-circuits and returns the error otherwise.
+    ///
+    /// Unlike [crate::ParIterResult::chain], transformations applied after chaining have access to mutable
+    /// reference of the used variable.
+    ///
+    /// Please see [`crate::ParIter::using`] transformation for details and examples.
+    ///
+    /// Further documentation can be found here: [`using.md`](https://github.com/orxfun/orx-parallel/blob/main/docs/using.md).
+    fn chain<Q, C>(
+        self,
+        other: C,
+    ) -> impl ParIterResultUsing<'using, U, R, Item = Self::Item, Err = Self::Err>
+    where
+        Self: Sized,
+        Q: ParallelRunner,
+        C: ParIterResult<Q, Item = Self::Item, Err = Self::Err>,
+        Self::Item: Send,
+        Self::Err: Send,
+    {
+        let left = self
+            .into_regular_par()
+            .map(|_: &mut U::Item, x: Self::RegularItem| x.into_result());
+        let right = other.into_regular_par().map(IntoResult::into_result);
+        left.chain(right).into_fallible_result()
+    }
+
     // collect
 
     /// Collects all the items from an iterator into a collection iff all elements are of Ok variant.
@@ -269,7 +299,7 @@ where
     /// Further documentation can be found here: [`using.md`](https://github.com/orxfun/orx-parallel/blob/main/docs/using.md).
     fn collect_into<C>(self, output: C) -> Result<C, Self::Err>
     where
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
         Self::Item: Send,
         Self::Err: Send;
 
@@ -279,9 +309,9 @@ where
     /// Similar to [`Iterator::collect`], the type annotation on the left-hand-side determines
     /// the type of the result collection; or turbofish annotation can be used.
     ///
-    /// All collections implementing [`ParCollectInto`] can be used to collect into.
+    /// All collections implementing [`ParCollectTarget`] can be used to collect into.
     ///
-    /// [`ParCollectInto`]: crate::ParCollectInto
+    /// [`ParCollectTarget`]: crate::ParCollectTarget
     ///
     /// Unlike [crate::ParIterResult::collect], the closure allows access to mutable reference of the used variable.
     ///
@@ -293,7 +323,7 @@ where
         Self: Sized,
         Self::Item: Send,
         Self::Err: Send,
-        C: ParCollectInto<Self::Item>,
+        C: ParCollectTarget<Self::Item>,
     {
         let output = C::empty(self.con_iter_len());
         self.collect_into(output)
@@ -492,6 +522,56 @@ where
         self.reduce(reduce)
     }
 
+    /// Returns Ok of the minimum and maximum elements of the iterator, computed in a single pass,
+    /// if all elements succeed.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    /// Early exits and returns the error if any of the elements is an Err.
+    ///
+    /// See the details here: [crate::ParIterResult::min_max].
+    fn min_max(self) -> ResultMinMax<Self::Item, Self::Err>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone + Send,
+        Self::Err: Send,
+    {
+        self.min_max_by(Self::Item::cmp)
+    }
+
+    /// Returns the elements that give the minimum and maximum values with respect to the specified
+    /// `compare` function, computed in a single pass.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    /// Early exits and returns the error if any of the elements is an Err.
+    ///
+    /// See the details here: [crate::ParIterResult::min_max_by].
+    fn min_max_by<Compare>(self, compare: Compare) -> ResultMinMax<Self::Item, Self::Err>
+    where
+        Self: Sized,
+        Self::Item: Clone + Send,
+        Self::Err: Send,
+        Compare: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
+    {
+        self.map(|_, x| min_max_unit(x))
+            .reduce(|_, a, b| min_max_reduce(&compare, a, b))
+            .map(|x| x.map(min_max_into_pair))
+    }
+
+    /// Returns the elements that give the minimum and maximum values from the specified function,
+    /// computed in a single pass.
+    /// If the iterator is empty, `Ok(None)` is returned.
+    /// Early exits and returns the error if any of the elements is an Err.
+    ///
+    /// See the details here: [crate::ParIterResult::min_max_by_key].
+    fn min_max_by_key<Key, GetKey>(self, get_key: GetKey) -> ResultMinMax<Self::Item, Self::Err>
+    where
+        Self: Sized,
+        Self::Item: Clone + Send,
+        Self::Err: Send,
+        Key: Ord,
+        GetKey: Fn(&Self::Item) -> Key + Sync,
+    {
+        self.min_max_by(|x, y| get_key(x).cmp(&get_key(y)))
+    }
+
     /// Sums the elements of an iterator.
     /// Early exits and returns the error if any of the elements is an Err.
     ///